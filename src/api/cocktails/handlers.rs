@@ -1,27 +1,125 @@
+use futures::TryStreamExt;
+use warp::{
+    http::{header, StatusCode},
+    multipart::FormData,
+    Buf, Reply,
+};
+
 use crate::{
-    api::cocktails::models::GetByIdResponse,
+    api::{
+        cocktails::models::GetByIdResponse,
+        common::{
+            cached_json_reply, check_if_match, error_reply, internal_error_reply,
+            validation_error_reply, version_etag, ErrorResponse,
+        },
+    },
     domain::{
-        aggregates::cocktail::{CocktailFilter, CocktailRepo},
+        aggregates::{
+            cocktail::{Cocktail, CocktailError, CocktailFilter, CocktailPatch, CocktailRepo},
+            revision::{RevisionAction, RevisionError, RevisionRepo},
+            user::UserRepo,
+        },
+        locale::LocaleChain,
+        services::{
+            images::{self, ImageError},
+            random, revisions, search,
+            shopping_list::{self, CocktailServings},
+            similarity, slugs, trash, validation,
+        },
         Pagination,
     },
     infrastructure,
+    shared::{
+        card::{self, CardFormat, CocktailCard},
+        i18n::Language,
+    },
 };
 
 use super::models::{
-    CreateRequest, DeleteRequest, ListByFilterRequest, ListByFilterResponse, UpdateRequest,
+    CardRequest, CocktailImageDto, CreateRequest, DeleteRequest, ImageUploadForm,
+    ListByFilterRequest, ListByFilterResponse, ListRequest, OfTheDayRequest, PatchDocument,
+    PatchFormat, RandomRequest, RevisionDiffRequest, RevisionDiffResponse, RevisionResponse,
+    RevisionsRequest, RevisionsResponse, SearchRequest, SearchResponse, ShoppingListRequest,
+    ShoppingListResponse, SimilarRequest, SimilarResponse, StatsResponse, TrashRequest,
+    TrashResponse, UpdateRequest, JSON_PATCH_CONTENT_TYPE, MERGE_PATCH_CONTENT_TYPE,
 };
 
+const DEFAULT_SIMILAR_LIMIT: usize = 5;
+const DEFAULT_SEARCH_PAGE_SIZE: u64 = 10;
+const DEFAULT_LIST_PAGE_SIZE: u64 = 10;
+const MAX_LIST_PAGE_SIZE: u64 = 50;
+const MAX_SEARCH_PAGE_SIZE: u64 = 50;
+const DEFAULT_REVISIONS_PAGE_SIZE: u64 = 10;
+const MAX_REVISIONS_PAGE_SIZE: u64 = 50;
+const DEFAULT_TRASH_PAGE_SIZE: u64 = 10;
+const MAX_TRASH_PAGE_SIZE: u64 = 50;
+
+#[utoipa::path(
+    get,
+    path = "v1",
+    params(
+        ListRequest,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of localised texts")
+    ),
+    responses(
+        (status = 200, description = "Cocktails matching the filters", body = [ListByFilterResponse]),
+        (status = 304, description = "Cocktails did not change since the response with the tag from If-None-Match")
+    )
+)]
+pub async fn list(
+    request: ListRequest,
+    if_none_match: Option<String>,
+    locales: LocaleChain,
+    cache_control: String,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let query = request.q.clone().filter(|query| !query.trim().is_empty());
+    let cocktail_filter = request.into_filter(DEFAULT_LIST_PAGE_SIZE, MAX_LIST_PAGE_SIZE);
+    let cocktails_list = match &query {
+        Some(query) => search::search_cocktails(&cocktail_repository, query, &cocktail_filter)
+            .await
+            .expect("Error while search cocktails in db"),
+        None => cocktail_repository
+            .get_by_filter(&cocktail_filter)
+            .await
+            .expect("Error while get information about cocktail from db"),
+    };
+    let last_modified = cocktails_list
+        .items
+        .iter()
+        .filter_map(|cocktail| cocktail.updated_at)
+        .max();
+
+    let mut response = cached_json_reply(
+        &ListByFilterResponse::localized(&cocktails_list, &locales),
+        last_modified,
+        if_none_match,
+        &cache_control,
+    );
+    response.headers_mut().insert(
+        header::VARY,
+        header::HeaderValue::from_static("Accept-Language"),
+    );
+
+    Ok(response)
+}
+
 #[utoipa::path(
         get,
         path = "v1/{id}",
         params(
-            ("id" = uuid::Uuid, Path, description = "Identifier of cocktail.")
+            ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+            ("Accept-Language" = Option<String>, Header, description = "Preferred languages of localised texts")
         ),
         responses(
-            (status = 200, description = "Get by is ended successfully", body = [GetByIdResponse])
+            (status = 200, description = "Get by is ended successfully, ETag header contains the version", body = [GetByIdResponse])
         )
     )]
-pub async fn get_by_id(id: uuid::Uuid) -> Result<impl warp::Reply, warp::Rejection> {
+pub async fn get_by_id(
+    id: uuid::Uuid,
+    locales: LocaleChain,
+) -> Result<impl warp::Reply, warp::Rejection> {
     let repository_factory = infrastructure::RepositoryFactory::global().clone();
     let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
     let cocktail_from_db = cocktail_repository
@@ -33,9 +131,235 @@ pub async fn get_by_id(id: uuid::Uuid) -> Result<impl warp::Reply, warp::Rejecti
         None => return Err(warp::reject::not_found()),
     };
 
-    let response: GetByIdResponse = GetByIdResponse::from(result.clone());
+    let etag = version_etag(result.version);
+    let response: GetByIdResponse = GetByIdResponse::localized(result, &locales);
 
-    Ok(warp::reply::json(&response))
+    Ok(warp::reply::with_header(
+        warp::reply::with_header(warp::reply::json(&response), header::ETAG, etag),
+        header::VARY,
+        "Accept-Language",
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/{id}/card",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        CardRequest,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of the card")
+    ),
+    responses(
+        (status = 200, description = "Cocktail card as the bot shares it, without the link to the bot", content_type = "text/plain"),
+        (status = 404, description = "Cocktail not found")
+    )
+)]
+pub async fn card(
+    id: uuid::Uuid,
+    request: CardRequest,
+    locales: LocaleChain,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let cocktail_from_db = cocktail_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about cocktail from db");
+    let Some(cocktail) = cocktail_from_db else {
+        return Err(warp::reject::not_found());
+    };
+
+    let format = request
+        .format
+        .map(CardFormat::from)
+        .unwrap_or(CardFormat::PlainText);
+    let cocktail_card = CocktailCard::new(&cocktail, Language::from_locales(&locales), &locales);
+    let mut text = cocktail_card.render(card::SHARED_CARD_TEMPLATE, format);
+    if let Some(max_length) = request.max_length {
+        text = card::truncate(&text, max_length.max(1));
+    }
+    let content_type = match format {
+        CardFormat::Markdown => "text/markdown; charset=utf-8",
+        CardFormat::PlainText => "text/plain; charset=utf-8",
+    };
+
+    Ok(warp::http::Response::builder()
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::VARY, "Accept-Language")
+        .body(text)
+        .unwrap())
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "Current or previous slug of cocktail."),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of localised texts")
+    ),
+    responses(
+        (status = 200, description = "Cocktail with the slug, ETag header contains the version", body = [GetByIdResponse]),
+        (status = 301, description = "Slug was replaced after a rename, Location header contains URL with the current slug"),
+        (status = 404, description = "Cocktail not found")
+    )
+)]
+pub async fn get_by_slug(
+    slug: String,
+    locales: LocaleChain,
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let cocktail_from_db = cocktail_repository
+        .get_by_slug(&slug)
+        .await
+        .expect("Error while get information about cocktail from db");
+    let Some(cocktail) = cocktail_from_db else {
+        return Err(warp::reject::not_found());
+    };
+    if let Some(current_slug) = cocktail.slug.as_ref().filter(|current| **current != slug) {
+        let location: warp::http::Uri = format!("/api/cocktails/v1/by-slug/{}", current_slug)
+            .parse()
+            .expect("Slug must be valid in URL path");
+        return Ok(warp::redirect::redirect(location).into_response());
+    }
+
+    let etag = version_etag(cocktail.version);
+
+    Ok(warp::reply::with_header(
+        warp::reply::with_header(
+            warp::reply::json(&GetByIdResponse::localized(cocktail, &locales)),
+            header::ETAG,
+            etag,
+        ),
+        header::VARY,
+        "Accept-Language",
+    )
+    .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/{id}/similar",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        SimilarRequest
+    ),
+    responses(
+        (status = 200, description = "Similar cocktails, the most similar first", body = [SimilarResponse]),
+        (status = 404, description = "Cocktail not found")
+    )
+)]
+pub async fn similar(
+    id: uuid::Uuid,
+    request: SimilarRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_SIMILAR_LIMIT)
+        .min(similarity::MAX_SIMILAR_COCKTAILS);
+    let similar = similarity::get_similar_cocktails(&cocktail_repository, &id, limit)
+        .await
+        .expect("Error while get information about cocktail from db");
+
+    match similar {
+        Some(similar) => Ok(warp::reply::json(&SimilarResponse::from(similar))),
+        None => Err(warp::reject::not_found()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/search",
+    params(SearchRequest),
+    responses(
+        (status = 200, description = "Cocktails matching the words, the most relevant first", body = [SearchResponse])
+    )
+)]
+pub async fn search(request: SearchRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let pagination = Pagination {
+        page: request.page.unwrap_or(0),
+        items_per_page: request
+            .items_per_page
+            .unwrap_or(DEFAULT_SEARCH_PAGE_SIZE)
+            .clamp(1, MAX_SEARCH_PAGE_SIZE),
+    };
+    let found = search::text_search(&cocktail_repository, &request.q, &pagination)
+        .await
+        .expect("Error while search cocktails in db");
+
+    Ok(warp::reply::json(&SearchResponse::from(found)))
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/random",
+    params(
+        RandomRequest,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of localised texts")
+    ),
+    responses(
+        (status = 200, description = "Random cocktail", body = [GetByIdResponse]),
+        (status = 404, description = "No cocktails match the constraints")
+    )
+)]
+pub async fn random(
+    request: RandomRequest,
+    locales: LocaleChain,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let cocktail = cocktail_repository
+        .get_random(&request.into())
+        .await
+        .expect("Error while get information about cocktail from db");
+
+    match cocktail {
+        Some(cocktail) => Ok(warp::reply::with_header(
+            warp::reply::json(&GetByIdResponse::localized(cocktail, &locales)),
+            header::VARY,
+            "Accept-Language",
+        )),
+        None => Err(warp::reject::not_found()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/of-the-day",
+    params(
+        OfTheDayRequest,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of localised texts")
+    ),
+    responses(
+        (status = 200, description = "Cocktail of the day", body = [GetByIdResponse]),
+        (status = 404, description = "No cocktails match the constraints")
+    )
+)]
+pub async fn of_the_day(
+    request: OfTheDayRequest,
+    locales: LocaleChain,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let date = request
+        .date
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let cocktail = random::get_cocktail_of_the_day(&cocktail_repository, &request.into(), &date)
+        .await
+        .expect("Error while get information about cocktail from db");
+
+    match cocktail {
+        Some(cocktail) => Ok(warp::reply::with_header(
+            warp::reply::json(&GetByIdResponse::localized(cocktail, &locales)),
+            header::VARY,
+            "Accept-Language",
+        )),
+        None => Err(warp::reject::not_found()),
+    }
 }
 
 #[utoipa::path(
@@ -55,82 +379,875 @@ pub async fn list_by_filter(
         ids: filter.ids,
         names: None,
         russian_names: None,
+        tags: filter.tags,
+        countries: filter.countries,
+        sort: filter.sort.map(|sort| sort.into()),
         pagination: Pagination {
             page: filter.pagination.page,
             items_per_page: filter.pagination.items_per_page,
         },
     };
-    let cocktails_list = cocktail_repository
-        .get_by_filter(&cocktail_filter)
-        .await
-        .expect("Error while get information about cocktail from db");
+    let query = filter.query.filter(|query| !query.trim().is_empty());
+    let cocktails_list = match &query {
+        Some(query) => search::search_cocktails(&cocktail_repository, query, &cocktail_filter)
+            .await
+            .expect("Error while search cocktails in db"),
+        None => cocktail_repository
+            .get_by_filter(&cocktail_filter)
+            .await
+            .expect("Error while get information about cocktail from db"),
+    };
 
-    let response = ListByFilterResponse::from(&cocktails_list);
+    let mut response = ListByFilterResponse::from(&cocktails_list);
+    if filter.include_facets.unwrap_or(false) {
+        let facets_filter = match &query {
+            Some(query) => CocktailFilter {
+                ids: Some(
+                    search::search_cocktail_ids(&cocktail_repository, query, &cocktail_filter)
+                        .await
+                        .expect("Error while search cocktails in db"),
+                ),
+                tags: None,
+                ..cocktail_filter
+            },
+            None => cocktail_filter,
+        };
+        let facets = cocktail_repository
+            .get_facets(&facets_filter)
+            .await
+            .expect("Error while get cocktail facets from db");
+        response.facets = Some(facets.into());
+    }
     Ok(warp::reply::json(&response))
 }
 
+#[utoipa::path(
+    get,
+    path = "v1/stats",
+    responses(
+        (status = 200, description = "Totals over the whole catalogue", body = [StatsResponse])
+    )
+)]
+pub async fn stats() -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let stats = cocktail_repository
+        .get_stats()
+        .await
+        .expect("Error while get catalogue stats from db");
+
+    Ok(warp::reply::json(&StatsResponse::from(stats)))
+}
+
 #[utoipa::path(
     post,
     path = "v1",
+    params(
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history")
+    ),
     request_body = CreateRequest,
     responses(
-        (status = 200, description = "Create cocktail status")
+        (status = 200, description = "Create cocktail status"),
+        (status = 409, description = "No free slug was found for the cocktail name", body = [ErrorResponse]),
+        (status = 422, description = "Cocktail is invalid, errors list the fields", body = [ErrorResponse])
     )
 )]
-pub async fn create(request: CreateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+pub async fn create(
+    author: Option<String>,
+    request: CreateRequest,
+) -> Result<warp::reply::Response, warp::Rejection> {
     let repository_factory = infrastructure::RepositoryFactory::global().clone();
     let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
-    cocktail_repository.create(&request.into()).await;
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let mut cocktail: Cocktail = request.into();
+    if let Err(err) = validation::validate_cocktail(&cocktail_repository, &cocktail).await {
+        return Ok(cocktail_error_reply(err).into_response());
+    }
+    if let Err(err) = slugs::create(&cocktail_repository, &mut cocktail).await {
+        return Ok(cocktail_error_reply(err).into_response());
+    }
+    similarity::invalidate_cache();
+    search::invalidate_cache();
+    // the creation is already stored, a missing revision must not fail it
+    if let Err(err) = revisions::record(
+        &revision_repository,
+        RevisionAction::Create,
+        None,
+        Some(&cocktail),
+        author,
+    )
+    .await
+    {
+        log::error!(
+            "Failed to record revision of cocktail {}: {:?}",
+            cocktail.id,
+            err
+        );
+    }
 
-    Ok(warp::reply())
+    Ok(warp::reply().into_response())
 }
 
 #[utoipa::path(
     put,
     path = "v1",
+    params(
+        ("If-Match" = String, Header, description = "ETag of the cocktail version being changed"),
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history")
+    ),
     request_body = UpdateRequest,
     responses(
-        (status = 200, description = "Update cocktail status")
+        (status = 200, description = "Update cocktail status, ETag header contains the new version"),
+        (status = 409, description = "Slug for the new name was taken by another cocktail meanwhile", body = [ErrorResponse]),
+        (status = 422, description = "Cocktail is invalid, errors list the fields", body = [ErrorResponse]),
+        (status = 412, description = "Cocktail was changed since the given version", body = [ErrorResponse]),
+        (status = 428, description = "If-Match header is missing", body = [ErrorResponse])
     )
 )]
-pub async fn update(request: UpdateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+pub async fn update(
+    if_match: Option<String>,
+    author: Option<String>,
+    request: UpdateRequest,
+) -> Result<warp::reply::Response, warp::Rejection> {
     let repository_factory = infrastructure::RepositoryFactory::global().clone();
     let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
     let cocktail_from_db = cocktail_repository
         .get_by_id(&request.id)
         .await
         .expect("Error while get information about cocktail from db");
-    let _ = match cocktail_from_db {
-        Some(_) => {
-            cocktail_repository.update(&request.into()).await;
+    let Some(cocktail_from_db) = cocktail_from_db else {
+        return Err(warp::reject::not_found());
+    };
+    if let Err(reply) = check_if_match(if_match.as_deref(), &version_etag(cocktail_from_db.version))
+    {
+        return Ok(reply.into_response());
+    }
+
+    let mut cocktail: Cocktail = request.into();
+    cocktail.version = cocktail_from_db.version;
+    if let Err(err) = validation::validate_cocktail(&cocktail_repository, &cocktail).await {
+        return Ok(cocktail_error_reply(err).into_response());
+    }
+    if let Err(err) =
+        slugs::assign(&cocktail_repository, &mut cocktail, Some(&cocktail_from_db)).await
+    {
+        return Ok(cocktail_error_reply(err).into_response());
+    }
+    if let Err(err) = cocktail_repository.update(&cocktail).await {
+        return Ok(cocktail_error_reply(err).into_response());
+    }
+    similarity::invalidate_cache();
+    search::invalidate_cache();
+    let updated = cocktail_repository
+        .get_by_id(&cocktail.id)
+        .await
+        .expect("Error while get information about cocktail from db")
+        .unwrap_or(cocktail);
+    // the update is already stored, a missing revision must not fail it
+    if let Err(err) = revisions::record(
+        &revision_repository,
+        RevisionAction::Update,
+        Some(&cocktail_from_db),
+        Some(&updated),
+        author,
+    )
+    .await
+    {
+        log::error!(
+            "Failed to record revision of cocktail {}: {:?}",
+            updated.id,
+            err
+        );
+    }
+
+    Ok(
+        warp::reply::with_header(warp::reply(), header::ETAG, version_etag(updated.version))
+            .into_response(),
+    )
+}
+
+#[utoipa::path(
+    patch,
+    path = "v1/{id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        ("If-Match" = String, Header, description = "ETag of the cocktail version being changed"),
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history")
+    ),
+    request_body(
+        content = PatchDocument,
+        description = "JSON Merge Patch (RFC 7396) of the document, or JSON Patch (RFC 6902) with application/json-patch+json",
+        content_type = "application/merge-patch+json"
+    ),
+    responses(
+        (status = 200, description = "Patched cocktail, ETag header contains the new version", body = [GetByIdResponse]),
+        (status = 400, description = "Patch is not valid JSON or JSON Patch", body = [ErrorResponse]),
+        (status = 404, description = "Cocktail not found"),
+        (status = 409, description = "JSON Patch test operation failed or slug for the new name was taken meanwhile", body = [ErrorResponse]),
+        (status = 412, description = "Cocktail was changed since the given version", body = [ErrorResponse]),
+        (status = 415, description = "Unsupported patch format", body = [ErrorResponse]),
+        (status = 422, description = "Patch can not be applied or makes cocktail invalid", body = [ErrorResponse]),
+        (status = 428, description = "If-Match header is missing", body = [ErrorResponse])
+    )
+)]
+pub async fn patch(
+    id: uuid::Uuid,
+    if_match: Option<String>,
+    author: Option<String>,
+    content_type: Option<String>,
+    body: warp::hyper::body::Bytes,
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let Some(format) = PatchFormat::from_content_type(content_type.as_deref()) else {
+        return Ok(error_reply(
+            format!(
+                "Content-Type must be {} or {}",
+                MERGE_PATCH_CONTENT_TYPE, JSON_PATCH_CONTENT_TYPE
+            ),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        )
+        .into_response());
+    };
+    let patch: serde_json::Value = match serde_json::from_slice(&body) {
+        Ok(patch) => patch,
+        Err(err) => {
+            return Ok(error_reply(
+                format!("Patch is not valid JSON: {}", err),
+                StatusCode::BAD_REQUEST,
+            )
+            .into_response())
         }
-        None => return Err(warp::reject::not_found()),
     };
 
-    Ok(warp::reply())
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let Some(cocktail) = cocktail_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about cocktail from db")
+    else {
+        return Err(warp::reject::not_found());
+    };
+    if let Err(reply) = check_if_match(if_match.as_deref(), &version_etag(cocktail.version)) {
+        return Ok(reply.into_response());
+    }
+
+    let mut document = serde_json::to_value(PatchDocument::from(&cocktail)).unwrap();
+    match format {
+        PatchFormat::MergePatch => json_patch::merge(&mut document, &patch),
+        PatchFormat::JsonPatch => {
+            let operations: Vec<json_patch::PatchOperation> = match serde_json::from_value(patch) {
+                Ok(operations) => operations,
+                Err(err) => {
+                    return Ok(error_reply(
+                        format!("Invalid JSON Patch: {}", err),
+                        StatusCode::BAD_REQUEST,
+                    )
+                    .into_response())
+                }
+            };
+            if let Err(err) = json_patch::patch(&mut document, &operations) {
+                let status = match err.kind {
+                    json_patch::PatchErrorKind::TestFailed => StatusCode::CONFLICT,
+                    _ => StatusCode::UNPROCESSABLE_ENTITY,
+                };
+                return Ok(error_reply(err.to_string(), status).into_response());
+            }
+        }
+    }
+    let document: PatchDocument = match serde_json::from_value(document) {
+        Ok(document) => document,
+        Err(err) => {
+            return Ok(error_reply(
+                format!("Patched cocktail is invalid: {}", err),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            )
+            .into_response())
+        }
+    };
+
+    let mut patched = document.apply_to(&cocktail);
+    let mut changes = CocktailPatch::between(&cocktail, &patched);
+    if !changes.is_empty() {
+        if let Err(err) = validation::validate_cocktail(&cocktail_repository, &patched).await {
+            return Ok(cocktail_error_reply(err).into_response());
+        }
+        // slug follows the names, it is not part of the patch document
+        if let Err(err) = slugs::assign(&cocktail_repository, &mut patched, Some(&cocktail)).await {
+            return Ok(cocktail_error_reply(err).into_response());
+        }
+        changes = CocktailPatch::between(&cocktail, &patched);
+        if let Err(err) = cocktail_repository
+            .patch(&id, cocktail.version, &changes)
+            .await
+        {
+            return Ok(cocktail_error_reply(err).into_response());
+        }
+        similarity::invalidate_cache();
+        search::invalidate_cache();
+    }
+    let cocktail = match cocktail_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about cocktail from db")
+    {
+        Some(updated) if !changes.is_empty() => {
+            let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+            // the patch is already stored, a missing revision must not fail it
+            if let Err(err) = revisions::record(
+                &revision_repository,
+                RevisionAction::Update,
+                Some(&cocktail),
+                Some(&updated),
+                author,
+            )
+            .await
+            {
+                log::error!("Failed to record revision of cocktail {}: {:?}", id, err);
+            }
+            updated
+        }
+        Some(updated) => updated,
+        None => patched,
+    };
+
+    let etag = version_etag(cocktail.version);
+
+    Ok(warp::reply::with_header(
+        warp::reply::json(&GetByIdResponse::from(cocktail)),
+        header::ETAG,
+        etag,
+    )
+    .into_response())
 }
 
 #[utoipa::path(
     delete,
     path = "v1",
+    params(
+        ("If-Match" = String, Header, description = "ETag of the cocktail version being deleted"),
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history")
+    ),
     request_body = DeleteRequest,
     responses(
-        (status = 200, description = "Delete cocktail status")
+        (status = 200, description = "Cocktail is moved to trash"),
+        (status = 412, description = "Cocktail was changed since the given version", body = [ErrorResponse]),
+        (status = 428, description = "If-Match header is missing", body = [ErrorResponse])
     )
 )]
-pub async fn delete(request: DeleteRequest) -> Result<impl warp::Reply, warp::Rejection> {
+pub async fn delete(
+    if_match: Option<String>,
+    author: Option<String>,
+    request: DeleteRequest,
+) -> Result<warp::reply::Response, warp::Rejection> {
     let repository_factory = infrastructure::RepositoryFactory::global().clone();
     let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
     let cocktail_from_db = cocktail_repository
         .get_by_id(&request.id)
         .await
         .expect("Error while get information about cocktail from db");
-    let _ = match cocktail_from_db {
-        Some(_) => {
-            cocktail_repository.delete(&cocktail_from_db.unwrap()).await;
+    let Some(cocktail) = cocktail_from_db else {
+        return Err(warp::reject::not_found());
+    };
+    if let Err(reply) = check_if_match(if_match.as_deref(), &version_etag(cocktail.version)) {
+        return Ok(reply.into_response());
+    }
+
+    if let Err(err) = cocktail_repository.delete(&cocktail).await {
+        return Ok(cocktail_error_reply(err).into_response());
+    }
+    similarity::invalidate_cache();
+    search::invalidate_cache();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    // the deletion is already stored, a missing revision must not fail it
+    if let Err(err) = revisions::record(
+        &revision_repository,
+        RevisionAction::Delete,
+        Some(&cocktail),
+        None,
+        author,
+    )
+    .await
+    {
+        log::error!(
+            "Failed to record revision of cocktail {}: {:?}",
+            cocktail.id,
+            err
+        );
+    }
+
+    Ok(warp::reply().into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/{id}/revisions",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        RevisionsRequest
+    ),
+    responses(
+        (status = 200, description = "Revisions of cocktail, the latest first", body = [RevisionsResponse])
+    )
+)]
+pub async fn revisions(
+    id: uuid::Uuid,
+    request: RevisionsRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let pagination = Pagination {
+        page: request.page.unwrap_or(0),
+        items_per_page: request
+            .items_per_page
+            .unwrap_or(DEFAULT_REVISIONS_PAGE_SIZE)
+            .clamp(1, MAX_REVISIONS_PAGE_SIZE),
+    };
+    let revisions = revision_repository
+        .get_by_cocktail(&id, &pagination)
+        .await
+        .expect("Error while get cocktail revisions from db");
+
+    Ok(warp::reply::json(&RevisionsResponse::from(revisions)))
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/{id}/revisions/diff",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        RevisionDiffRequest
+    ),
+    responses(
+        (status = 200, description = "Difference between two revisions", body = [RevisionDiffResponse]),
+        (status = 404, description = "Revision not found", body = [ErrorResponse])
+    )
+)]
+pub async fn revision_diff(
+    id: uuid::Uuid,
+    request: RevisionDiffRequest,
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let diff = match revisions::diff_revisions(&revision_repository, &id, request.from, request.to)
+        .await
+    {
+        Ok(diff) => diff,
+        Err(err) => return Ok(revision_error_reply(err).into_response()),
+    };
+
+    Ok(warp::reply::json(&RevisionDiffResponse {
+        from: request.from,
+        to: request.to,
+        diff,
+    })
+    .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/{id}/revisions/{number}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        ("number" = u64, Path, description = "Number of revision.")
+    ),
+    responses(
+        (status = 200, description = "Revision with full snapshot of cocktail", body = [RevisionResponse]),
+        (status = 404, description = "Revision not found")
+    )
+)]
+pub async fn revision(id: uuid::Uuid, number: u64) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let Some(revision) = revision_repository
+        .get(&id, number)
+        .await
+        .expect("Error while get cocktail revision from db")
+    else {
+        return Err(warp::reject::not_found());
+    };
+
+    Ok(warp::reply::json(&RevisionResponse::from(revision)))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/{id}/revisions/{number}/restore",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        ("number" = u64, Path, description = "Number of revision to restore."),
+        ("If-Match" = Option<String>, Header, description = "ETag of the current cocktail version, also of the cocktail in trash, not needed for purged cocktail"),
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history")
+    ),
+    responses(
+        (status = 200, description = "Restored cocktail, ETag header contains the new version", body = [GetByIdResponse]),
+        (status = 404, description = "Revision not found", body = [ErrorResponse]),
+        (status = 412, description = "Cocktail was changed since the given version", body = [ErrorResponse]),
+        (status = 428, description = "If-Match header is missing", body = [ErrorResponse])
+    )
+)]
+pub async fn restore_revision(
+    id: uuid::Uuid,
+    number: u64,
+    if_match: Option<String>,
+    author: Option<String>,
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let mut current = cocktail_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about cocktail from db");
+    if current.is_none() {
+        current = cocktail_repository
+            .get_deleted_by_id(&id)
+            .await
+            .expect("Error while get information about deleted cocktail from db");
+    }
+    if let Some(current) = &current {
+        if let Err(reply) = check_if_match(if_match.as_deref(), &version_etag(current.version)) {
+            return Ok(reply.into_response());
         }
-        None => return Err(warp::reject::not_found()),
+    }
+
+    let restored = match revisions::restore(
+        &cocktail_repository,
+        &revision_repository,
+        &id,
+        number,
+        current.as_ref(),
+        author,
+    )
+    .await
+    {
+        Ok(restored) => restored,
+        Err(err) => return Ok(revision_error_reply(err).into_response()),
+    };
+    let etag = version_etag(restored.version);
+
+    Ok(warp::reply::with_header(
+        warp::reply::json(&GetByIdResponse::from(restored)),
+        header::ETAG,
+        etag,
+    )
+    .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "v1/trash",
+    params(TrashRequest),
+    responses(
+        (status = 200, description = "Deleted cocktails, the most recently deleted first", body = [TrashResponse])
+    )
+)]
+pub async fn trash(request: TrashRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let pagination = Pagination {
+        page: request.page.unwrap_or(0),
+        items_per_page: request
+            .items_per_page
+            .unwrap_or(DEFAULT_TRASH_PAGE_SIZE)
+            .clamp(1, MAX_TRASH_PAGE_SIZE),
+    };
+    let deleted = cocktail_repository
+        .get_deleted(&pagination)
+        .await
+        .expect("Error while get deleted cocktails from db");
+
+    Ok(warp::reply::json(&TrashResponse::from(deleted)))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/trash/{id}/restore",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of deleted cocktail."),
+        ("If-Match" = String, Header, description = "ETag of the deleted cocktail version from the trash list"),
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history")
+    ),
+    responses(
+        (status = 200, description = "Restored cocktail, ETag header contains the new version", body = [GetByIdResponse]),
+        (status = 404, description = "Cocktail is not in trash"),
+        (status = 412, description = "Cocktail was changed since the given version", body = [ErrorResponse]),
+        (status = 428, description = "If-Match header is missing", body = [ErrorResponse])
+    )
+)]
+pub async fn restore_from_trash(
+    id: uuid::Uuid,
+    if_match: Option<String>,
+    author: Option<String>,
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let Some(deleted) = cocktail_repository
+        .get_deleted_by_id(&id)
+        .await
+        .expect("Error while get information about deleted cocktail from db")
+    else {
+        return Err(warp::reject::not_found());
     };
+    if let Err(reply) = check_if_match(if_match.as_deref(), &version_etag(deleted.version)) {
+        return Ok(reply.into_response());
+    }
+    let restored =
+        match trash::restore(&cocktail_repository, &revision_repository, &deleted, author).await {
+            Ok(restored) => restored,
+            Err(err) => return Ok(cocktail_error_reply(err).into_response()),
+        };
+    let etag = version_etag(restored.version);
+
+    Ok(warp::reply::with_header(
+        warp::reply::json(&GetByIdResponse::from(restored)),
+        header::ETAG,
+        etag,
+    )
+    .into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/{id}/image",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        ("If-Match" = String, Header, description = "ETag of the cocktail version being changed")
+    ),
+    request_body(content = ImageUploadForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Image is stored, the previous one is replaced, ETag header contains the new version", body = [CocktailImageDto]),
+        (status = 400, description = "Form has no image field", body = [ErrorResponse]),
+        (status = 404, description = "Cocktail not found"),
+        (status = 412, description = "Cocktail was changed since the given version", body = [ErrorResponse]),
+        (status = 415, description = "File is not a JPEG, PNG or WebP image", body = [ErrorResponse]),
+        (status = 422, description = "Image can not be decoded", body = [ErrorResponse]),
+        (status = 428, description = "If-Match header is missing", body = [ErrorResponse])
+    )
+)]
+pub async fn upload_image(
+    id: uuid::Uuid,
+    if_match: Option<String>,
+    form: FormData,
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let blob_store = repository_factory
+        .get_blob_store()
+        .expect("Error while create blob store");
+    let cocktail_from_db = cocktail_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about cocktail from db");
+    let Some(cocktail) = cocktail_from_db else {
+        return Err(warp::reject::not_found());
+    };
+    if let Err(reply) = check_if_match(if_match.as_deref(), &version_etag(cocktail.version)) {
+        return Ok(reply.into_response());
+    }
+    let data = match read_form_field(form, "image").await {
+        Ok(Some(data)) => data,
+        Ok(None) => {
+            return Ok(
+                error_reply("Form has no image field", StatusCode::BAD_REQUEST).into_response(),
+            )
+        }
+        Err(err) => {
+            return Ok(error_reply(err.to_string(), StatusCode::BAD_REQUEST).into_response())
+        }
+    };
+
+    match images::upload(&cocktail_repository, blob_store.as_ref(), &cocktail, data).await {
+        Ok(image) => Ok(warp::reply::with_header(
+            warp::reply::json(&CocktailImageDto::from(&image)),
+            header::ETAG,
+            version_etag(cocktail.version + 1),
+        )
+        .into_response()),
+        Err(err) => match err.downcast_ref::<ImageError>() {
+            Some(unsupported @ ImageError::UnsupportedFormat) => Ok(error_reply(
+                unsupported.to_string(),
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            )
+            .into_response()),
+            Some(corrupted @ ImageError::Corrupted(_)) => Ok(error_reply(
+                corrupted.to_string(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            )
+            .into_response()),
+            None => Ok(cocktail_error_reply(err).into_response()),
+        },
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "v1/{id}/image",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Identifier of cocktail."),
+        ("If-Match" = String, Header, description = "ETag of the cocktail version being changed")
+    ),
+    responses(
+        (status = 200, description = "Image and its files are removed, ETag header contains the new version"),
+        (status = 404, description = "Cocktail not found or has no image"),
+        (status = 412, description = "Cocktail was changed since the given version", body = [ErrorResponse]),
+        (status = 428, description = "If-Match header is missing", body = [ErrorResponse])
+    )
+)]
+pub async fn delete_image(
+    id: uuid::Uuid,
+    if_match: Option<String>,
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let blob_store = repository_factory
+        .get_blob_store()
+        .expect("Error while create blob store");
+    let cocktail_from_db = cocktail_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about cocktail from db");
+    let Some(cocktail) = cocktail_from_db else {
+        return Err(warp::reject::not_found());
+    };
+    if let Err(reply) = check_if_match(if_match.as_deref(), &version_etag(cocktail.version)) {
+        return Ok(reply.into_response());
+    }
+    match images::remove(&cocktail_repository, blob_store.as_ref(), &cocktail).await {
+        Ok(true) => Ok(warp::reply::with_header(
+            warp::reply(),
+            header::ETAG,
+            version_etag(cocktail.version + 1),
+        )
+        .into_response()),
+        Ok(false) => Err(warp::reject::not_found()),
+        Err(err) => Ok(cocktail_error_reply(err).into_response()),
+    }
+}
+
+/// Content of the first part of multipart form with given name.
+async fn read_form_field(form: FormData, name: &str) -> Result<Option<Vec<u8>>, warp::Error> {
+    let mut form = form;
+    while let Some(part) = form.try_next().await? {
+        if part.name() != name {
+            continue;
+        }
+        let data = part
+            .stream()
+            .try_fold(vec![], |mut data, chunk| async move {
+                data.extend_from_slice(chunk.chunk());
+                Ok(data)
+            })
+            .await?;
+        return Ok(Some(data));
+    }
+
+    Ok(None)
+}
+
+fn revision_error_reply(err: anyhow::Error) -> warp::reply::WithStatus<warp::reply::Json> {
+    match err.downcast_ref::<RevisionError>() {
+        Some(not_found @ RevisionError::NotFound { .. }) => {
+            error_reply(not_found.to_string(), StatusCode::NOT_FOUND)
+        }
+        Some(taken @ RevisionError::NumberTaken { .. }) => {
+            error_reply(taken.to_string(), StatusCode::CONFLICT)
+        }
+        Some(RevisionError::NoState) | None => cocktail_error_reply(err),
+    }
+}
+
+/// Cocktail is invalid, was changed by another request between reading and conditional write
+/// or its slug was taken meanwhile.
+fn cocktail_error_reply(err: anyhow::Error) -> warp::reply::WithStatus<warp::reply::Json> {
+    match err.downcast_ref::<CocktailError>() {
+        Some(conflict @ CocktailError::VersionConflict { .. }) => {
+            error_reply(conflict.to_string(), StatusCode::PRECONDITION_FAILED)
+        }
+        Some(CocktailError::Invalid(errors)) => {
+            validation_error_reply("Cocktail is invalid", errors)
+        }
+        Some(taken @ CocktailError::SlugTaken(_)) => {
+            error_reply(taken.to_string(), StatusCode::CONFLICT)
+        }
+        None => internal_error_reply(err),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/shopping-list",
+    request_body = ShoppingListRequest,
+    responses(
+        (status = 200, description = "Shopping list is built successfully", body = [ShoppingListResponse]),
+        (status = 404, description = "User not found", body = [ErrorResponse])
+    )
+)]
+pub async fn shopping_list(
+    request: ShoppingListRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let default_servings = request.servings.unwrap_or(1);
+
+    let mut servings: Vec<CocktailServings> = request
+        .cocktails
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| CocktailServings {
+            cocktail_id: item.cocktail_id,
+            servings: item.servings.unwrap_or(default_servings),
+        })
+        .collect();
+
+    if let Some(telegram_id) = request.favorites_of {
+        let user_repository = repository_factory.get_user_repository().await.unwrap();
+        let user = user_repository
+            .get_by_telegram_id(&telegram_id)
+            .await
+            .expect("Error while get information about user from db");
+        let user = match user {
+            Some(user) => user,
+            None => {
+                return Ok(error_reply(
+                    format!("user with telegram id {} not found", telegram_id),
+                    StatusCode::NOT_FOUND,
+                ))
+            }
+        };
+        servings.extend(
+            user.favorite_cocktails
+                .iter()
+                .map(|favorite| CocktailServings {
+                    cocktail_id: favorite.cocktail_id,
+                    servings: default_servings,
+                }),
+        );
+    }
+
+    if let Some(tag) = &request.tag {
+        let ids = shopping_list::get_cocktail_ids_by_tag(&cocktail_repository, tag)
+            .await
+            .expect("Error while get information about cocktail from db");
+        servings.extend(ids.into_iter().map(|cocktail_id| CocktailServings {
+            cocktail_id,
+            servings: default_servings,
+        }));
+    }
+
+    let owned: Vec<_> = request
+        .owned_ingredients
+        .unwrap_or_default()
+        .into_iter()
+        .map(|owned_ingredient| owned_ingredient.into())
+        .collect();
+    let list = shopping_list::build_shopping_list(&cocktail_repository, &servings, &owned)
+        .await
+        .expect("Error while get information about cocktail from db");
 
-    Ok(warp::reply())
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ShoppingListResponse::from(list)),
+        StatusCode::OK,
+    ))
 }
@@ -1,23 +1,66 @@
 use utoipa::OpenApi;
 use warp::Filter;
 
+use super::configurations::ApiConfiguration;
+
 // private modules
 mod handlers;
 mod models;
 mod routes;
 
 #[derive(OpenApi)]
-#[openapi(
-    paths(handlers::get_by_id, handlers::list_by_filter, handlers::create, handlers::update, handlers::delete)
-)]
+#[openapi(paths(
+    handlers::list,
+    handlers::get_by_id,
+    handlers::card,
+    handlers::get_by_slug,
+    handlers::similar,
+    handlers::search,
+    handlers::random,
+    handlers::of_the_day,
+    handlers::list_by_filter,
+    handlers::stats,
+    handlers::create,
+    handlers::update,
+    handlers::patch,
+    handlers::delete,
+    handlers::revisions,
+    handlers::revision_diff,
+    handlers::revision,
+    handlers::restore_revision,
+    handlers::trash,
+    handlers::restore_from_trash,
+    handlers::upload_image,
+    handlers::delete_image,
+    handlers::shopping_list
+))]
 pub struct CocktailsApi;
 
 pub fn use_cocktails_api(
+    api_configuration: &ApiConfiguration,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    routes::get_by_id()
-        .and_then(handlers::get_by_id)
+    routes::list(api_configuration.cache_control.clone())
+        .and_then(handlers::list)
+        .or(routes::get_by_id().and_then(handlers::get_by_id))
+        .or(routes::card().and_then(handlers::card))
+        .or(routes::get_by_slug().and_then(handlers::get_by_slug))
+        .or(routes::similar().and_then(handlers::similar))
+        .or(routes::search().and_then(handlers::search))
+        .or(routes::random().and_then(handlers::random))
+        .or(routes::of_the_day().and_then(handlers::of_the_day))
         .or(routes::list_by_filter().and_then(handlers::list_by_filter))
+        .or(routes::stats().and_then(handlers::stats))
         .or(routes::create().and_then(handlers::create))
         .or(routes::update().and_then(handlers::update))
+        .or(routes::patch().and_then(handlers::patch))
         .or(routes::delete().and_then(handlers::delete))
+        .or(routes::revisions().and_then(handlers::revisions))
+        .or(routes::revision_diff().and_then(handlers::revision_diff))
+        .or(routes::revision().and_then(handlers::revision))
+        .or(routes::restore_revision().and_then(handlers::restore_revision))
+        .or(routes::trash().and_then(handlers::trash))
+        .or(routes::restore_from_trash().and_then(handlers::restore_from_trash))
+        .or(routes::upload_image().and_then(handlers::upload_image))
+        .or(routes::delete_image().and_then(handlers::delete_image))
+        .or(routes::shopping_list().and_then(handlers::shopping_list))
 }
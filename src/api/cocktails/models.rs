@@ -1,9 +1,26 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::{
     api::common::PaginationRequest,
-    domain::aggregates::cocktail::{Cocktail, CocktailItem, CocktailsPaged, Recipe, Tag},
+    domain::aggregates::{
+        cocktail::{
+            CatalogueStats, Cocktail, CocktailFacets, CocktailFilter, CocktailImage, CocktailItem,
+            CocktailSort, CocktailTool, CocktailsPaged, FacetValue, ImageSize, ImageVariant,
+            PreparationMethod, RandomCocktailFilter, Recipe, Tag,
+        },
+        rating::RatingSummary,
+        revision::{CocktailRevision, RevisionAction, RevisionsPaged},
+    },
+    domain::services::{
+        search::{SearchSnippet, SnippetField, TextSearchPaged},
+        shopping_list::{OwnedIngredient, ShoppingList},
+        similarity::SimilarCocktail,
+    },
+    domain::{locale::LocaleChain, Pagination},
+    shared::card::CardFormat,
 };
 
 // --------
@@ -16,12 +33,27 @@ pub struct GetByIdResponse {
     pub url: Option<String>,
     pub name: Option<String>,
     pub russian_name: String,
+    /// Names in locales other than `ru` and `en`, keyed by locale.
+    pub name_translations: BTreeMap<String, String>,
     pub country_of_origin: Option<String>,
     pub history: Option<String>,
+    /// Translations of `history`, keyed by locale other than `ru`.
+    pub history_translations: BTreeMap<String, String>,
     pub tags: Option<Vec<TagDto>>,
-    pub tools: Option<Vec<CocktailItemDto>>,
+    pub glass: Option<String>,
+    pub method: Option<PreparationMethodDto>,
+    pub tools: Option<Vec<CocktailToolDto>>,
     pub composition_elements: Option<Vec<CocktailItemDto>>,
     pub recipe: Option<RecipeDto>,
+    pub rating: Option<RatingSummaryDto>,
+    /// Texts in the language from Accept-Language header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localized: Option<LocalizedCocktailDto>,
+    /// Same as ETag header, pass it in If-Match header to change the cocktail.
+    pub version: u64,
+    /// Use in `v1/by-slug/{slug}` and in bot deep links `/start c_{slug}`.
+    pub slug: Option<String>,
+    pub image: Option<CocktailImageDto>,
 }
 
 impl From<Cocktail> for GetByIdResponse {
@@ -31,14 +63,18 @@ impl From<Cocktail> for GetByIdResponse {
             url: value.url,
             name: value.name,
             russian_name: value.russian_name,
+            name_translations: value.name_translations.into(),
             country_of_origin: value.country_of_origin,
             history: value.history,
+            history_translations: value.history_translations.into(),
             tags: value
                 .tags
                 .map(|tags| tags.iter().map(|x| TagDto::from(x)).collect()),
+            glass: value.glass,
+            method: value.method.map(PreparationMethodDto::from),
             tools: value
                 .tools
-                .map(|tools| tools.iter().map(|x| CocktailItemDto::from(x)).collect()),
+                .map(|tools| tools.iter().map(CocktailToolDto::from).collect()),
             composition_elements: value.composition_elements.map(|composition_elements| {
                 composition_elements
                     .iter()
@@ -46,12 +82,65 @@ impl From<Cocktail> for GetByIdResponse {
                     .collect()
             }),
             recipe: value.recipe.map(|recipe| RecipeDto::from(recipe)),
+            rating: value.rating.as_ref().map(RatingSummaryDto::from),
+            localized: None,
+            version: value.version,
+            slug: value.slug,
+            image: value.image.as_ref().map(CocktailImageDto::from),
+        }
+    }
+}
+
+impl GetByIdResponse {
+    /// Response with texts in the first language of the chain having them.
+    pub fn localized(cocktail: Cocktail, chain: &LocaleChain) -> Self {
+        let localized = LocalizedCocktailDto::new(&cocktail, chain);
+        GetByIdResponse {
+            localized: Some(localized),
+            ..GetByIdResponse::from(cocktail)
+        }
+    }
+}
+
+/// Missing translations fall back to english, then to russian.
+#[derive(Serialize, ToSchema, Clone)]
+pub struct LocalizedCocktailDto {
+    pub name: String,
+    pub history: Option<String>,
+    pub recipe_steps: Vec<String>,
+    /// Ingredient names in the order of `composition_elements`.
+    pub ingredients: Vec<String>,
+}
+
+impl LocalizedCocktailDto {
+    pub fn new(cocktail: &Cocktail, chain: &LocaleChain) -> Self {
+        LocalizedCocktailDto {
+            name: cocktail.localized_name(chain).to_string(),
+            history: cocktail.localized_history(chain).map(str::to_string),
+            recipe_steps: cocktail
+                .recipe
+                .as_ref()
+                .map(|recipe| {
+                    recipe
+                        .localized_steps(chain)
+                        .into_iter()
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            ingredients: cocktail
+                .composition_elements
+                .iter()
+                .flatten()
+                .map(|element| element.localized_name(chain).to_string())
+                .collect(),
         }
     }
 }
 
 #[derive(Deserialize, Serialize, ToSchema, Clone)]
 pub struct TagDto {
+    #[schema(min_length = 1, max_length = 30, pattern = r"^[\p{L}\p{N} _'-]+$")]
     pub(crate) name: String,
 }
 
@@ -69,11 +158,71 @@ impl Into<Tag> for TagDto {
     }
 }
 
+#[derive(Deserialize, Serialize, ToSchema, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PreparationMethodDto {
+    Shake,
+    Stir,
+    Build,
+    Blend,
+    Throw,
+}
+
+impl From<PreparationMethod> for PreparationMethodDto {
+    fn from(value: PreparationMethod) -> Self {
+        match value {
+            PreparationMethod::Shake => PreparationMethodDto::Shake,
+            PreparationMethod::Stir => PreparationMethodDto::Stir,
+            PreparationMethod::Build => PreparationMethodDto::Build,
+            PreparationMethod::Blend => PreparationMethodDto::Blend,
+            PreparationMethod::Throw => PreparationMethodDto::Throw,
+        }
+    }
+}
+
+impl From<PreparationMethodDto> for PreparationMethod {
+    fn from(value: PreparationMethodDto) -> Self {
+        match value {
+            PreparationMethodDto::Shake => PreparationMethod::Shake,
+            PreparationMethodDto::Stir => PreparationMethod::Stir,
+            PreparationMethodDto::Build => PreparationMethod::Build,
+            PreparationMethodDto::Blend => PreparationMethod::Blend,
+            PreparationMethodDto::Throw => PreparationMethod::Throw,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Clone)]
+pub struct CocktailToolDto {
+    #[schema(min_length = 1, max_length = 100)]
+    pub(crate) name: String,
+}
+
+impl From<&CocktailTool> for CocktailToolDto {
+    fn from(value: &CocktailTool) -> Self {
+        CocktailToolDto {
+            name: value.name.clone(),
+        }
+    }
+}
+
+impl From<CocktailToolDto> for CocktailTool {
+    fn from(value: CocktailToolDto) -> Self {
+        CocktailTool { name: value.name }
+    }
+}
+
 #[derive(Deserialize, Serialize, ToSchema, Clone)]
 pub struct CocktailItemDto {
+    #[schema(min_length = 1, max_length = 100)]
     pub(crate) name: String,
+    #[schema(minimum = 0)]
     pub(crate) count: i32,
+    #[schema(max_length = 20)]
     pub(crate) unit: String,
+    /// Names shown in locales other than `ru`, keyed by locale.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) display_names: BTreeMap<String, String>,
 }
 
 impl From<&CocktailItem> for CocktailItemDto {
@@ -82,6 +231,7 @@ impl From<&CocktailItem> for CocktailItemDto {
             name: value.name.clone(),
             count: value.count.clone(),
             unit: value.unit.clone(),
+            display_names: value.display_names.clone().into(),
         }
     }
 }
@@ -92,26 +242,185 @@ impl Into<CocktailItem> for CocktailItemDto {
             name: self.name,
             count: self.count,
             unit: self.unit,
+            display_names: self.display_names.into(),
         }
     }
 }
 
 #[derive(Deserialize, Serialize, ToSchema, Clone)]
 pub struct RecipeDto {
+    #[schema(max_items = 50)]
     pub(crate) steps: Vec<String>,
+    /// Translations of the step with the same index, keyed by locale other than `ru`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(max_items = 50)]
+    pub(crate) step_translations: Vec<BTreeMap<String, String>>,
 }
 
 impl From<Recipe> for RecipeDto {
     fn from(value: Recipe) -> Self {
         RecipeDto {
             steps: value.steps.clone(),
+            step_translations: value
+                .step_translations
+                .into_iter()
+                .map(|translations| translations.into())
+                .collect(),
         }
     }
 }
 
 impl Into<Recipe> for RecipeDto {
     fn into(self) -> Recipe {
-        Recipe { steps: self.steps }
+        Recipe {
+            steps: self.steps,
+            step_translations: self
+                .step_translations
+                .into_iter()
+                .map(|translations| translations.into())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RatingSummaryDto {
+    pub average: f64,
+    pub count: u64,
+}
+
+impl From<&RatingSummary> for RatingSummaryDto {
+    fn from(value: &RatingSummary) -> Self {
+        RatingSummaryDto {
+            average: value.average,
+            count: value.count,
+        }
+    }
+}
+
+/// Uploaded photo, variants start with the uploaded file followed by resized JPEG copies.
+#[derive(Serialize, ToSchema, Clone)]
+pub struct CocktailImageDto {
+    pub id: uuid::Uuid,
+    pub uploaded_at: chrono::DateTime<chrono::Utc>,
+    pub variants: Vec<ImageVariantDto>,
+}
+
+impl From<&CocktailImage> for CocktailImageDto {
+    fn from(value: &CocktailImage) -> Self {
+        CocktailImageDto {
+            id: value.id,
+            uploaded_at: value.uploaded_at,
+            variants: value.variants.iter().map(ImageVariantDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ImageVariantDto {
+    pub size: ImageSizeDto,
+    pub width: u32,
+    pub height: u32,
+    pub content_type: String,
+    pub url: String,
+}
+
+impl From<&ImageVariant> for ImageVariantDto {
+    fn from(value: &ImageVariant) -> Self {
+        ImageVariantDto {
+            size: ImageSizeDto::from(value.size),
+            width: value.width,
+            height: value.height,
+            content_type: value.content_type.clone(),
+            url: value.url.clone(),
+        }
+    }
+}
+
+/// `large`, `medium` and `thumbnail` fit into 1280, 640 and 160 pixels square.
+#[derive(Serialize, ToSchema, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageSizeDto {
+    Original,
+    Large,
+    Medium,
+    Thumbnail,
+}
+
+impl From<ImageSize> for ImageSizeDto {
+    fn from(value: ImageSize) -> Self {
+        match value {
+            ImageSize::Original => ImageSizeDto::Original,
+            ImageSize::Large => ImageSizeDto::Large,
+            ImageSize::Medium => ImageSizeDto::Medium,
+            ImageSize::Thumbnail => ImageSizeDto::Thumbnail,
+        }
+    }
+}
+
+/// Multipart form of image upload, describes the request for OpenAPI only.
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ImageUploadForm {
+    /// JPEG, PNG or WebP file up to 10 MiB.
+    #[schema(value_type = String, format = Binary)]
+    pub image: Vec<u8>,
+}
+
+// -----
+// List
+// -----
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct ListRequest {
+    /// Name to search for in russian or english, typos and transliteration are tolerated.
+    /// Results are ordered by relevance, `sort` is ignored.
+    pub q: Option<String>,
+    /// Comma separated tags, cocktails with any of them are returned.
+    pub tag: Option<String>,
+    /// Comma separated countries of origin, cocktails from any of them are returned.
+    pub country: Option<String>,
+    /// Page number starting from 0.
+    pub page: Option<u64>,
+    /// Number of cocktails per page, 10 by default, 50 at most.
+    pub items_per_page: Option<u64>,
+    pub sort: Option<CocktailSortDto>,
+}
+
+fn split_list(value: Option<String>) -> Option<Vec<String>> {
+    value
+        .map(|value| {
+            value
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|items| !items.is_empty())
+}
+
+impl ListRequest {
+    pub fn into_filter(
+        self,
+        default_items_per_page: u64,
+        max_items_per_page: u64,
+    ) -> CocktailFilter {
+        CocktailFilter {
+            ids: None,
+            names: None,
+            russian_names: None,
+            tags: split_list(self.tag),
+            countries: split_list(self.country),
+            sort: self.sort.map(|sort| sort.into()),
+            pagination: Pagination {
+                page: self.page.unwrap_or(0),
+                items_per_page: self
+                    .items_per_page
+                    .unwrap_or(default_items_per_page)
+                    .clamp(1, max_items_per_page),
+            },
+        }
     }
 }
 
@@ -119,16 +428,43 @@ impl Into<Recipe> for RecipeDto {
 // ListByFilter
 // -------------
 
+#[derive(Deserialize, ToSchema, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum CocktailSortDto {
+    Name,
+    Rating,
+}
+
+impl From<CocktailSortDto> for CocktailSort {
+    fn from(value: CocktailSortDto) -> Self {
+        match value {
+            CocktailSortDto::Name => CocktailSort::Name,
+            CocktailSortDto::Rating => CocktailSort::Rating,
+        }
+    }
+}
+
 #[derive(Deserialize, ToSchema, Clone)]
 pub struct ListByFilterRequest {
+    /// Name to search for in russian or english, typos and transliteration are tolerated.
+    /// Results are ordered by relevance, `sort` is ignored.
+    pub query: Option<String>,
     pub ids: Option<Vec<uuid::Uuid>>,
+    pub tags: Option<Vec<String>>,
+    pub countries: Option<Vec<String>>,
+    pub sort: Option<CocktailSortDto>,
     pub pagination: PaginationRequest,
+    /// Return numbers of cocktails per tag, country, base spirit, glass and method
+    /// among all cocktails matching the filter.
+    pub include_facets: Option<bool>,
 }
 
 #[derive(Serialize, ToSchema, Clone)]
 pub struct ListByFilterResponse {
     pub items: Vec<ListByFilterResponseItem>,
     pub total_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<FacetsDto>,
 }
 
 impl From<&CocktailsPaged> for ListByFilterResponse {
@@ -140,10 +476,23 @@ impl From<&CocktailsPaged> for ListByFilterResponse {
                 .map(|cocktail| ListByFilterResponseItem::from(cocktail))
                 .collect(),
             total_count: value.total_count,
+            facets: None,
         }
     }
 }
 
+impl ListByFilterResponse {
+    /// Response with names in the first language of the chain having them.
+    pub fn localized(value: &CocktailsPaged, chain: &LocaleChain) -> Self {
+        let mut response = ListByFilterResponse::from(value);
+        for (item, cocktail) in response.items.iter_mut().zip(&value.items) {
+            item.localized_name = Some(cocktail.localized_name(chain).to_string());
+        }
+
+        response
+    }
+}
+
 #[derive(Serialize, ToSchema, Clone)]
 pub struct ListByFilterResponseItem {
     pub id: uuid::Uuid,
@@ -152,6 +501,12 @@ pub struct ListByFilterResponseItem {
     pub russian_name: String,
     pub country_of_origin: Option<String>,
     pub tags: Option<Vec<TagDto>>,
+    pub rating: Option<RatingSummaryDto>,
+    pub slug: Option<String>,
+    pub image: Option<CocktailImageDto>,
+    /// Name in the language from Accept-Language header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localized_name: Option<String>,
 }
 
 impl From<&Cocktail> for ListByFilterResponseItem {
@@ -166,6 +521,298 @@ impl From<&Cocktail> for ListByFilterResponseItem {
             tags: cloned
                 .tags
                 .map(|tags| tags.iter().map(|x| TagDto::from(x)).collect()),
+            rating: cloned.rating.as_ref().map(RatingSummaryDto::from),
+            slug: cloned.slug,
+            image: cloned.image.as_ref().map(CocktailImageDto::from),
+            localized_name: None,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct FacetValueDto {
+    pub value: String,
+    pub count: u64,
+}
+
+impl From<FacetValue> for FacetValueDto {
+    fn from(value: FacetValue) -> Self {
+        FacetValueDto {
+            value: value.value,
+            count: value.count,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct FacetsDto {
+    pub tags: Vec<FacetValueDto>,
+    pub countries: Vec<FacetValueDto>,
+    pub base_spirits: Vec<FacetValueDto>,
+    pub glasses: Vec<FacetValueDto>,
+    pub methods: Vec<FacetValueDto>,
+}
+
+impl From<CocktailFacets> for FacetsDto {
+    fn from(value: CocktailFacets) -> Self {
+        let map = |values: Vec<FacetValue>| values.into_iter().map(FacetValueDto::from).collect();
+        FacetsDto {
+            tags: map(value.tags),
+            countries: map(value.countries),
+            base_spirits: map(value.base_spirits),
+            glasses: map(value.glasses),
+            methods: map(value.methods),
+        }
+    }
+}
+
+// ------
+// Stats
+// ------
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct StatsResponse {
+    pub cocktails_count: u64,
+    pub tags_count: u64,
+    pub ingredients_count: u64,
+    pub countries_count: u64,
+    pub glasses_count: u64,
+    pub rated_cocktails_count: u64,
+    pub ratings_count: u64,
+    pub average_rating: Option<f64>,
+    pub facets: FacetsDto,
+}
+
+impl From<CatalogueStats> for StatsResponse {
+    fn from(value: CatalogueStats) -> Self {
+        StatsResponse {
+            cocktails_count: value.cocktails_count,
+            tags_count: value.tags_count,
+            ingredients_count: value.ingredients_count,
+            countries_count: value.countries_count,
+            glasses_count: value.glasses_count,
+            rated_cocktails_count: value.rated_cocktails_count,
+            ratings_count: value.ratings_count,
+            average_rating: value.average_rating,
+            facets: value.facets.into(),
+        }
+    }
+}
+
+// -----
+// Card
+// -----
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct CardRequest {
+    pub format: Option<CardFormatDto>,
+    /// Truncates the card to the length, e.g. 4096 for a Telegram message.
+    pub max_length: Option<usize>,
+}
+
+/// `text` by default, `markdown` is Telegram MarkdownV2 the bot sends.
+#[derive(Deserialize, ToSchema, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CardFormatDto {
+    Text,
+    Markdown,
+}
+
+impl From<CardFormatDto> for CardFormat {
+    fn from(value: CardFormatDto) -> Self {
+        match value {
+            CardFormatDto::Text => CardFormat::PlainText,
+            CardFormatDto::Markdown => CardFormat::Markdown,
+        }
+    }
+}
+
+// --------
+// Similar
+// --------
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct SimilarRequest {
+    /// Number of cocktails to return, 5 by default, 20 at most.
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct SimilarCocktailDto {
+    pub id: uuid::Uuid,
+    pub russian_name: String,
+    pub score: f64,
+    pub shared_ingredients: Vec<String>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct SimilarResponse {
+    pub items: Vec<SimilarCocktailDto>,
+}
+
+impl From<Vec<SimilarCocktail>> for SimilarResponse {
+    fn from(value: Vec<SimilarCocktail>) -> Self {
+        SimilarResponse {
+            items: value
+                .into_iter()
+                .map(|similar| SimilarCocktailDto {
+                    id: similar.id,
+                    russian_name: similar.russian_name,
+                    score: similar.score,
+                    shared_ingredients: similar.shared_ingredients,
+                })
+                .collect(),
+        }
+    }
+}
+
+// -------
+// Search
+// -------
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct SearchRequest {
+    /// Words to search for in names, history, recipe steps, ingredients and tags.
+    /// Supports "quoted phrases" and -excluded words.
+    pub q: String,
+    /// Page number starting from 0.
+    pub page: Option<u64>,
+    /// Number of cocktails per page, 10 by default, 50 at most.
+    pub items_per_page: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SnippetFieldDto {
+    Name,
+    Ingredients,
+    Tags,
+    History,
+    Recipe,
+}
+
+impl From<SnippetField> for SnippetFieldDto {
+    fn from(value: SnippetField) -> Self {
+        match value {
+            SnippetField::Name => SnippetFieldDto::Name,
+            SnippetField::Ingredients => SnippetFieldDto::Ingredients,
+            SnippetField::Tags => SnippetFieldDto::Tags,
+            SnippetField::History => SnippetFieldDto::History,
+            SnippetField::Recipe => SnippetFieldDto::Recipe,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct HighlightDto {
+    /// Offset of the first character of matched word in snippet text, in characters.
+    pub start: usize,
+    /// Offset after the last character of matched word, in characters.
+    pub end: usize,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct SnippetDto {
+    pub field: SnippetFieldDto,
+    pub text: String,
+    pub highlights: Vec<HighlightDto>,
+}
+
+impl From<SearchSnippet> for SnippetDto {
+    fn from(value: SearchSnippet) -> Self {
+        SnippetDto {
+            field: value.field.into(),
+            text: value.text,
+            highlights: value
+                .highlights
+                .into_iter()
+                .map(|(start, end)| HighlightDto { start, end })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct SearchResponseItem {
+    pub id: uuid::Uuid,
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub score: f64,
+    pub snippets: Vec<SnippetDto>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct SearchResponse {
+    pub items: Vec<SearchResponseItem>,
+    pub total_count: u64,
+}
+
+impl From<TextSearchPaged> for SearchResponse {
+    fn from(value: TextSearchPaged) -> Self {
+        SearchResponse {
+            items: value
+                .items
+                .into_iter()
+                .map(|hit| SearchResponseItem {
+                    id: hit.cocktail.id,
+                    name: hit.cocktail.name,
+                    russian_name: hit.cocktail.russian_name,
+                    score: hit.score,
+                    snippets: hit.snippets.into_iter().map(SnippetDto::from).collect(),
+                })
+                .collect(),
+            total_count: value.total_count,
+        }
+    }
+}
+
+// -------
+// Random
+// -------
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct RandomRequest {
+    /// Only cocktails with the tag.
+    pub tag: Option<String>,
+    /// Only cocktails without alcohol.
+    pub non_alcoholic: Option<bool>,
+    /// Maximum number of ingredients in cocktail.
+    pub max_ingredients: Option<u32>,
+}
+
+impl From<RandomRequest> for RandomCocktailFilter {
+    fn from(value: RandomRequest) -> Self {
+        RandomCocktailFilter {
+            tag: value.tag,
+            non_alcoholic: value.non_alcoholic.unwrap_or(false),
+            max_ingredients: value.max_ingredients,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct OfTheDayRequest {
+    /// Day in format YYYY-MM-DD, today (UTC) by default.
+    pub date: Option<chrono::NaiveDate>,
+    /// Only cocktails with the tag.
+    pub tag: Option<String>,
+    /// Only cocktails without alcohol.
+    pub non_alcoholic: Option<bool>,
+    /// Maximum number of ingredients in cocktail.
+    pub max_ingredients: Option<u32>,
+}
+
+impl From<OfTheDayRequest> for RandomCocktailFilter {
+    fn from(value: OfTheDayRequest) -> Self {
+        RandomCocktailFilter {
+            tag: value.tag,
+            non_alcoholic: value.non_alcoholic.unwrap_or(false),
+            max_ingredients: value.max_ingredients,
         }
     }
 }
@@ -174,22 +821,40 @@ impl From<&Cocktail> for ListByFilterResponseItem {
 // Create
 // --------
 
+/// Constraints are checked on the server, violations are listed in `errors` of 422 response.
 #[derive(Deserialize, ToSchema)]
 pub struct CreateRequest {
+    #[schema(min_length = 1, max_length = 100)]
     pub name: String,
+    #[schema(min_length = 1, max_length = 100)]
     pub russian_name: String,
+    /// Names in locales other than `ru` and `en`, keyed by locale.
+    #[serde(default)]
+    pub name_translations: BTreeMap<String, String>,
+    #[schema(min_length = 1, max_length = 100)]
     pub country_of_origin: String,
+    #[schema(format = "uri", max_length = 2048)]
     pub url: Option<String>,
+    #[schema(max_length = 5000)]
     pub history: Option<String>,
+    /// Translations of `history`, keyed by locale other than `ru`.
+    #[serde(default)]
+    pub history_translations: BTreeMap<String, String>,
+    #[schema(max_items = 20)]
     pub tags: Option<Vec<TagDto>>,
-    pub tools: Option<Vec<CocktailItemDto>>,
+    #[schema(min_length = 1, max_length = 100)]
+    pub glass: Option<String>,
+    pub method: Option<PreparationMethodDto>,
+    #[schema(max_items = 20)]
+    pub tools: Option<Vec<CocktailToolDto>>,
+    #[schema(min_items = 1, max_items = 30)]
     pub composition_elements: Option<Vec<CocktailItemDto>>,
     pub recipe: Option<RecipeDto>,
 }
 
 impl Into<Cocktail> for CreateRequest {
     fn into(self) -> Cocktail {
-        Cocktail::new(
+        let mut cocktail = Cocktail::new(
             Some(self.name),
             self.russian_name,
             self.url,
@@ -197,6 +862,8 @@ impl Into<Cocktail> for CreateRequest {
             self.history,
             self.tags
                 .map(|tags| tags.iter().map(|tag| tag.clone().into()).collect()),
+            self.glass,
+            self.method.map(|method| method.into()),
             self.tools
                 .map(|tools| tools.iter().map(|tool| tool.clone().into()).collect()),
             self.composition_elements.map(|compos_elements| {
@@ -205,9 +872,12 @@ impl Into<Cocktail> for CreateRequest {
                     .map(|compos_element| compos_element.clone().into())
                     .collect()
             }),
-            self.recipe
-                .map(|recipe| recipe.clone().into()),
-        )
+            self.recipe.map(|recipe| recipe.clone().into()),
+        );
+        cocktail.name_translations = self.name_translations.into();
+        cocktail.history_translations = self.history_translations.into();
+
+        cocktail
     }
 }
 
@@ -215,32 +885,56 @@ impl Into<Cocktail> for CreateRequest {
 // Update
 // --------
 
+/// Constraints are checked on the server, violations are listed in `errors` of 422 response.
 #[derive(Deserialize, ToSchema, Clone)]
 pub struct UpdateRequest {
     pub id: uuid::Uuid,
+    #[schema(min_length = 1, max_length = 100)]
     pub name: String,
+    #[schema(min_length = 1, max_length = 100)]
     pub russian_name: String,
+    /// Names in locales other than `ru` and `en`, keyed by locale.
+    #[serde(default)]
+    pub name_translations: BTreeMap<String, String>,
+    #[schema(min_length = 1, max_length = 100)]
     pub country_of_origin: String,
+    #[schema(format = "uri", max_length = 2048)]
     pub url: Option<String>,
+    #[schema(max_length = 5000)]
     pub history: Option<String>,
+    /// Translations of `history`, keyed by locale other than `ru`.
+    #[serde(default)]
+    pub history_translations: BTreeMap<String, String>,
+    #[schema(max_items = 20)]
     pub tags: Option<Vec<TagDto>>,
-    pub tools: Option<Vec<CocktailItemDto>>,
+    #[schema(min_length = 1, max_length = 100)]
+    pub glass: Option<String>,
+    pub method: Option<PreparationMethodDto>,
+    #[schema(max_items = 20)]
+    pub tools: Option<Vec<CocktailToolDto>>,
+    #[schema(min_items = 1, max_items = 30)]
     pub composition_elements: Option<Vec<CocktailItemDto>>,
     pub recipe: Option<RecipeDto>,
 }
 
 impl Into<Cocktail> for UpdateRequest {
     fn into(self) -> Cocktail {
-        Cocktail{
+        Cocktail {
             id: self.id,
             name: Some(self.name),
             russian_name: self.russian_name,
+            name_translations: self.name_translations.into(),
             url: self.url,
             country_of_origin: Some(self.country_of_origin),
             history: self.history,
-            tags: self.tags
+            history_translations: self.history_translations.into(),
+            tags: self
+                .tags
                 .map(|tags| tags.iter().map(|tag| tag.clone().into()).collect()),
-            tools: self.tools
+            glass: self.glass,
+            method: self.method.map(|method| method.into()),
+            tools: self
+                .tools
                 .map(|tools| tools.iter().map(|tool| tool.clone().into()).collect()),
             composition_elements: self.composition_elements.map(|compos_elements| {
                 compos_elements
@@ -248,8 +942,137 @@ impl Into<Cocktail> for UpdateRequest {
                     .map(|compos_element| compos_element.clone().into())
                     .collect()
             }),
-            recipe: self.recipe
-                .map(|recipe| recipe.clone().into()),
+            recipe: self.recipe.map(|recipe| recipe.clone().into()),
+            rating: None,
+            updated_at: Some(chrono::Utc::now()),
+            // expected version comes from If-Match header
+            version: 0,
+            deleted_at: None,
+            // slug is kept or derived from the names of the stored cocktail
+            slug: None,
+            previous_slugs: vec![],
+            // image is kept, it is changed only by image upload
+            image: None,
+        }
+    }
+}
+
+// ------
+// Patch
+// ------
+
+/// Content types of supported patch formats.
+pub const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+pub const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
+pub enum PatchFormat {
+    /// RFC 7396, also used for plain `application/json`.
+    MergePatch,
+    /// RFC 6902.
+    JsonPatch,
+}
+
+impl PatchFormat {
+    pub fn from_content_type(content_type: Option<&str>) -> Option<Self> {
+        let mime = content_type?.split(';').next()?.trim().to_lowercase();
+        match mime.as_str() {
+            MERGE_PATCH_CONTENT_TYPE | "application/json" => Some(PatchFormat::MergePatch),
+            JSON_PATCH_CONTENT_TYPE => Some(PatchFormat::JsonPatch),
+            _ => None,
+        }
+    }
+}
+
+/// Editable fields of cocktail, patches are applied to this document.
+/// Absent and null fields are removed from the cocktail.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PatchDocument {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(format = "uri", max_length = 2048)]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: Option<String>,
+    #[schema(min_length = 1, max_length = 100)]
+    pub russian_name: String,
+    /// Always present, so JSON Patch can add a locale to it.
+    #[serde(default)]
+    pub name_translations: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(min_length = 1, max_length = 100)]
+    pub country_of_origin: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(max_length = 5000)]
+    pub history: Option<String>,
+    #[serde(default)]
+    pub history_translations: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(max_items = 20)]
+    pub tags: Option<Vec<TagDto>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(min_length = 1, max_length = 100)]
+    pub glass: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<PreparationMethodDto>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(max_items = 20)]
+    pub tools: Option<Vec<CocktailToolDto>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(min_items = 1, max_items = 30)]
+    pub composition_elements: Option<Vec<CocktailItemDto>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipe: Option<RecipeDto>,
+}
+
+impl From<&Cocktail> for PatchDocument {
+    fn from(value: &Cocktail) -> Self {
+        let response = GetByIdResponse::from(value.clone());
+        PatchDocument {
+            url: response.url,
+            name: response.name,
+            russian_name: response.russian_name,
+            name_translations: response.name_translations,
+            country_of_origin: response.country_of_origin,
+            history: response.history,
+            history_translations: response.history_translations,
+            tags: response.tags,
+            glass: response.glass,
+            method: response.method,
+            tools: response.tools,
+            composition_elements: response.composition_elements,
+            recipe: response.recipe,
+        }
+    }
+}
+
+impl PatchDocument {
+    /// Returns the cocktail with editable fields taken from the document.
+    pub fn apply_to(self, cocktail: &Cocktail) -> Cocktail {
+        Cocktail {
+            url: self.url,
+            name: self.name,
+            russian_name: self.russian_name,
+            name_translations: self.name_translations.into(),
+            country_of_origin: self.country_of_origin,
+            history: self.history,
+            history_translations: self.history_translations.into(),
+            tags: self
+                .tags
+                .map(|tags| tags.into_iter().map(|tag| tag.into()).collect()),
+            glass: self.glass,
+            method: self.method.map(|method| method.into()),
+            tools: self
+                .tools
+                .map(|tools| tools.into_iter().map(|tool| tool.into()).collect()),
+            composition_elements: self.composition_elements.map(|compos_elements| {
+                compos_elements
+                    .into_iter()
+                    .map(|compos_element| compos_element.into())
+                    .collect()
+            }),
+            recipe: self.recipe.map(|recipe| recipe.into()),
+            ..cocktail.clone()
         }
     }
 }
@@ -262,3 +1085,226 @@ impl Into<Cocktail> for UpdateRequest {
 pub struct DeleteRequest {
     pub id: uuid::Uuid,
 }
+
+// -------------
+// ShoppingList
+// -------------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct CocktailServingsDto {
+    pub cocktail_id: uuid::Uuid,
+    pub servings: Option<u32>,
+}
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct OwnedIngredientDto {
+    pub name: String,
+    pub amount: Option<f64>,
+    pub unit: Option<String>,
+}
+
+impl From<OwnedIngredientDto> for OwnedIngredient {
+    fn from(value: OwnedIngredientDto) -> Self {
+        OwnedIngredient {
+            name: value.name,
+            amount: value.amount,
+            unit: value.unit,
+        }
+    }
+}
+
+/// Cocktails are taken from all given sources: explicit list, user favorites and tag.
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct ShoppingListRequest {
+    pub cocktails: Option<Vec<CocktailServingsDto>>,
+    /// Telegram id of user whose favorite cocktails are added to the list.
+    pub favorites_of: Option<u64>,
+    pub tag: Option<String>,
+    /// Servings for cocktails without explicitly set servings, 1 by default.
+    pub servings: Option<u32>,
+    pub owned_ingredients: Option<Vec<OwnedIngredientDto>>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ShoppingListItemDto {
+    pub name: String,
+    pub amount: f64,
+    pub unit: String,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ShoppingListResponse {
+    pub items: Vec<ShoppingListItemDto>,
+    pub owned: Vec<String>,
+    pub not_found_cocktails: Vec<uuid::Uuid>,
+}
+
+impl From<ShoppingList> for ShoppingListResponse {
+    fn from(value: ShoppingList) -> Self {
+        ShoppingListResponse {
+            items: value
+                .items
+                .into_iter()
+                .map(|item| ShoppingListItemDto {
+                    name: item.name,
+                    amount: item.amount,
+                    unit: item.unit,
+                })
+                .collect(),
+            owned: value.owned,
+            not_found_cocktails: value.not_found_cocktails,
+        }
+    }
+}
+
+// ----------
+// Revisions
+// ----------
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct RevisionsRequest {
+    /// Page number starting from 0.
+    pub page: Option<u64>,
+    /// Number of revisions per page, 10 by default, 50 at most.
+    pub items_per_page: Option<u64>,
+}
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct RevisionDiffRequest {
+    /// Number of the revision to compare from.
+    pub from: u64,
+    /// Number of the revision to compare to.
+    pub to: u64,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RevisionActionDto {
+    Create,
+    Update,
+    Delete,
+    Restore,
+}
+
+impl From<RevisionAction> for RevisionActionDto {
+    fn from(value: RevisionAction) -> Self {
+        match value {
+            RevisionAction::Create => RevisionActionDto::Create,
+            RevisionAction::Update => RevisionActionDto::Update,
+            RevisionAction::Delete => RevisionActionDto::Delete,
+            RevisionAction::Restore => RevisionActionDto::Restore,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RevisionDto {
+    pub number: u64,
+    pub action: RevisionActionDto,
+    pub author: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// JSON Patch (RFC 6902) from the previous revision.
+    #[schema(value_type = Vec<Object>)]
+    pub diff: json_patch::Patch,
+}
+
+impl From<&CocktailRevision> for RevisionDto {
+    fn from(value: &CocktailRevision) -> Self {
+        RevisionDto {
+            number: value.number,
+            action: value.action.into(),
+            author: value.author.clone(),
+            created_at: value.created_at,
+            diff: value.diff.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RevisionsResponse {
+    pub items: Vec<RevisionDto>,
+    pub total_count: u64,
+}
+
+impl From<RevisionsPaged> for RevisionsResponse {
+    fn from(value: RevisionsPaged) -> Self {
+        RevisionsResponse {
+            items: value.items.iter().map(RevisionDto::from).collect(),
+            total_count: value.total_count,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RevisionResponse {
+    #[serde(flatten)]
+    pub revision: RevisionDto,
+    /// Cocktail as it was after the change.
+    pub snapshot: GetByIdResponse,
+}
+
+impl From<CocktailRevision> for RevisionResponse {
+    fn from(value: CocktailRevision) -> Self {
+        RevisionResponse {
+            revision: RevisionDto::from(&value),
+            snapshot: GetByIdResponse::from(value.snapshot),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RevisionDiffResponse {
+    pub from: u64,
+    pub to: u64,
+    /// JSON Patch (RFC 6902) turning the first revision into the second one.
+    #[schema(value_type = Vec<Object>)]
+    pub diff: json_patch::Patch,
+}
+
+// ------
+// Trash
+// ------
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct TrashRequest {
+    /// Page number starting from 0.
+    pub page: Option<u64>,
+    /// Number of cocktails per page, 10 by default, 50 at most.
+    pub items_per_page: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct TrashItemDto {
+    pub id: uuid::Uuid,
+    pub russian_name: String,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Version to send in `If-Match` as `"<version>"` when restoring the cocktail.
+    pub version: u64,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct TrashResponse {
+    pub items: Vec<TrashItemDto>,
+    pub total_count: u64,
+}
+
+impl From<CocktailsPaged> for TrashResponse {
+    fn from(value: CocktailsPaged) -> Self {
+        TrashResponse {
+            items: value
+                .items
+                .into_iter()
+                .map(|cocktail| TrashItemDto {
+                    id: cocktail.id,
+                    russian_name: cocktail.russian_name,
+                    deleted_at: cocktail.deleted_at,
+                    version: cocktail.version,
+                })
+                .collect(),
+            total_count: value.total_count,
+        }
+    }
+}
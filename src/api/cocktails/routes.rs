@@ -1,18 +1,108 @@
-use warp::{filters::BoxedFilter, Filter};
+use warp::{filters::BoxedFilter, multipart::FormData, Filter};
 
-use crate::{api::common::api_prefix, json_body};
+use crate::{
+    api::common::{accept_language, api_prefix},
+    domain::{locale::LocaleChain, services::images::MAX_IMAGE_SIZE},
+    json_body,
+};
 
-use super::models::{CreateRequest, UpdateRequest, DeleteRequest, ListByFilterRequest};
+use super::models::{
+    CardRequest, CreateRequest, DeleteRequest, ListByFilterRequest, ListRequest, OfTheDayRequest,
+    RandomRequest, RevisionDiffRequest, RevisionsRequest, SearchRequest, ShoppingListRequest,
+    SimilarRequest, TrashRequest, UpdateRequest,
+};
 
 fn path_prefix() -> BoxedFilter<()> {
     warp::path!("cocktails" / "v1" / ..).boxed()
 }
 
-pub fn get_by_id() -> BoxedFilter<(uuid::Uuid,)> {
+pub fn list(
+    cache_control: String,
+) -> BoxedFilter<(ListRequest, Option<String>, LocaleChain, String)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::end())
+        .and(warp::query::<ListRequest>())
+        .and(warp::header::optional::<String>("if-none-match"))
+        .and(accept_language())
+        .and(warp::any().map(move || cache_control.clone()))
+        .boxed()
+}
+
+pub fn get_by_id() -> BoxedFilter<(uuid::Uuid, LocaleChain)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path::end())
+        .and(accept_language())
+        .boxed()
+}
+
+pub fn card() -> BoxedFilter<(uuid::Uuid, CardRequest, LocaleChain)> {
     warp::get()
         .and(api_prefix())
         .and(path_prefix())
         .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("card"))
+        .and(warp::path::end())
+        .and(warp::query::<CardRequest>())
+        .and(accept_language())
+        .boxed()
+}
+
+pub fn get_by_slug() -> BoxedFilter<(String, LocaleChain)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("by-slug"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(accept_language())
+        .boxed()
+}
+
+pub fn similar() -> BoxedFilter<(uuid::Uuid, SimilarRequest)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("similar"))
+        .and(warp::path::end())
+        .and(warp::query::<SimilarRequest>())
+        .boxed()
+}
+
+pub fn search() -> BoxedFilter<(SearchRequest,)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("search").boxed())
+        .and(warp::path::end())
+        .and(warp::query::<SearchRequest>())
+        .boxed()
+}
+
+pub fn random() -> BoxedFilter<(RandomRequest, LocaleChain)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("random").boxed())
+        .and(warp::path::end())
+        .and(warp::query::<RandomRequest>())
+        .and(accept_language())
+        .boxed()
+}
+
+pub fn of_the_day() -> BoxedFilter<(OfTheDayRequest, LocaleChain)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("of-the-day").boxed())
+        .and(warp::path::end())
+        .and(warp::query::<OfTheDayRequest>())
+        .and(accept_language())
         .boxed()
 }
 
@@ -26,29 +116,171 @@ pub fn list_by_filter() -> BoxedFilter<(ListByFilterRequest,)> {
         .boxed()
 }
 
-pub fn create() -> BoxedFilter<(CreateRequest,)> {
+pub fn stats() -> BoxedFilter<()> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("stats").boxed())
+        .and(warp::path::end())
+        .boxed()
+}
+
+pub fn create() -> BoxedFilter<(Option<String>, CreateRequest)> {
     warp::post()
         .and(api_prefix())
         .and(path_prefix())
         .and(warp::path::end())
+        .and(warp::header::optional::<String>("x-author"))
         .and(json_body!())
         .boxed()
 }
 
-pub fn update() -> BoxedFilter<(UpdateRequest,)> {
+pub fn update() -> BoxedFilter<(Option<String>, Option<String>, UpdateRequest)> {
     warp::put()
         .and(api_prefix())
         .and(path_prefix())
         .and(warp::path::end())
+        .and(warp::header::optional::<String>("if-match"))
+        .and(warp::header::optional::<String>("x-author"))
         .and(json_body!())
         .boxed()
 }
 
-pub fn delete() -> BoxedFilter<(DeleteRequest,)> {
+/// Cocktail id, `If-Match`, `X-Author` and `Content-Type` headers and raw patch body.
+type PatchRequest = (
+    uuid::Uuid,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    warp::hyper::body::Bytes,
+);
+
+pub fn patch() -> BoxedFilter<PatchRequest> {
+    warp::patch()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>("if-match"))
+        .and(warp::header::optional::<String>("x-author"))
+        .and(warp::header::optional::<String>("content-type"))
+        .and(warp::body::content_length_limit(1024 * 16))
+        .and(warp::body::bytes())
+        .boxed()
+}
+
+pub fn delete() -> BoxedFilter<(Option<String>, Option<String>, DeleteRequest)> {
     warp::delete()
         .and(api_prefix())
         .and(path_prefix())
         .and(warp::path::end())
+        .and(warp::header::optional::<String>("if-match"))
+        .and(warp::header::optional::<String>("x-author"))
+        .and(json_body!())
+        .boxed()
+}
+
+pub fn shopping_list() -> BoxedFilter<(ShoppingListRequest,)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("shopping-list").boxed())
+        .and(warp::path::end())
         .and(json_body!())
         .boxed()
 }
+
+pub fn revisions() -> BoxedFilter<(uuid::Uuid, RevisionsRequest)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("revisions"))
+        .and(warp::path::end())
+        .and(warp::query::<RevisionsRequest>())
+        .boxed()
+}
+
+pub fn revision_diff() -> BoxedFilter<(uuid::Uuid, RevisionDiffRequest)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("revisions"))
+        .and(warp::path("diff"))
+        .and(warp::path::end())
+        .and(warp::query::<RevisionDiffRequest>())
+        .boxed()
+}
+
+pub fn revision() -> BoxedFilter<(uuid::Uuid, u64)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("revisions"))
+        .and(warp::path::param::<u64>())
+        .and(warp::path::end())
+        .boxed()
+}
+
+pub fn restore_revision() -> BoxedFilter<(uuid::Uuid, u64, Option<String>, Option<String>)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("revisions"))
+        .and(warp::path::param::<u64>())
+        .and(warp::path("restore"))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>("if-match"))
+        .and(warp::header::optional::<String>("x-author"))
+        .boxed()
+}
+
+pub fn trash() -> BoxedFilter<(TrashRequest,)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("trash"))
+        .and(warp::path::end())
+        .and(warp::query::<TrashRequest>())
+        .boxed()
+}
+
+pub fn restore_from_trash() -> BoxedFilter<(uuid::Uuid, Option<String>, Option<String>)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("trash"))
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("restore"))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>("if-match"))
+        .and(warp::header::optional::<String>("x-author"))
+        .boxed()
+}
+
+pub fn upload_image() -> BoxedFilter<(uuid::Uuid, Option<String>, FormData)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("image"))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>("if-match"))
+        // room for multipart boundaries and part headers
+        .and(warp::multipart::form().max_length(MAX_IMAGE_SIZE + 1024 * 16))
+        .boxed()
+}
+
+pub fn delete_image() -> BoxedFilter<(uuid::Uuid, Option<String>)> {
+    warp::delete()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<uuid::Uuid>())
+        .and(warp::path("image"))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>("if-match"))
+        .boxed()
+}
@@ -0,0 +1,208 @@
+use warp::http::StatusCode;
+
+use crate::{
+    api::common::{error_reply, ErrorResponse},
+    domain::{
+        aggregates::{
+            collection::{Collection, CollectionFilter, CollectionRepo},
+            user::UserRepo,
+        },
+        Pagination,
+    },
+    infrastructure,
+};
+
+use super::models::{
+    CreateRequest, CreateResponse, DeleteRequest, GetByIdResponse, ListByFilterRequest,
+    ListByFilterResponse, UpdateRequest,
+};
+
+#[utoipa::path(
+        get,
+        path = "v1/{id}",
+        params(
+            ("id" = uuid::Uuid, Path, description = "Identifier of collection.")
+        ),
+        responses(
+            (status = 200, description = "Get by id is ended successfully", body = [GetByIdResponse])
+        )
+    )]
+pub async fn get_by_id(id: uuid::Uuid) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let collection_repository = repository_factory
+        .get_collections_repository()
+        .await
+        .unwrap();
+    let collection_from_db = collection_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about collection from db");
+    let result = match collection_from_db {
+        Some(collection) => collection,
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply::json(&GetByIdResponse::from(result)))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/by-filter",
+    request_body = ListByFilterRequest,
+    responses(
+        (status = 200, description = "Get by filter is ended successfully", body = [ListByFilterResponse])
+    )
+)]
+pub async fn list_by_filter(
+    filter: ListByFilterRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let collection_repository = repository_factory
+        .get_collections_repository()
+        .await
+        .unwrap();
+    let owner_id = match filter.telegram_id {
+        Some(telegram_id) => {
+            let user_repository = repository_factory.get_user_repository().await.unwrap();
+            let user = user_repository
+                .get_by_telegram_id(&telegram_id)
+                .await
+                .expect("Error while get information about user from db");
+            match user {
+                Some(user) => Some(user.id),
+                None => return Err(warp::reject::not_found()),
+            }
+        }
+        None => None,
+    };
+    let collection_filter = CollectionFilter {
+        ids: filter.ids,
+        owner_id,
+        pagination: Pagination {
+            page: filter.pagination.page,
+            items_per_page: filter.pagination.items_per_page,
+        },
+    };
+    let collections_list = collection_repository
+        .get_by_filter(&collection_filter)
+        .await
+        .expect("Error while get information about collections from db");
+
+    Ok(warp::reply::json(&ListByFilterResponse::from(
+        &collections_list,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1",
+    request_body = CreateRequest,
+    responses(
+        (status = 200, description = "Collection created successfully", body = [CreateResponse]),
+        (status = 404, description = "Owner not found", body = [ErrorResponse])
+    )
+)]
+pub async fn create(request: CreateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let user_repository = repository_factory.get_user_repository().await.unwrap();
+    let collection_repository = repository_factory
+        .get_collections_repository()
+        .await
+        .unwrap();
+
+    let user = user_repository
+        .get_by_telegram_id(&request.telegram_id)
+        .await
+        .expect("Error while get information about user from db");
+    let user = match user {
+        Some(user) => user,
+        None => {
+            return Ok(error_reply(
+                format!("user with telegram id {} not found", request.telegram_id),
+                StatusCode::NOT_FOUND,
+            ))
+        }
+    };
+
+    let mut collection = Collection::new(user.id, request.name, request.description);
+    for cocktail_id in request.cocktail_ids.unwrap_or_default() {
+        collection.add_cocktail(&cocktail_id);
+    }
+    collection_repository
+        .create(&collection)
+        .await
+        .expect("Error while create collection in db");
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&CreateResponse { id: collection.id }),
+        StatusCode::OK,
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "v1",
+    request_body = UpdateRequest,
+    responses(
+        (status = 200, description = "Update collection status")
+    )
+)]
+pub async fn update(request: UpdateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let collection_repository = repository_factory
+        .get_collections_repository()
+        .await
+        .unwrap();
+    let collection_from_db = collection_repository
+        .get_by_id(&request.id)
+        .await
+        .expect("Error while get information about collection from db");
+    match collection_from_db {
+        Some(mut collection) => {
+            collection.name = request.name;
+            collection.description = request.description;
+            collection.cocktail_ids = vec![];
+            for cocktail_id in request.cocktail_ids {
+                collection.add_cocktail(&cocktail_id);
+            }
+            collection_repository
+                .update(&collection)
+                .await
+                .expect("Error while update collection in db");
+        }
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply())
+}
+
+#[utoipa::path(
+    delete,
+    path = "v1",
+    request_body = DeleteRequest,
+    responses(
+        (status = 200, description = "Delete collection status")
+    )
+)]
+pub async fn delete(request: DeleteRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let collection_repository = repository_factory
+        .get_collections_repository()
+        .await
+        .unwrap();
+    let collection_from_db = collection_repository
+        .get_by_id(&request.id)
+        .await
+        .expect("Error while get information about collection from db");
+    match collection_from_db {
+        Some(collection) => {
+            collection_repository
+                .delete(&collection)
+                .await
+                .expect("Error while delete collection from db");
+        }
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply())
+}
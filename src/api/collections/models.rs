@@ -0,0 +1,109 @@
+#![allow(clippy::from_over_into)]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    api::common::PaginationRequest,
+    domain::aggregates::collection::{Collection, CollectionsPaged},
+};
+
+// --------
+// GetById
+// --------
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct GetByIdResponse {
+    pub id: uuid::Uuid,
+    pub owner_id: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// Cocktails in the order defined by collection owner.
+    pub cocktail_ids: Vec<uuid::Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Collection> for GetByIdResponse {
+    fn from(value: Collection) -> Self {
+        GetByIdResponse {
+            id: value.id,
+            owner_id: value.owner_id,
+            name: value.name,
+            description: value.description,
+            cocktail_ids: value.cocktail_ids,
+            created_at: value.created_at,
+        }
+    }
+}
+
+// -------------
+// ListByFilter
+// -------------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct ListByFilterRequest {
+    pub ids: Option<Vec<uuid::Uuid>>,
+    /// Telegram id of collections owner.
+    pub telegram_id: Option<u64>,
+    pub pagination: PaginationRequest,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ListByFilterResponse {
+    pub items: Vec<GetByIdResponse>,
+    pub total_count: u64,
+}
+
+impl From<&CollectionsPaged> for ListByFilterResponse {
+    fn from(value: &CollectionsPaged) -> Self {
+        ListByFilterResponse {
+            items: value
+                .items
+                .iter()
+                .map(|collection| GetByIdResponse::from(collection.clone()))
+                .collect(),
+            total_count: value.total_count,
+        }
+    }
+}
+
+// --------
+// Create
+// --------
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateRequest {
+    /// Telegram id of collection owner.
+    pub telegram_id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    pub cocktail_ids: Option<Vec<uuid::Uuid>>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct CreateResponse {
+    pub id: uuid::Uuid,
+}
+
+// --------
+// Update
+// --------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct UpdateRequest {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// Replaces cocktails of collection, order is preserved.
+    pub cocktail_ids: Vec<uuid::Uuid>,
+}
+
+// --------
+// Delete
+// --------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct DeleteRequest {
+    pub id: uuid::Uuid,
+}
@@ -1,11 +1,131 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use utoipa::ToSchema;
-use warp::{filters::BoxedFilter, Filter};
+use warp::{filters::BoxedFilter, http::header, Filter};
+
+use crate::domain::{aggregates::cocktail::FieldError, locale::LocaleChain};
 
 pub fn api_prefix() -> BoxedFilter<()> {
     warp::path("api").boxed()
 }
 
+/// Languages of localised texts from `Accept-Language` header. Responses depending on it
+/// should have `Vary: Accept-Language`.
+pub fn accept_language() -> BoxedFilter<(LocaleChain,)> {
+    warp::header::optional::<String>("accept-language")
+        .map(|header: Option<String>| LocaleChain::from_accept_language(header.as_deref()))
+        .boxed()
+}
+
+pub fn error_reply(
+    message: impl Into<String>,
+    status: warp::http::StatusCode,
+) -> warp::reply::WithStatus<warp::reply::Json> {
+    warp::reply::with_status(
+        warp::reply::json(&ErrorResponse {
+            message: message.into(),
+            errors: vec![],
+        }),
+        status,
+    )
+}
+
+/// Logs unexpected error and replies `500 Internal Server Error` without its details.
+pub fn internal_error_reply(err: anyhow::Error) -> warp::reply::WithStatus<warp::reply::Json> {
+    log::error!("Error while process request: {:?}", err);
+    error_reply(
+        "Internal server error",
+        warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+    )
+}
+
+/// Replies `422 Unprocessable Entity` listing violated constraints of the fields.
+pub fn validation_error_reply(
+    message: impl Into<String>,
+    errors: &[FieldError],
+) -> warp::reply::WithStatus<warp::reply::Json> {
+    warp::reply::with_status(
+        warp::reply::json(&ErrorResponse {
+            message: message.into(),
+            errors: errors.iter().map(FieldErrorDto::from).collect(),
+        }),
+        warp::http::StatusCode::UNPROCESSABLE_ENTITY,
+    )
+}
+
+/// Serializes value to JSON with `ETag`, `Last-Modified` and `Cache-Control` headers.
+/// Replies `304 Not Modified` without body when `If-None-Match` contains the current tag.
+pub fn cached_json_reply(
+    value: &impl Serialize,
+    last_modified: Option<chrono::DateTime<chrono::Utc>>,
+    if_none_match: Option<String>,
+    cache_control: &str,
+) -> warp::http::Response<Vec<u8>> {
+    let body = serde_json::to_vec(value).unwrap();
+    let etag = format!("\"{:x}\"", Sha256::digest(&body));
+    let mut response = warp::http::Response::builder()
+        .header(header::ETAG, &etag)
+        .header(header::CACHE_CONTROL, cache_control);
+    if let Some(last_modified) = last_modified {
+        response = response.header(
+            header::LAST_MODIFIED,
+            last_modified
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string(),
+        );
+    }
+
+    let not_modified = if_none_match.is_some_and(|if_none_match| {
+        if_none_match.split(',').any(|tag| {
+            let tag = tag.trim();
+            tag == "*" || tag.trim_start_matches("W/") == etag
+        })
+    });
+    if not_modified {
+        response
+            .status(warp::http::StatusCode::NOT_MODIFIED)
+            .body(vec![])
+            .unwrap()
+    } else {
+        response
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .unwrap()
+    }
+}
+
+/// Strong entity tag of a stored entity version.
+pub fn version_etag(version: u64) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Checks `If-Match` header of a modifying request against the current entity tag.
+/// Replies `428 Precondition Required` when the header is missing
+/// and `412 Precondition Failed` when no tag matches.
+pub fn check_if_match(
+    if_match: Option<&str>,
+    etag: &str,
+) -> Result<(), warp::reply::WithStatus<warp::reply::Json>> {
+    let Some(if_match) = if_match else {
+        return Err(error_reply(
+            "If-Match header is required",
+            warp::http::StatusCode::PRECONDITION_REQUIRED,
+        ));
+    };
+    let matches = if_match.split(',').any(|tag| {
+        let tag = tag.trim();
+        tag == "*" || tag == etag
+    });
+    if matches {
+        Ok(())
+    } else {
+        Err(error_reply(
+            "Entity was changed, get the current version and retry",
+            warp::http::StatusCode::PRECONDITION_FAILED,
+        ))
+    }
+}
+
 #[macro_export]
 macro_rules! json_body {
     () => {
@@ -18,3 +138,27 @@ pub struct PaginationRequest {
     pub page: u64,
     pub items_per_page: u64,
 }
+
+#[derive(Serialize, Clone, ToSchema)]
+pub struct ErrorResponse {
+    pub message: String,
+    /// Field-level errors of request validation.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldErrorDto>,
+}
+
+#[derive(Serialize, Clone, ToSchema)]
+pub struct FieldErrorDto {
+    /// Path of the field, like `composition_elements[2].count`.
+    pub field: String,
+    pub message: String,
+}
+
+impl From<&FieldError> for FieldErrorDto {
+    fn from(value: &FieldError) -> Self {
+        FieldErrorDto {
+            field: value.field.clone(),
+            message: value.message.clone(),
+        }
+    }
+}
@@ -4,4 +4,7 @@ use envconfig::Envconfig;
 pub struct ApiConfiguration {
     #[envconfig(from = "HTTP_PORT", default = "80")]
     pub http_port: u16,
+    /// Value of `Cache-Control` header of cacheable GET responses.
+    #[envconfig(from = "HTTP_CACHE_CONTROL", default = "public, max-age=60")]
+    pub cache_control: String,
 }
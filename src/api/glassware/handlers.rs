@@ -0,0 +1,142 @@
+use crate::{
+    domain::{
+        aggregates::glassware::{GlasswareFilter, GlasswareRepo},
+        Pagination,
+    },
+    infrastructure,
+};
+
+use super::models::{
+    CreateRequest, DeleteRequest, GetByIdResponse, ListByFilterRequest, ListByFilterResponse,
+    UpdateRequest,
+};
+
+#[utoipa::path(
+        get,
+        path = "v1/{id}",
+        params(
+            ("id" = uuid::Uuid, Path, description = "Identifier of glassware.")
+        ),
+        responses(
+            (status = 200, description = "Get by id is ended successfully", body = [GetByIdResponse])
+        )
+    )]
+pub async fn get_by_id(id: uuid::Uuid) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let glassware_repository = repository_factory.get_glassware_repository().await.unwrap();
+    let glassware_from_db = glassware_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about glassware from db");
+    let result = match glassware_from_db {
+        Some(glassware) => glassware,
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply::json(&GetByIdResponse::from(result)))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/by-filter",
+    request_body = ListByFilterRequest,
+    responses(
+        (status = 200, description = "Get by filter is ended successfully", body = [ListByFilterResponse])
+    )
+)]
+pub async fn list_by_filter(
+    filter: ListByFilterRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let glassware_repository = repository_factory.get_glassware_repository().await.unwrap();
+    let glassware_filter = GlasswareFilter {
+        ids: filter.ids,
+        pagination: Pagination {
+            page: filter.pagination.page,
+            items_per_page: filter.pagination.items_per_page,
+        },
+    };
+    let glassware_list = glassware_repository
+        .get_by_filter(&glassware_filter)
+        .await
+        .expect("Error while get information about glassware from db");
+
+    Ok(warp::reply::json(&ListByFilterResponse::from(
+        &glassware_list,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1",
+    request_body = CreateRequest,
+    responses(
+        (status = 200, description = "Create glassware status")
+    )
+)]
+pub async fn create(request: CreateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let glassware_repository = repository_factory.get_glassware_repository().await.unwrap();
+    glassware_repository
+        .create(&request.into())
+        .await
+        .expect("Error while create glassware in db");
+
+    Ok(warp::reply())
+}
+
+#[utoipa::path(
+    put,
+    path = "v1",
+    request_body = UpdateRequest,
+    responses(
+        (status = 200, description = "Update glassware status")
+    )
+)]
+pub async fn update(request: UpdateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let glassware_repository = repository_factory.get_glassware_repository().await.unwrap();
+    let glassware_from_db = glassware_repository
+        .get_by_id(&request.id)
+        .await
+        .expect("Error while get information about glassware from db");
+    match glassware_from_db {
+        Some(_) => {
+            glassware_repository
+                .update(&request.into())
+                .await
+                .expect("Error while update glassware in db");
+        }
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply())
+}
+
+#[utoipa::path(
+    delete,
+    path = "v1",
+    request_body = DeleteRequest,
+    responses(
+        (status = 200, description = "Delete glassware status")
+    )
+)]
+pub async fn delete(request: DeleteRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let glassware_repository = repository_factory.get_glassware_repository().await.unwrap();
+    let glassware_from_db = glassware_repository
+        .get_by_id(&request.id)
+        .await
+        .expect("Error while get information about glassware from db");
+    match glassware_from_db {
+        Some(glassware) => {
+            glassware_repository
+                .delete(&glassware)
+                .await
+                .expect("Error while delete glassware from db");
+        }
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply())
+}
@@ -0,0 +1,120 @@
+#![allow(clippy::from_over_into)]
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    api::common::PaginationRequest,
+    domain::aggregates::glassware::{Glassware, GlasswarePaged},
+};
+
+// --------
+// GetById
+// --------
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct GetByIdResponse {
+    pub id: uuid::Uuid,
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub volume_ml: Option<i32>,
+    pub description: Option<String>,
+}
+
+impl From<Glassware> for GetByIdResponse {
+    fn from(value: Glassware) -> Self {
+        GetByIdResponse {
+            id: value.id,
+            name: value.name,
+            russian_name: value.russian_name,
+            volume_ml: value.volume_ml,
+            description: value.description,
+        }
+    }
+}
+
+// -------------
+// ListByFilter
+// -------------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct ListByFilterRequest {
+    pub ids: Option<Vec<uuid::Uuid>>,
+    pub pagination: PaginationRequest,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ListByFilterResponse {
+    pub items: Vec<GetByIdResponse>,
+    pub total_count: u64,
+}
+
+impl From<&GlasswarePaged> for ListByFilterResponse {
+    fn from(value: &GlasswarePaged) -> Self {
+        ListByFilterResponse {
+            items: value
+                .items
+                .iter()
+                .map(|glassware| GetByIdResponse::from(glassware.clone()))
+                .collect(),
+            total_count: value.total_count,
+        }
+    }
+}
+
+// --------
+// Create
+// --------
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateRequest {
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub volume_ml: Option<i32>,
+    pub description: Option<String>,
+}
+
+impl Into<Glassware> for CreateRequest {
+    fn into(self) -> Glassware {
+        Glassware::new(
+            self.name,
+            self.russian_name,
+            self.volume_ml,
+            self.description,
+        )
+    }
+}
+
+// --------
+// Update
+// --------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct UpdateRequest {
+    pub id: uuid::Uuid,
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub volume_ml: Option<i32>,
+    pub description: Option<String>,
+}
+
+impl Into<Glassware> for UpdateRequest {
+    fn into(self) -> Glassware {
+        Glassware {
+            id: self.id,
+            name: self.name,
+            russian_name: self.russian_name,
+            volume_ml: self.volume_ml,
+            description: self.description,
+        }
+    }
+}
+
+// --------
+// Delete
+// --------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct DeleteRequest {
+    pub id: uuid::Uuid,
+}
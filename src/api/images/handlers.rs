@@ -0,0 +1,37 @@
+use warp::{http::header, path::Tail};
+
+use crate::infrastructure;
+
+/// Keys contain the id of the uploaded image, so the file behind an URL never changes.
+const IMAGE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+#[utoipa::path(
+    get,
+    path = "v1/{key}",
+    params(
+        ("key" = String, Path, description = "Key of the image file from variant url, may contain slashes.")
+    ),
+    responses(
+        (status = 200, description = "Image file", content_type = "image/*"),
+        (status = 404, description = "File not found")
+    )
+)]
+pub async fn get(key: Tail) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let blob_store = repository_factory
+        .get_blob_store()
+        .expect("Error while create blob store");
+    let blob = blob_store
+        .get(key.as_str())
+        .await
+        .expect("Error while read image file");
+    let Some(blob) = blob else {
+        return Err(warp::reject::not_found());
+    };
+
+    Ok(warp::http::Response::builder()
+        .header(header::CONTENT_TYPE, blob.content_type)
+        .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+        .body(blob.data)
+        .unwrap())
+}
@@ -0,0 +1,15 @@
+use utoipa::OpenApi;
+use warp::Filter;
+
+// private modules
+mod handlers;
+mod routes;
+
+#[derive(OpenApi)]
+#[openapi(paths(handlers::get))]
+pub struct ImagesApi;
+
+pub fn use_images_api(
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    routes::get().and_then(handlers::get)
+}
@@ -0,0 +1,15 @@
+use warp::{filters::BoxedFilter, path::Tail, Filter};
+
+use crate::api::common::api_prefix;
+
+fn path_prefix() -> BoxedFilter<()> {
+    warp::path!("images" / "v1" / ..).boxed()
+}
+
+pub fn get() -> BoxedFilter<(Tail,)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::tail())
+        .boxed()
+}
@@ -1,14 +1,28 @@
 // private modules
 mod cocktails;
+mod collections;
 pub(crate) mod common;
+mod glassware;
+mod images;
+mod ratings;
+mod recommendations;
 mod system;
+mod tags;
+mod tools;
 
 // public modules
 pub mod configurations;
 
 use cocktails::use_cocktails_api;
+use collections::use_collections_api;
 use configurations::ApiConfiguration;
+use glassware::use_glassware_api;
+use images::use_images_api;
+use ratings::use_ratings_api;
+use recommendations::use_recommendations_api;
 use system::use_system_api;
+use tags::use_tags_api;
+use tools::use_tools_api;
 use warp::{filters::cors::Builder, http::Method, Filter};
 
 pub struct ApiProvider {
@@ -25,8 +39,15 @@ impl ApiProvider {
 
 impl ApiProvider {
     pub async fn start_server(&self) {
-        
-        let api = use_system_api().or(use_cocktails_api());
+        let api = use_system_api()
+            .or(use_cocktails_api(&self.api_configuration))
+            .or(use_tools_api())
+            .or(use_glassware_api())
+            .or(use_tags_api())
+            .or(use_ratings_api())
+            .or(use_collections_api())
+            .or(use_recommendations_api())
+            .or(use_images_api());
 
         let routes = api.with(self.add_cors()).with(warp::log("api"));
 
@@ -40,8 +61,14 @@ impl ApiProvider {
 impl ApiProvider {
     fn add_cors(&self) -> Builder {
         warp::cors()
-            .allow_methods(&[Method::GET, Method::POST])
-            .allow_headers(vec!["content-type"])
+            .allow_methods(&[Method::GET, Method::POST, Method::PATCH, Method::DELETE])
+            .allow_headers(vec![
+                "content-type",
+                "if-none-match",
+                "if-match",
+                "x-author",
+            ])
+            .expose_headers(vec!["etag", "last-modified", "cache-control"])
             .allow_credentials(true)
     }
 }
@@ -0,0 +1,108 @@
+use warp::http::StatusCode;
+
+use crate::{
+    api::common::{error_reply, internal_error_reply, ErrorResponse},
+    domain::{
+        aggregates::{
+            rating::{RatingError, RatingFilter, RatingRepo},
+            user::UserRepo,
+        },
+        services::ratings,
+        Pagination,
+    },
+    infrastructure,
+};
+
+use super::models::{ListByFilterRequest, ListByFilterResponse, RateRequest, RateResponse};
+
+#[utoipa::path(
+    post,
+    path = "v1",
+    request_body = RateRequest,
+    responses(
+        (status = 200, description = "Cocktail rated successfully", body = [RateResponse]),
+        (status = 400, description = "Invalid rating", body = [ErrorResponse]),
+        (status = 404, description = "User or cocktail not found", body = [ErrorResponse])
+    )
+)]
+pub async fn rate(request: RateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let user_repository = repository_factory.get_user_repository().await.unwrap();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let rating_repository = repository_factory.get_ratings_repository().await.unwrap();
+
+    let user = user_repository
+        .get_by_telegram_id(&request.telegram_id)
+        .await
+        .expect("Error while get information about user from db");
+    let user = match user {
+        Some(user) => user,
+        None => {
+            return Ok(error_reply(
+                format!("user with telegram id {} not found", request.telegram_id),
+                StatusCode::NOT_FOUND,
+            ))
+        }
+    };
+
+    let rate_result = ratings::rate_cocktail(
+        &rating_repository,
+        &cocktail_repository,
+        &user.id,
+        &request.cocktail_id,
+        request.stars,
+        request.review,
+    )
+    .await;
+    match rate_result {
+        Ok(summary) => Ok(warp::reply::with_status(
+            warp::reply::json(&RateResponse::from(&summary)),
+            StatusCode::OK,
+        )),
+        Err(err) => match err.downcast_ref::<RatingError>() {
+            Some(RatingError::InvalidStars(_)) => {
+                Ok(error_reply(err.to_string(), StatusCode::BAD_REQUEST))
+            }
+            Some(RatingError::CocktailNotFound(_)) => {
+                Ok(error_reply(err.to_string(), StatusCode::NOT_FOUND))
+            }
+            None => Ok(internal_error_reply(err)),
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/by-filter",
+    request_body = ListByFilterRequest,
+    responses(
+        (status = 200, description = "Get reviews by filter is ended successfully", body = [ListByFilterResponse])
+    )
+)]
+pub async fn list_by_filter(
+    filter: ListByFilterRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let rating_repository = repository_factory.get_ratings_repository().await.unwrap();
+    let rating_filter = RatingFilter {
+        cocktail_id: filter.cocktail_id,
+        with_review_only: filter.with_review_only.unwrap_or(false),
+        pagination: Pagination {
+            page: filter.pagination.page,
+            items_per_page: filter.pagination.items_per_page,
+        },
+    };
+    let ratings_list = rating_repository
+        .get_by_filter(&rating_filter)
+        .await
+        .expect("Error while get ratings from db");
+    let summary = rating_repository
+        .get_summary(&filter.cocktail_id)
+        .await
+        .expect("Error while get rating summary from db");
+
+    Ok(warp::reply::json(&ListByFilterResponse::new(
+        &ratings_list,
+        &summary,
+    )))
+}
@@ -0,0 +1,18 @@
+use utoipa::OpenApi;
+use warp::Filter;
+
+// private modules
+mod handlers;
+mod models;
+mod routes;
+
+#[derive(OpenApi)]
+#[openapi(paths(handlers::rate, handlers::list_by_filter))]
+pub struct RatingsApi;
+
+pub fn use_ratings_api(
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    routes::rate()
+        .and_then(handlers::rate)
+        .or(routes::list_by_filter().and_then(handlers::list_by_filter))
+}
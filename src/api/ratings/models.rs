@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    api::common::PaginationRequest,
+    domain::aggregates::rating::{Rating, RatingSummary, RatingsPaged},
+};
+
+// -----
+// Rate
+// -----
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct RateRequest {
+    pub telegram_id: u64,
+    pub cocktail_id: uuid::Uuid,
+    /// From 1 to 5.
+    pub stars: u8,
+    pub review: Option<String>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RateResponse {
+    pub average: f64,
+    pub count: u64,
+}
+
+impl From<&RatingSummary> for RateResponse {
+    fn from(value: &RatingSummary) -> Self {
+        RateResponse {
+            average: value.average,
+            count: value.count,
+        }
+    }
+}
+
+// -------------
+// ListByFilter
+// -------------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct ListByFilterRequest {
+    pub cocktail_id: uuid::Uuid,
+    pub with_review_only: Option<bool>,
+    pub pagination: PaginationRequest,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ListByFilterResponse {
+    pub items: Vec<ListByFilterResponseItem>,
+    pub total_count: u64,
+    pub average: f64,
+    pub ratings_count: u64,
+}
+
+impl ListByFilterResponse {
+    pub fn new(ratings: &RatingsPaged, summary: &RatingSummary) -> Self {
+        ListByFilterResponse {
+            items: ratings
+                .items
+                .iter()
+                .map(ListByFilterResponseItem::from)
+                .collect(),
+            total_count: ratings.total_count,
+            average: summary.average,
+            ratings_count: summary.count,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ListByFilterResponseItem {
+    pub stars: u8,
+    pub review: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Rating> for ListByFilterResponseItem {
+    fn from(value: &Rating) -> Self {
+        ListByFilterResponseItem {
+            stars: value.stars,
+            review: value.review.clone(),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
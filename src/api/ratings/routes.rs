@@ -0,0 +1,28 @@
+use warp::{filters::BoxedFilter, Filter};
+
+use crate::{api::common::api_prefix, json_body};
+
+use super::models::{ListByFilterRequest, RateRequest};
+
+fn path_prefix() -> BoxedFilter<()> {
+    warp::path!("ratings" / "v1" / ..).boxed()
+}
+
+pub fn rate() -> BoxedFilter<(RateRequest,)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::end())
+        .and(json_body!())
+        .boxed()
+}
+
+pub fn list_by_filter() -> BoxedFilter<(ListByFilterRequest,)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("by-filter"))
+        .and(warp::path::end())
+        .and(json_body!())
+        .boxed()
+}
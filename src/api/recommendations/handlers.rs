@@ -0,0 +1,69 @@
+use warp::http::StatusCode;
+
+use crate::{
+    api::common::{error_reply, ErrorResponse},
+    domain::{aggregates::user::UserRepo, services::recommendations},
+    infrastructure,
+};
+
+use super::models::{GetForUserRequest, GetForUserResponse, RecommendationDto};
+
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 50;
+
+#[utoipa::path(
+    get,
+    path = "v1/{telegram_id}",
+    params(
+        ("telegram_id" = u64, Path, description = "Telegram id of user."),
+        GetForUserRequest
+    ),
+    responses(
+        (status = 200, description = "Recommendations for user, the best first", body = [GetForUserResponse]),
+        (status = 404, description = "User not found", body = [ErrorResponse])
+    )
+)]
+pub async fn get_for_user(
+    telegram_id: u64,
+    request: GetForUserRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let user_repository = repository_factory.get_user_repository().await.unwrap();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let rating_repository = repository_factory.get_ratings_repository().await.unwrap();
+
+    let user = user_repository
+        .get_by_telegram_id(&telegram_id)
+        .await
+        .expect("Error while get information about user from db");
+    let user = match user {
+        Some(user) => user,
+        None => {
+            return Ok(error_reply(
+                format!("user with telegram id {} not found", telegram_id),
+                StatusCode::NOT_FOUND,
+            ))
+        }
+    };
+
+    let limit = request.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let recommendations = recommendations::get_recommendations(
+        &cocktail_repository,
+        &rating_repository,
+        &user,
+        limit,
+    )
+    .await
+    .expect("Error while get recommendations from db");
+
+    let response = GetForUserResponse {
+        items: recommendations
+            .iter()
+            .map(RecommendationDto::from)
+            .collect(),
+    };
+    Ok(warp::reply::with_status(
+        warp::reply::json(&response),
+        StatusCode::OK,
+    ))
+}
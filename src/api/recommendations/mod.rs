@@ -0,0 +1,16 @@
+use utoipa::OpenApi;
+use warp::Filter;
+
+// private modules
+mod handlers;
+mod models;
+mod routes;
+
+#[derive(OpenApi)]
+#[openapi(paths(handlers::get_for_user))]
+pub struct RecommendationsApi;
+
+pub fn use_recommendations_api(
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    routes::get_for_user().and_then(handlers::get_for_user)
+}
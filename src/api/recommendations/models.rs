@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::services::recommendations::{Recommendation, RecommendationReason};
+
+// ------------
+// GetForUser
+// ------------
+
+#[derive(Deserialize, IntoParams, Clone)]
+#[into_params(parameter_in = Query)]
+pub struct GetForUserRequest {
+    /// Number of cocktails to return, 10 by default.
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RecommendationReasonDto {
+    Favorite,
+    Rated,
+    Viewed,
+}
+
+impl From<&RecommendationReason> for RecommendationReasonDto {
+    fn from(value: &RecommendationReason) -> Self {
+        match value {
+            RecommendationReason::Favorite => RecommendationReasonDto::Favorite,
+            RecommendationReason::Rated => RecommendationReasonDto::Rated,
+            RecommendationReason::Viewed => RecommendationReasonDto::Viewed,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RecommendationDto {
+    pub id: uuid::Uuid,
+    pub russian_name: String,
+    pub score: f64,
+    pub reason: RecommendationReasonDto,
+    pub because_of_id: uuid::Uuid,
+    /// Human readable explanation, e.g. "Потому что вам нравится Негрони".
+    pub explanation: String,
+}
+
+impl From<&Recommendation> for RecommendationDto {
+    fn from(value: &Recommendation) -> Self {
+        RecommendationDto {
+            id: value.id,
+            russian_name: value.russian_name.clone(),
+            score: value.score,
+            reason: RecommendationReasonDto::from(&value.reason),
+            because_of_id: value.because_of_id,
+            explanation: value.explanation(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct GetForUserResponse {
+    pub items: Vec<RecommendationDto>,
+}
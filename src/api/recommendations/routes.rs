@@ -0,0 +1,19 @@
+use warp::{filters::BoxedFilter, Filter};
+
+use crate::api::common::api_prefix;
+
+use super::models::GetForUserRequest;
+
+fn path_prefix() -> BoxedFilter<()> {
+    warp::path!("recommendations" / "v1" / ..).boxed()
+}
+
+pub fn get_for_user() -> BoxedFilter<(u64, GetForUserRequest)> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::param::<u64>())
+        .and(warp::path::end())
+        .and(warp::query::<GetForUserRequest>())
+        .boxed()
+}
@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
 use utoipa_swagger_ui::Config;
-use warp::{filters::path::{FullPath, Tail}, reply::Reply};
+use warp::{
+    filters::path::{FullPath, Tail},
+    reply::Reply,
+};
 
 pub async fn healthcheck_handler() -> Result<impl Reply, warp::Rejection> {
     const MESSAGE: &str = "healthy";
@@ -15,9 +18,9 @@ pub async fn serve_swagger_handler(
     config: Arc<Config<'static>>,
 ) -> Result<Box<dyn Reply + 'static>, warp::Rejection> {
     if full_path.as_str() == "/swagger-ui" {
-        return Ok(Box::new(warp::redirect::found(warp::http::Uri::from_static(
-            "/swagger-ui/",
-        ))));
+        return Ok(Box::new(warp::redirect::found(
+            warp::http::Uri::from_static("/swagger-ui/"),
+        )));
     }
 
     let path = tail.as_str();
@@ -6,7 +6,14 @@ use utoipa::{
 #[derive(OpenApi)]
 #[openapi(
         nest(
-            (path = "/api/cocktails/", api = crate::api::cocktails::CocktailsApi)
+            (path = "/api/cocktails/", api = crate::api::cocktails::CocktailsApi),
+            (path = "/api/tools/", api = crate::api::tools::ToolsApi),
+            (path = "/api/glassware/", api = crate::api::glassware::GlasswareApi),
+            (path = "/api/tags/", api = crate::api::tags::TagsApi),
+            (path = "/api/ratings/", api = crate::api::ratings::RatingsApi),
+            (path = "/api/collections/", api = crate::api::collections::CollectionsApi),
+            (path = "/api/recommendations/", api = crate::api::recommendations::RecommendationsApi),
+            (path = "/api/images/", api = crate::api::images::ImagesApi)
         ),
         modifiers(&SecurityAddon)
     )]
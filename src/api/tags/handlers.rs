@@ -0,0 +1,93 @@
+use crate::{
+    domain::{
+        aggregates::cocktail::CocktailRepo,
+        services::{search, similarity, tags},
+    },
+    infrastructure,
+};
+
+use super::models::{ListResponse, MergeRequest, ModifiedResponse, RenameRequest};
+
+#[utoipa::path(
+    get,
+    path = "v1",
+    responses(
+        (status = 200, description = "List of tags with cocktails count", body = [ListResponse])
+    )
+)]
+pub async fn list() -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let tags = cocktail_repository
+        .get_tags()
+        .await
+        .expect("Error while get tags from db");
+
+    Ok(warp::reply::json(&ListResponse::from(&tags)))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/rename",
+    request_body = RenameRequest,
+    params(
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history of changed cocktails")
+    ),
+    responses(
+        (status = 200, description = "Tag renamed in all cocktails", body = [ModifiedResponse])
+    )
+)]
+pub async fn rename(
+    request: RenameRequest,
+    author: Option<String>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let modified_count = tags::rename(
+        &cocktail_repository,
+        &revision_repository,
+        &request.from,
+        &request.to,
+        author,
+    )
+    .await
+    .expect("Error while rename tag in db");
+    similarity::invalidate_cache();
+    search::invalidate_cache();
+
+    Ok(warp::reply::json(&ModifiedResponse { modified_count }))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/merge",
+    request_body = MergeRequest,
+    params(
+        ("X-Author" = Option<String>, Header, description = "Who makes the change, saved in revision history of changed cocktails")
+    ),
+    responses(
+        (status = 200, description = "Tags merged in all cocktails", body = [ModifiedResponse])
+    )
+)]
+pub async fn merge(
+    request: MergeRequest,
+    author: Option<String>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory.get_cocktails_repository().await.unwrap();
+    let revision_repository = repository_factory.get_revisions_repository().await.unwrap();
+    let modified_count = tags::merge(
+        &cocktail_repository,
+        &revision_repository,
+        &request.sources,
+        &request.target,
+        author,
+    )
+    .await
+    .expect("Error while merge tags in db");
+    similarity::invalidate_cache();
+    search::invalidate_cache();
+
+    Ok(warp::reply::json(&ModifiedResponse { modified_count }))
+}
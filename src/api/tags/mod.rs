@@ -0,0 +1,19 @@
+use utoipa::OpenApi;
+use warp::Filter;
+
+// private modules
+mod handlers;
+mod models;
+mod routes;
+
+#[derive(OpenApi)]
+#[openapi(paths(handlers::list, handlers::rename, handlers::merge))]
+pub struct TagsApi;
+
+pub fn use_tags_api() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+{
+    routes::list()
+        .and_then(handlers::list)
+        .or(routes::rename().and_then(handlers::rename))
+        .or(routes::merge().and_then(handlers::merge))
+}
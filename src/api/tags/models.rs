@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::aggregates::cocktail::TagWithCount;
+
+// -----
+// List
+// -----
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ListResponse {
+    pub items: Vec<ListResponseItem>,
+}
+
+impl From<&Vec<TagWithCount>> for ListResponse {
+    fn from(value: &Vec<TagWithCount>) -> Self {
+        ListResponse {
+            items: value.iter().map(ListResponseItem::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ListResponseItem {
+    pub name: String,
+    pub cocktails_count: u64,
+}
+
+impl From<&TagWithCount> for ListResponseItem {
+    fn from(value: &TagWithCount) -> Self {
+        ListResponseItem {
+            name: value.name.clone(),
+            cocktails_count: value.cocktails_count,
+        }
+    }
+}
+
+// -------
+// Rename
+// -------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct RenameRequest {
+    pub from: String,
+    pub to: String,
+}
+
+// ------
+// Merge
+// ------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct MergeRequest {
+    pub sources: Vec<String>,
+    pub target: String,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ModifiedResponse {
+    pub modified_count: u64,
+}
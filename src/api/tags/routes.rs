@@ -0,0 +1,39 @@
+use warp::{filters::BoxedFilter, Filter};
+
+use crate::{api::common::api_prefix, json_body};
+
+use super::models::{MergeRequest, RenameRequest};
+
+fn path_prefix() -> BoxedFilter<()> {
+    warp::path!("tags" / "v1" / ..).boxed()
+}
+
+pub fn list() -> BoxedFilter<()> {
+    warp::get()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path::end())
+        .boxed()
+}
+
+pub fn rename() -> BoxedFilter<(RenameRequest, Option<String>)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("rename"))
+        .and(warp::path::end())
+        .and(json_body!())
+        .and(warp::header::optional::<String>("x-author"))
+        .boxed()
+}
+
+pub fn merge() -> BoxedFilter<(MergeRequest, Option<String>)> {
+    warp::post()
+        .and(api_prefix())
+        .and(path_prefix())
+        .and(warp::path("merge"))
+        .and(warp::path::end())
+        .and(json_body!())
+        .and(warp::header::optional::<String>("x-author"))
+        .boxed()
+}
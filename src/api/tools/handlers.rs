@@ -0,0 +1,140 @@
+use crate::{
+    domain::{
+        aggregates::tool::{ToolFilter, ToolRepo},
+        Pagination,
+    },
+    infrastructure,
+};
+
+use super::models::{
+    CreateRequest, DeleteRequest, GetByIdResponse, ListByFilterRequest, ListByFilterResponse,
+    UpdateRequest,
+};
+
+#[utoipa::path(
+        get,
+        path = "v1/{id}",
+        params(
+            ("id" = uuid::Uuid, Path, description = "Identifier of bar tool.")
+        ),
+        responses(
+            (status = 200, description = "Get by id is ended successfully", body = [GetByIdResponse])
+        )
+    )]
+pub async fn get_by_id(id: uuid::Uuid) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let tool_repository = repository_factory.get_tools_repository().await.unwrap();
+    let tool_from_db = tool_repository
+        .get_by_id(&id)
+        .await
+        .expect("Error while get information about tool from db");
+    let result = match tool_from_db {
+        Some(tool) => tool,
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply::json(&GetByIdResponse::from(result)))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1/by-filter",
+    request_body = ListByFilterRequest,
+    responses(
+        (status = 200, description = "Get by filter is ended successfully", body = [ListByFilterResponse])
+    )
+)]
+pub async fn list_by_filter(
+    filter: ListByFilterRequest,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let tool_repository = repository_factory.get_tools_repository().await.unwrap();
+    let tool_filter = ToolFilter {
+        ids: filter.ids,
+        pagination: Pagination {
+            page: filter.pagination.page,
+            items_per_page: filter.pagination.items_per_page,
+        },
+    };
+    let tools_list = tool_repository
+        .get_by_filter(&tool_filter)
+        .await
+        .expect("Error while get information about tools from db");
+
+    Ok(warp::reply::json(&ListByFilterResponse::from(&tools_list)))
+}
+
+#[utoipa::path(
+    post,
+    path = "v1",
+    request_body = CreateRequest,
+    responses(
+        (status = 200, description = "Create bar tool status")
+    )
+)]
+pub async fn create(request: CreateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let tool_repository = repository_factory.get_tools_repository().await.unwrap();
+    tool_repository
+        .create(&request.into())
+        .await
+        .expect("Error while create tool in db");
+
+    Ok(warp::reply())
+}
+
+#[utoipa::path(
+    put,
+    path = "v1",
+    request_body = UpdateRequest,
+    responses(
+        (status = 200, description = "Update bar tool status")
+    )
+)]
+pub async fn update(request: UpdateRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let tool_repository = repository_factory.get_tools_repository().await.unwrap();
+    let tool_from_db = tool_repository
+        .get_by_id(&request.id)
+        .await
+        .expect("Error while get information about tool from db");
+    match tool_from_db {
+        Some(_) => {
+            tool_repository
+                .update(&request.into())
+                .await
+                .expect("Error while update tool in db");
+        }
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply())
+}
+
+#[utoipa::path(
+    delete,
+    path = "v1",
+    request_body = DeleteRequest,
+    responses(
+        (status = 200, description = "Delete bar tool status")
+    )
+)]
+pub async fn delete(request: DeleteRequest) -> Result<impl warp::Reply, warp::Rejection> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let tool_repository = repository_factory.get_tools_repository().await.unwrap();
+    let tool_from_db = tool_repository
+        .get_by_id(&request.id)
+        .await
+        .expect("Error while get information about tool from db");
+    match tool_from_db {
+        Some(tool) => {
+            tool_repository
+                .delete(&tool)
+                .await
+                .expect("Error while delete tool from db");
+        }
+        None => return Err(warp::reject::not_found()),
+    };
+
+    Ok(warp::reply())
+}
@@ -0,0 +1,110 @@
+#![allow(clippy::from_over_into)]
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    api::common::PaginationRequest,
+    domain::aggregates::tool::{Tool, ToolsPaged},
+};
+
+// --------
+// GetById
+// --------
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct GetByIdResponse {
+    pub id: uuid::Uuid,
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub description: Option<String>,
+}
+
+impl From<Tool> for GetByIdResponse {
+    fn from(value: Tool) -> Self {
+        GetByIdResponse {
+            id: value.id,
+            name: value.name,
+            russian_name: value.russian_name,
+            description: value.description,
+        }
+    }
+}
+
+// -------------
+// ListByFilter
+// -------------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct ListByFilterRequest {
+    pub ids: Option<Vec<uuid::Uuid>>,
+    pub pagination: PaginationRequest,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ListByFilterResponse {
+    pub items: Vec<GetByIdResponse>,
+    pub total_count: u64,
+}
+
+impl From<&ToolsPaged> for ListByFilterResponse {
+    fn from(value: &ToolsPaged) -> Self {
+        ListByFilterResponse {
+            items: value
+                .items
+                .iter()
+                .map(|tool| GetByIdResponse::from(tool.clone()))
+                .collect(),
+            total_count: value.total_count,
+        }
+    }
+}
+
+// --------
+// Create
+// --------
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateRequest {
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub description: Option<String>,
+}
+
+impl Into<Tool> for CreateRequest {
+    fn into(self) -> Tool {
+        Tool::new(self.name, self.russian_name, self.description)
+    }
+}
+
+// --------
+// Update
+// --------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct UpdateRequest {
+    pub id: uuid::Uuid,
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub description: Option<String>,
+}
+
+impl Into<Tool> for UpdateRequest {
+    fn into(self) -> Tool {
+        Tool {
+            id: self.id,
+            name: self.name,
+            russian_name: self.russian_name,
+            description: self.description,
+        }
+    }
+}
+
+// --------
+// Delete
+// --------
+
+#[derive(Deserialize, ToSchema, Clone)]
+pub struct DeleteRequest {
+    pub id: uuid::Uuid,
+}
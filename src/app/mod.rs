@@ -1,8 +1,12 @@
+pub mod slug_backfill;
+pub mod trash_purge;
+
 use envconfig::Envconfig;
 
 use crate::{
-    api::configurations::ApiConfiguration, bot::configurations::BotConfig,
-    infrastructure::configurations::DbConfiguration,
+    api::configurations::ApiConfiguration,
+    bot::configurations::BotConfig,
+    infrastructure::configurations::{BlobStoreConfiguration, DbConfiguration},
 };
 
 #[derive(Envconfig, Debug, Clone)]
@@ -13,6 +17,11 @@ pub struct Config {
     pub db_configuration: DbConfiguration,
     #[envconfig(nested)]
     pub api_configuration: ApiConfiguration,
+    #[envconfig(nested)]
+    pub blob_store_configuration: BlobStoreConfiguration,
+    /// Days a deleted cocktail is kept in trash before it is removed for good.
+    #[envconfig(from = "TRASH_RETENTION_DAYS", default = "30")]
+    pub trash_retention_days: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,22 @@
+use anyhow::{Context, Result};
+
+use crate::{domain::services::slugs, infrastructure};
+
+/// Gives slugs to cocktails stored before slugs were introduced. Runs once on start.
+pub async fn start_slug_backfill() {
+    match backfill_cocktail_slugs().await {
+        Ok(0) => {}
+        Ok(count) => log::info!("Assigned slugs to {} cocktails", count),
+        Err(err) => log::error!("Failed to assign slugs to cocktails: {:?}", err),
+    }
+}
+
+async fn backfill_cocktail_slugs() -> Result<u64> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory
+        .get_cocktails_repository()
+        .await
+        .context("failed to create cocktail repo in slug backfill")?;
+
+    slugs::backfill(&cocktail_repository).await
+}
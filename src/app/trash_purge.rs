@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::{domain::services::trash, infrastructure};
+
+/// How often trash is checked for cocktails kept longer than retention period.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Purges expired cocktails from trash. Runs until the application stops.
+pub async fn start_trash_purge_job(retention_days: i64) {
+    let retention = chrono::Duration::days(retention_days);
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        if let Err(err) = purge_expired_cocktails(retention).await {
+            log::error!("Failed to purge cocktails from trash: {:?}", err);
+        }
+    }
+}
+
+async fn purge_expired_cocktails(retention: chrono::Duration) -> Result<()> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let cocktail_repository = repository_factory
+        .get_cocktails_repository()
+        .await
+        .context("failed to create cocktail repo in trash purge job")?;
+    let user_repository = repository_factory
+        .get_user_repository()
+        .await
+        .context("failed to create user repo in trash purge job")?;
+    let collection_repository = repository_factory
+        .get_collections_repository()
+        .await
+        .context("failed to create collection repo in trash purge job")?;
+    let rating_repository = repository_factory
+        .get_ratings_repository()
+        .await
+        .context("failed to create rating repo in trash purge job")?;
+    let blob_store = repository_factory
+        .get_blob_store()
+        .context("failed to create blob store in trash purge job")?;
+
+    let purged = trash::purge_expired(
+        &cocktail_repository,
+        &user_repository,
+        &collection_repository,
+        &rating_repository,
+        blob_store.as_ref(),
+        retention,
+    )
+    .await?;
+    if !purged.is_empty() {
+        log::info!("Purged {} cocktails from trash", purged.len());
+    }
+
+    Ok(())
+}
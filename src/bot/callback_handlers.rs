@@ -1,7 +1,12 @@
 use std::error::Error;
 
 use teloxide::{
-    adaptors::DefaultParseMode, dispatching::dialogue::GetChatId, prelude::Requester, types::{CallbackQuery, UserId}, utils::markdown::escape, Bot as TBot
+    adaptors::DefaultParseMode,
+    dispatching::dialogue::GetChatId,
+    prelude::Requester,
+    types::{CallbackQuery, UserId},
+    utils::markdown::escape,
+    Bot as TBot,
 };
 
 use crate::{
@@ -9,14 +14,26 @@ use crate::{
         commands::MenuCommands,
         dialogue::State,
         message_processor::{
-            AddCocktailToFavoriteCommand, GetCocktailPageByIdCommand, GetCocktailPagesCommand,
-            GetCocktailsFilterByNameListCommand, GetCocktailsListCommand,
-            GetFavoriteCocktailsListCommand, GetMainMenuCommand, GetProfilePageCommand,
-            GetRegisterUserConfigrationCommand, GetRemoveUserConfirmationCommand, MessageProcessor,
-            RegisterUserCommand, RemoveCocktailFromFavoriteCommand, RemoveUserCommand,
+            is_photo_message, AddBarIngredientCommand, AddCocktailToFavoriteCommand,
+            ChangeDailySubscriptionCommand, DailySubscriptionChange, DailySubscriptionChooser,
+            GetCocktailPageByIdCommand, GetCocktailPagesCommand,
+            GetCocktailsFilterByCollectionListCommand, GetCocktailsFilterByNameListCommand,
+            GetCocktailsFilterByTagListCommand, GetCocktailsListCommand,
+            GetCollectionChooserCommand, GetCollectionsListCommand,
+            GetDailySubscriptionChooserCommand, GetDailySubscriptionPageCommand,
+            GetFavoriteCocktailsListCommand, GetFavoriteNoteFormCommand, GetLanguageChooserCommand,
+            GetMainMenuCommand, GetMyBarPageCommand, GetProfilePageCommand,
+            GetRecommendationsCommand, GetRegisterUserConfigrationCommand,
+            GetRemoveUserConfirmationCommand, GetSimilarCocktailsCommand, GetTagsListCommand,
+            GetWhatCanIMakeCommand, MessageProcessor, RateCocktailCommand, RegisterUserCommand,
+            RemoveBarIngredientCommand, RemoveCocktailFromFavoriteCommand, RemoveUserCommand,
+            SendShoppingListCommand, SetFavoriteNoteCommand, SetLanguageCommand,
+            ShoppingListSource, ToggleCollectionCocktailCommand,
         },
     },
-    shared::CommandHandler,
+    domain::aggregates::cocktail::{CocktailRepo, RandomCocktailFilter},
+    infrastructure,
+    shared::{i18n::Language, CommandHandler},
 };
 
 use super::BotDialogue;
@@ -48,11 +65,15 @@ pub async fn default_callback_handler(
                     .await?;
             }
             MenuCommands::SearchByName => {
+                let message_proc = MessageProcessor::new().await?;
+                let language = message_proc
+                    .get_language(&user_id, callback.from.language_code.as_deref())
+                    .await?;
                 let message_id = callback.clone().message.unwrap().id();
                 let edit_message_text = bot.edit_message_text(
                     callback.chat_id().unwrap(),
                     message_id,
-                    escape("Напишите мне полное название коктейля или его часть."),
+                    escape(&language.text("search-by-name-prompt")),
                 );
                 edit_message_text.await?;
 
@@ -71,8 +92,10 @@ pub async fn default_callback_handler(
                 let message_id = callback.clone().message.unwrap().id();
                 message_proc
                     .handle(GetProfilePageCommand {
+                        user_id,
                         chat_id: callback.chat_id().unwrap(),
                         message_id,
+                        language_code: callback.from.language_code.clone(),
                     })
                     .await?;
             }
@@ -88,6 +111,24 @@ pub async fn default_callback_handler(
             MenuCommands::RemoveFromFavorite(coctail_id, prev_page, page_num) => {
                 process_remove_from_favorite(callback, coctail_id, prev_page, page_num).await?;
             }
+            MenuCommands::RateCocktail(coctail_id, stars, prev_page, page_num) => {
+                process_rate_cocktail(callback, coctail_id, stars, prev_page, page_num).await?;
+            }
+            MenuCommands::ToggleHistory(coctail_id, show_history, prev_page, page_num) => {
+                process_toggle_history(callback, coctail_id, show_history, prev_page, page_num)
+                    .await?;
+            }
+            MenuCommands::EditFavoriteNote(coctail_id, prev_page, page_num) => {
+                process_edit_favorite_note(callback, coctail_id, prev_page, page_num, dialogue)
+                    .await?;
+            }
+            MenuCommands::RemoveFavoriteNote(coctail_id, prev_page, page_num) => {
+                process_remove_favorite_note(callback, coctail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::ChooseCollection(coctail_id, prev_page, page_num) => {
+                process_choose_collection(callback, coctail_id, prev_page, page_num, dialogue)
+                    .await?;
+            }
             MenuCommands::RegisterConfirmation => {
                 let message_proc = MessageProcessor::new().await?;
                 let message_id = callback.clone().message.unwrap().id();
@@ -95,6 +136,7 @@ pub async fn default_callback_handler(
                     .handle(GetRegisterUserConfigrationCommand {
                         chat_id: callback.chat_id().unwrap(),
                         message_id,
+                        language_code: callback.from.language_code.clone(),
                     })
                     .await?;
             }
@@ -111,8 +153,10 @@ pub async fn default_callback_handler(
                 let message_id = callback.clone().message.unwrap().id();
                 message_proc
                     .handle(GetRemoveUserConfirmationCommand {
+                        user_id,
                         chat_id: callback.chat_id().unwrap(),
                         message_id,
+                        language_code: callback.from.language_code.clone(),
                     })
                     .await?;
             }
@@ -125,8 +169,216 @@ pub async fn default_callback_handler(
                     })
                     .await?;
             }
-            MenuCommands::Unknown => todo!(),
-            MenuCommands::CocktailsListByName(_page) => todo!(),
+            MenuCommands::TagsList(page) => {
+                process_tags_list(callback, page).await?;
+            }
+            MenuCommands::SelectTag(tag_index) => {
+                process_select_tag(callback, tag_index, dialogue).await?;
+            }
+            MenuCommands::CocktailsListByTag(_page) => {
+                process_tags_list(callback, 0).await?;
+            }
+            MenuCommands::ShowCollections(page) => {
+                process_collections_list(callback, page).await?;
+            }
+            MenuCommands::SelectCollection(collection_id) => {
+                process_select_collection(callback, collection_id, dialogue).await?;
+            }
+            MenuCommands::CocktailsListByCollection(_page) => {
+                process_collections_list(callback, 0).await?;
+            }
+            MenuCommands::CreateCollection => {
+                let message_proc = MessageProcessor::new().await?;
+                let language = message_proc
+                    .get_language(&user_id, callback.from.language_code.as_deref())
+                    .await?;
+                let message_id = callback.clone().message.unwrap().id();
+                let edit_message_text = bot.edit_message_text(
+                    callback.chat_id().unwrap(),
+                    message_id,
+                    escape(&language.text("collection-name-prompt")),
+                );
+                edit_message_text.await?;
+
+                dialogue.update(State::ReceiveCollectionName).await?;
+            }
+            MenuCommands::MyBar(page) => {
+                process_my_bar(callback, page).await?;
+            }
+            MenuCommands::AddBarIngredient => {
+                let message_proc = MessageProcessor::new().await?;
+                let language = message_proc
+                    .get_language(&user_id, callback.from.language_code.as_deref())
+                    .await?;
+                let message_id = callback.clone().message.unwrap().id();
+                let edit_message_text = bot.edit_message_text(
+                    callback.chat_id().unwrap(),
+                    message_id,
+                    escape(&language.text("bar-ingredient-prompt")),
+                );
+                edit_message_text.await?;
+
+                dialogue
+                    .update(State::ReceiveBarIngredient {
+                        suggestions: vec![],
+                    })
+                    .await?;
+            }
+            MenuCommands::SelectBarIngredient(suggestion_index) => {
+                log::warn!(
+                    "Bar ingredient suggestion {} selected out of dialogue, user {}",
+                    suggestion_index,
+                    user_id
+                );
+            }
+            MenuCommands::RemoveBarIngredient(ingredient_index, page) => {
+                let message_proc = MessageProcessor::new().await?;
+                message_proc
+                    .handle(RemoveBarIngredientCommand {
+                        callback: callback.clone(),
+                        ingredient_index,
+                        page,
+                    })
+                    .await?;
+            }
+            MenuCommands::WhatCanIMake(page) => {
+                let message_proc = MessageProcessor::new().await?;
+                let message_id = callback.clone().message.unwrap().id();
+                message_proc
+                    .handle(GetWhatCanIMakeCommand {
+                        user_id,
+                        chat_id: callback.chat_id().unwrap(),
+                        message_id,
+                        page,
+                        language_code: callback.from.language_code.clone(),
+                    })
+                    .await?;
+            }
+            MenuCommands::ToggleCollectionCocktail(collection_id) => {
+                log::warn!(
+                    "Collection {} selected without cocktail, user {}",
+                    collection_id,
+                    user_id
+                );
+            }
+            MenuCommands::DailySubscription => {
+                let message_proc = MessageProcessor::new().await?;
+                message_proc
+                    .handle(GetDailySubscriptionPageCommand {
+                        callback: callback.clone(),
+                    })
+                    .await?;
+            }
+            MenuCommands::SubscribeDaily => {
+                process_change_daily_subscription(callback, DailySubscriptionChange::Subscribe)
+                    .await?;
+            }
+            MenuCommands::UnsubscribeDaily => {
+                process_change_daily_subscription(callback, DailySubscriptionChange::Unsubscribe)
+                    .await?;
+            }
+            MenuCommands::ChooseDailyTime => {
+                let message_proc = MessageProcessor::new().await?;
+                message_proc
+                    .handle(GetDailySubscriptionChooserCommand {
+                        callback: callback.clone(),
+                        chooser: DailySubscriptionChooser::Time,
+                    })
+                    .await?;
+            }
+            MenuCommands::SetDailyTime(hour) => {
+                process_change_daily_subscription(callback, DailySubscriptionChange::SetTime(hour))
+                    .await?;
+            }
+            MenuCommands::ChooseDailyTimezone => {
+                let message_proc = MessageProcessor::new().await?;
+                message_proc
+                    .handle(GetDailySubscriptionChooserCommand {
+                        callback: callback.clone(),
+                        chooser: DailySubscriptionChooser::Timezone,
+                    })
+                    .await?;
+            }
+            MenuCommands::SetDailyTimezone(timezone_index) => {
+                process_change_daily_subscription(
+                    callback,
+                    DailySubscriptionChange::SetTimezone(timezone_index),
+                )
+                .await?;
+            }
+            MenuCommands::ToggleDailyNonAlcoholic => {
+                process_change_daily_subscription(
+                    callback,
+                    DailySubscriptionChange::ToggleNonAlcoholic,
+                )
+                .await?;
+            }
+            MenuCommands::SetDailyMaxIngredients(max_ingredients) => {
+                process_change_daily_subscription(
+                    callback,
+                    DailySubscriptionChange::SetMaxIngredients(max_ingredients),
+                )
+                .await?;
+            }
+            MenuCommands::SimilarCocktails(cocktail_id, prev_page, page_num) => {
+                process_similar_cocktails(callback, cocktail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::Recommendations => {
+                let message_proc = MessageProcessor::new().await?;
+                message_proc
+                    .handle(GetRecommendationsCommand {
+                        callback: callback.clone(),
+                    })
+                    .await?;
+            }
+            MenuCommands::RandomCocktail => {
+                process_random_cocktail(callback).await?;
+            }
+            MenuCommands::ShoppingList(source_list) => {
+                if source_list == MenuCommands::ShowFavorites(0).as_ref() {
+                    process_shopping_list(callback, ShoppingListSource::Favorites).await?;
+                } else {
+                    log::warn!(
+                        "Shopping list for {} requested out of dialogue, user {}",
+                        source_list,
+                        user_id
+                    );
+                }
+            }
+            MenuCommands::ChooseLanguage => {
+                let message_proc = MessageProcessor::new().await?;
+                message_proc
+                    .handle(GetLanguageChooserCommand {
+                        callback: callback.clone(),
+                    })
+                    .await?;
+            }
+            MenuCommands::SetLanguage(language_code) => match Language::from_code(&language_code) {
+                Some(language) => {
+                    let message_proc = MessageProcessor::new().await?;
+                    message_proc
+                        .handle(SetLanguageCommand {
+                            callback: callback.clone(),
+                            language,
+                        })
+                        .await?;
+                }
+                None => {
+                    log::warn!(
+                        "Unsupported language {} chosen, user {}",
+                        language_code,
+                        user_id
+                    );
+                }
+            },
+            _ => {
+                log::warn!(
+                    "Unexpected menu button {} out of dialogue, user {}",
+                    callback_btn,
+                    user_id
+                );
+                process_main_menu(callback, user_id, dialogue).await?;
+            }
         };
     }
 
@@ -134,7 +386,7 @@ pub async fn default_callback_handler(
 }
 
 pub async fn receive_cocktail_name_callback_handler(
-    _bot: Bot,
+    bot: Bot,
     dialogue: BotDialogue,
     callback: CallbackQuery,
     cocktail_name: String,
@@ -153,10 +405,79 @@ pub async fn receive_cocktail_name_callback_handler(
                 let message_id = callback.clone().message.unwrap().id();
                 message_proc
                     .handle(GetCocktailsFilterByNameListCommand {
+                        user_id,
                         chat_id: callback.chat_id().unwrap(),
                         message_id: Some(message_id),
                         cocktail_name_for_filter: cocktail_name,
                         next_page: page,
+                        language_code: callback.from.language_code.clone(),
+                    })
+                    .await?;
+            }
+            MenuCommands::CocktailsPages(total_pages, prev_page) => {
+                process_cocktails_pages(callback, total_pages, prev_page).await?;
+            }
+            MenuCommands::SearchById(cocktail_id, prev_page, page_num) => {
+                process_search_by_id(callback, cocktail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::AddToFavorite(coctail_id, prev_page, page_num) => {
+                process_add_to_favorite(callback, coctail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::RemoveFromFavorite(coctail_id, prev_page, page_num) => {
+                process_remove_from_favorite(callback, coctail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::RateCocktail(coctail_id, stars, prev_page, page_num) => {
+                process_rate_cocktail(callback, coctail_id, stars, prev_page, page_num).await?;
+            }
+            MenuCommands::ToggleHistory(coctail_id, show_history, prev_page, page_num) => {
+                process_toggle_history(callback, coctail_id, show_history, prev_page, page_num)
+                    .await?;
+            }
+            MenuCommands::EditFavoriteNote(coctail_id, prev_page, page_num) => {
+                process_edit_favorite_note(callback, coctail_id, prev_page, page_num, dialogue)
+                    .await?;
+            }
+            MenuCommands::RemoveFavoriteNote(coctail_id, prev_page, page_num) => {
+                process_remove_favorite_note(callback, coctail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::ChooseCollection(coctail_id, prev_page, page_num) => {
+                process_choose_collection(callback, coctail_id, prev_page, page_num, dialogue)
+                    .await?;
+            }
+            _ => {
+                default_callback_handler(bot, dialogue, callback).await?;
+            }
+        };
+    };
+    Ok(())
+}
+
+pub async fn receive_tag_callback_handler(
+    bot: Bot,
+    dialogue: BotDialogue,
+    callback: CallbackQuery,
+    tag_name: String,
+) -> HandlerResult {
+    if let Some(ref callback_btn) = callback.data {
+        let user_id = callback.from.id;
+
+        log::debug!("User {} press menu button: {}", user_id, callback_btn);
+        let menu_cmd = MenuCommands::parse(callback_btn);
+        match menu_cmd {
+            MenuCommands::MainMenu => {
+                process_main_menu(callback, user_id, dialogue).await?;
+            }
+            MenuCommands::CocktailsListByTag(page) => {
+                let message_proc = MessageProcessor::new().await?;
+                let message_id = callback.clone().message.unwrap().id();
+                message_proc
+                    .handle(GetCocktailsFilterByTagListCommand {
+                        user_id,
+                        chat_id: callback.chat_id().unwrap(),
+                        message_id,
+                        tag_name,
+                        next_page: page,
+                        language_code: callback.from.language_code.clone(),
                     })
                     .await?;
             }
@@ -172,12 +493,174 @@ pub async fn receive_cocktail_name_callback_handler(
             MenuCommands::RemoveFromFavorite(coctail_id, prev_page, page_num) => {
                 process_remove_from_favorite(callback, coctail_id, prev_page, page_num).await?;
             }
-            _ => todo!(),
+            MenuCommands::RateCocktail(coctail_id, stars, prev_page, page_num) => {
+                process_rate_cocktail(callback, coctail_id, stars, prev_page, page_num).await?;
+            }
+            MenuCommands::ToggleHistory(coctail_id, show_history, prev_page, page_num) => {
+                process_toggle_history(callback, coctail_id, show_history, prev_page, page_num)
+                    .await?;
+            }
+            MenuCommands::EditFavoriteNote(coctail_id, prev_page, page_num) => {
+                process_edit_favorite_note(callback, coctail_id, prev_page, page_num, dialogue)
+                    .await?;
+            }
+            MenuCommands::RemoveFavoriteNote(coctail_id, prev_page, page_num) => {
+                process_remove_favorite_note(callback, coctail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::ChooseCollection(coctail_id, prev_page, page_num) => {
+                process_choose_collection(callback, coctail_id, prev_page, page_num, dialogue)
+                    .await?;
+            }
+            MenuCommands::ShoppingList(source_list)
+                if source_list == MenuCommands::CocktailsListByTag(0).as_ref() =>
+            {
+                process_shopping_list(callback, ShoppingListSource::Tag(tag_name)).await?;
+            }
+            _ => {
+                default_callback_handler(bot, dialogue, callback).await?;
+            }
         };
     };
     Ok(())
 }
 
+pub async fn receive_collection_callback_handler(
+    bot: Bot,
+    dialogue: BotDialogue,
+    callback: CallbackQuery,
+    collection_id: uuid::Uuid,
+) -> HandlerResult {
+    if let Some(ref callback_btn) = callback.data {
+        let user_id = callback.from.id;
+
+        log::debug!("User {} press menu button: {}", user_id, callback_btn);
+        let menu_cmd = MenuCommands::parse(callback_btn);
+        match menu_cmd {
+            MenuCommands::MainMenu => {
+                process_main_menu(callback, user_id, dialogue).await?;
+            }
+            MenuCommands::CocktailsListByCollection(page) => {
+                let message_proc = MessageProcessor::new().await?;
+                let message_id = callback.clone().message.unwrap().id();
+                message_proc
+                    .handle(GetCocktailsFilterByCollectionListCommand {
+                        user_id,
+                        chat_id: callback.chat_id().unwrap(),
+                        message_id: Some(message_id),
+                        collection_id,
+                        next_page: page,
+                        language_code: callback.from.language_code.clone(),
+                    })
+                    .await?;
+            }
+            MenuCommands::CocktailsPages(total_pages, prev_page) => {
+                process_cocktails_pages(callback, total_pages, prev_page).await?;
+            }
+            MenuCommands::SearchById(cocktail_id, prev_page, page_num) => {
+                process_search_by_id(callback, cocktail_id, prev_page, page_num).await?;
+            }
+            MenuCommands::ShoppingList(source_list)
+                if source_list == MenuCommands::CocktailsListByCollection(0).as_ref() =>
+            {
+                process_shopping_list(callback, ShoppingListSource::Collection(collection_id))
+                    .await?;
+            }
+            _ => {
+                default_callback_handler(bot, dialogue, callback).await?;
+            }
+        };
+    };
+    Ok(())
+}
+
+/// Adds selected suggestion to user bar. Any other button finishes adding ingredients.
+pub async fn receive_bar_ingredient_callback_handler(
+    bot: Bot,
+    dialogue: BotDialogue,
+    callback: CallbackQuery,
+    suggestions: Vec<String>,
+) -> HandlerResult {
+    if let Some(ref callback_btn) = callback.data {
+        if let MenuCommands::SelectBarIngredient(suggestion_index) =
+            MenuCommands::parse(callback_btn)
+        {
+            if let Some(ingredient) = suggestions.get(suggestion_index as usize) {
+                let message_proc = MessageProcessor::new().await?;
+                message_proc
+                    .handle(AddBarIngredientCommand {
+                        callback: callback.clone(),
+                        ingredient: ingredient.clone(),
+                        suggestions: suggestions.clone(),
+                    })
+                    .await?;
+            }
+            return Ok(());
+        }
+    }
+    dialogue.exit().await?;
+    default_callback_handler(bot, dialogue, callback).await
+}
+
+/// Toggles cocktail in chosen collection. Any other button leaves the chooser
+/// and is processed by the handler of the state the user came from.
+pub async fn receive_collection_choice_callback_handler(
+    bot: Bot,
+    dialogue: BotDialogue,
+    callback: CallbackQuery,
+    (cocktail_id, prev_page, previous_state): (uuid::Uuid, String, Box<State>),
+) -> HandlerResult {
+    if let Some(ref callback_btn) = callback.data {
+        if let MenuCommands::ToggleCollectionCocktail(collection_id) =
+            MenuCommands::parse(callback_btn)
+        {
+            let message_proc = MessageProcessor::new().await?;
+            message_proc
+                .handle(ToggleCollectionCocktailCommand {
+                    callback: callback.clone(),
+                    prev_page: MenuCommands::parse(&prev_page),
+                    cocktail_id,
+                    collection_id: uuid::Uuid::parse_str(collection_id.as_str()).unwrap(),
+                })
+                .await?;
+            return Ok(());
+        }
+    }
+    dialogue.update(*previous_state.clone()).await?;
+    dispatch_callback_by_state(bot, dialogue, callback, *previous_state).await
+}
+
+/// Any button pressed while waiting for a note cancels note editing
+/// and is processed by the handler of the state the user came from.
+pub async fn receive_favorite_note_callback_handler(
+    bot: Bot,
+    dialogue: BotDialogue,
+    callback: CallbackQuery,
+    (_cocktail_id, _prev_page, previous_state): (uuid::Uuid, String, Box<State>),
+) -> HandlerResult {
+    dialogue.update(*previous_state.clone()).await?;
+    dispatch_callback_by_state(bot, dialogue, callback, *previous_state).await
+}
+
+async fn dispatch_callback_by_state(
+    bot: Bot,
+    dialogue: BotDialogue,
+    callback: CallbackQuery,
+    state: State,
+) -> HandlerResult {
+    match state {
+        State::ReveivedCocktailName { cocktail_name } => {
+            receive_cocktail_name_callback_handler(bot, dialogue, callback, cocktail_name).await
+        }
+        State::ReceivedTag { tag_name } => {
+            receive_tag_callback_handler(bot, dialogue, callback, tag_name).await
+        }
+        State::ReceivedCollection { collection_id } => {
+            receive_collection_callback_handler(bot, dialogue, callback, collection_id).await
+        }
+        _ => default_callback_handler(bot, dialogue, callback).await,
+    }
+}
+
 async fn process_main_menu(
     callback: CallbackQuery,
     user_id: UserId,
@@ -191,12 +674,65 @@ async fn process_main_menu(
             chat_id: callback.chat_id().unwrap(),
             message_id,
             edit_message: true,
+            language_code: callback.from.language_code.clone(),
         })
         .await?;
     dialogue.exit().await?;
     Ok(())
 }
 
+async fn process_tags_list(callback: CallbackQuery, page: u64) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    let message_id = callback.clone().message.unwrap().id();
+    message_proc
+        .handle(GetTagsListCommand {
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id,
+            page,
+            language_code: callback.from.language_code.clone(),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_select_tag(
+    callback: CallbackQuery,
+    tag_index: u64,
+    dialogue: BotDialogue,
+) -> HandlerResult {
+    let cocktail_repository = infrastructure::RepositoryFactory::global()
+        .get_cocktails_repository()
+        .await?;
+    let tags = cocktail_repository.get_tags().await?;
+    match tags.get(tag_index as usize) {
+        Some(tag) => {
+            let message_proc = MessageProcessor::new().await?;
+            let message_id = callback.clone().message.unwrap().id();
+            message_proc
+                .handle(GetCocktailsFilterByTagListCommand {
+                    user_id: callback.from.id,
+                    chat_id: callback.chat_id().unwrap(),
+                    message_id,
+                    tag_name: tag.name.clone(),
+                    next_page: 0,
+                    language_code: callback.from.language_code.clone(),
+                })
+                .await?;
+            dialogue
+                .update(State::ReceivedTag {
+                    tag_name: tag.name.clone(),
+                })
+                .await?;
+        }
+        None => {
+            log::warn!("Tag with index {} not found", tag_index);
+            process_tags_list(callback, 0).await?;
+        }
+    }
+    Ok(())
+}
+
 async fn process_cocktails_pages(
     callback: CallbackQuery,
     total_pages: u64,
@@ -222,7 +758,11 @@ async fn process_search_by_id(
     let message_proc = MessageProcessor::new().await?;
     message_proc
         .handle(GetCocktailPageByIdCommand {
-            callback: callback.clone(),
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id: callback.message.as_ref().map(|message| message.id()),
+            photo_message: false,
+            show_history: false,
             prev_page: MenuCommands::parse(
                 format!(
                     "{} {}",
@@ -236,6 +776,7 @@ async fn process_search_by_id(
                 .as_str(),
             ),
             cocktail_id: uuid::Uuid::parse_str(cocktail_id.as_str()).unwrap(),
+            language_code: callback.from.language_code.clone(),
         })
         .await?;
     Ok(())
@@ -296,3 +837,315 @@ async fn process_remove_from_favorite(
         .await?;
     Ok(())
 }
+
+async fn process_rate_cocktail(
+    callback: CallbackQuery,
+    cocktail_id: String,
+    stars: u8,
+    prev_page: String,
+    page_num: Option<u64>,
+) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    message_proc
+        .handle(RateCocktailCommand {
+            callback: callback.clone(),
+            prev_page: MenuCommands::parse(
+                format!(
+                    "{} {}",
+                    &prev_page,
+                    if let Some(page_num) = page_num {
+                        page_num.to_string()
+                    } else {
+                        "".to_string()
+                    }
+                )
+                .as_str(),
+            ),
+            cocktail_id: uuid::Uuid::parse_str(cocktail_id.as_str()).unwrap(),
+            stars,
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_toggle_history(
+    callback: CallbackQuery,
+    cocktail_id: String,
+    show_history: bool,
+    prev_page: String,
+    page_num: Option<u64>,
+) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    message_proc
+        .handle(GetCocktailPageByIdCommand {
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id: callback.message.as_ref().map(|message| message.id()),
+            photo_message: is_photo_message(&callback),
+            show_history,
+            prev_page: MenuCommands::parse(
+                format!(
+                    "{} {}",
+                    &prev_page,
+                    if let Some(page_num) = page_num {
+                        page_num.to_string()
+                    } else {
+                        "".to_string()
+                    }
+                )
+                .as_str(),
+            ),
+            cocktail_id: uuid::Uuid::parse_str(cocktail_id.as_str()).unwrap(),
+            language_code: callback.from.language_code.clone(),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_edit_favorite_note(
+    callback: CallbackQuery,
+    cocktail_id: String,
+    prev_page: String,
+    page_num: Option<u64>,
+    dialogue: BotDialogue,
+) -> HandlerResult {
+    let prev_page = format!(
+        "{} {}",
+        &prev_page,
+        if let Some(page_num) = page_num {
+            page_num.to_string()
+        } else {
+            "".to_string()
+        }
+    );
+    let cocktail_id = uuid::Uuid::parse_str(cocktail_id.as_str()).unwrap();
+    let message_proc = MessageProcessor::new().await?;
+    message_proc
+        .handle(GetFavoriteNoteFormCommand {
+            callback: callback.clone(),
+            prev_page: MenuCommands::parse(prev_page.as_str()),
+            cocktail_id,
+        })
+        .await?;
+    let previous_state = dialogue.get_or_default().await?;
+    dialogue
+        .update(State::ReceiveFavoriteNote {
+            cocktail_id,
+            prev_page,
+            previous_state: Box::new(previous_state),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_remove_favorite_note(
+    callback: CallbackQuery,
+    cocktail_id: String,
+    prev_page: String,
+    page_num: Option<u64>,
+) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    message_proc
+        .handle(SetFavoriteNoteCommand {
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id: callback.message.as_ref().map(|message| message.id()),
+            prev_page: MenuCommands::parse(
+                format!(
+                    "{} {}",
+                    &prev_page,
+                    if let Some(page_num) = page_num {
+                        page_num.to_string()
+                    } else {
+                        "".to_string()
+                    }
+                )
+                .as_str(),
+            ),
+            cocktail_id: uuid::Uuid::parse_str(cocktail_id.as_str()).unwrap(),
+            note: None,
+            language_code: callback.from.language_code.clone(),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_collections_list(callback: CallbackQuery, page: u64) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    let message_id = callback.clone().message.unwrap().id();
+    message_proc
+        .handle(GetCollectionsListCommand {
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id: Some(message_id),
+            page,
+            language_code: callback.from.language_code.clone(),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_select_collection(
+    callback: CallbackQuery,
+    collection_id: String,
+    dialogue: BotDialogue,
+) -> HandlerResult {
+    let collection_id = uuid::Uuid::parse_str(collection_id.as_str()).unwrap();
+    let message_proc = MessageProcessor::new().await?;
+    let message_id = callback.clone().message.unwrap().id();
+    message_proc
+        .handle(GetCocktailsFilterByCollectionListCommand {
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id: Some(message_id),
+            collection_id,
+            next_page: 0,
+            language_code: callback.from.language_code.clone(),
+        })
+        .await?;
+    dialogue
+        .update(State::ReceivedCollection { collection_id })
+        .await?;
+    Ok(())
+}
+
+async fn process_choose_collection(
+    callback: CallbackQuery,
+    cocktail_id: String,
+    prev_page: String,
+    page_num: Option<u64>,
+    dialogue: BotDialogue,
+) -> HandlerResult {
+    let prev_page = format!(
+        "{} {}",
+        &prev_page,
+        if let Some(page_num) = page_num {
+            page_num.to_string()
+        } else {
+            "".to_string()
+        }
+    );
+    let cocktail_id = uuid::Uuid::parse_str(cocktail_id.as_str()).unwrap();
+    let message_proc = MessageProcessor::new().await?;
+    let message_id = callback.clone().message.unwrap().id();
+    message_proc
+        .handle(GetCollectionChooserCommand {
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id,
+            prev_page: MenuCommands::parse(prev_page.as_str()),
+            cocktail_id,
+            language_code: callback.from.language_code.clone(),
+        })
+        .await?;
+    let previous_state = dialogue.get_or_default().await?;
+    dialogue
+        .update(State::ChooseCollection {
+            cocktail_id,
+            prev_page,
+            previous_state: Box::new(previous_state),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_my_bar(callback: CallbackQuery, page: u64) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    let message_id = callback.clone().message.unwrap().id();
+    message_proc
+        .handle(GetMyBarPageCommand {
+            user_id: callback.from.id,
+            chat_id: callback.chat_id().unwrap(),
+            message_id: Some(message_id),
+            page,
+            language_code: callback.from.language_code.clone(),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_shopping_list(
+    callback: CallbackQuery,
+    source: ShoppingListSource,
+) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    message_proc
+        .handle(SendShoppingListCommand {
+            callback: callback.clone(),
+            source,
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_random_cocktail(callback: CallbackQuery) -> HandlerResult {
+    let cocktail_repository = infrastructure::RepositoryFactory::global()
+        .get_cocktails_repository()
+        .await?;
+    match cocktail_repository
+        .get_random(&RandomCocktailFilter::default())
+        .await?
+    {
+        Some(cocktail) => {
+            let message_proc = MessageProcessor::new().await?;
+            message_proc
+                .handle(GetCocktailPageByIdCommand {
+                    user_id: callback.from.id,
+                    chat_id: callback.chat_id().unwrap(),
+                    message_id: callback.message.as_ref().map(|message| message.id()),
+                    photo_message: false,
+                    show_history: false,
+                    prev_page: MenuCommands::RandomCocktail,
+                    cocktail_id: cocktail.id,
+                    language_code: callback.from.language_code.clone(),
+                })
+                .await?;
+        }
+        None => {
+            log::warn!("No cocktails found for random choice");
+        }
+    }
+    Ok(())
+}
+
+async fn process_change_daily_subscription(
+    callback: CallbackQuery,
+    change: DailySubscriptionChange,
+) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    message_proc
+        .handle(ChangeDailySubscriptionCommand {
+            callback: callback.clone(),
+            change,
+        })
+        .await?;
+    Ok(())
+}
+
+async fn process_similar_cocktails(
+    callback: CallbackQuery,
+    cocktail_id: String,
+    prev_page: String,
+    page_num: Option<u64>,
+) -> HandlerResult {
+    let message_proc = MessageProcessor::new().await?;
+    message_proc
+        .handle(GetSimilarCocktailsCommand {
+            callback: callback.clone(),
+            prev_page: MenuCommands::parse(
+                format!(
+                    "{} {}",
+                    &prev_page,
+                    if let Some(page_num) = page_num {
+                        page_num.to_string()
+                    } else {
+                        "".to_string()
+                    }
+                )
+                .as_str(),
+            ),
+            cocktail_id: uuid::Uuid::parse_str(cocktail_id.as_str()).unwrap(),
+        })
+        .await?;
+    Ok(())
+}
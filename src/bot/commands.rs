@@ -1,4 +1,5 @@
 use crate::bot::inline_keyboards::PageNumber;
+use crate::shared::i18n::Language;
 use strum::{AsRefStr, EnumString};
 use teloxide::utils::command::BotCommands;
 
@@ -7,6 +8,10 @@ use teloxide::utils::command::BotCommands;
 pub enum MainCommands {
     #[command(parse_with = "split", description = "Основное меню бота\\.")]
     Menu,
+    /// Payload of deep link, e.g. `col_<collection_id>` for shared collection
+    /// or `c_<slug>` for cocktail.
+    #[command(description = "Запуск бота\\.")]
+    Start(String),
 }
 
 #[derive(AsRefStr, EnumString, Debug)]
@@ -52,6 +57,98 @@ pub enum MenuCommands {
     ShowFavorites(u64) = 12,
     #[strum(serialize = "cln")]
     CocktailsListByName(/*page_number*/ u64) = 13,
+    #[strum(serialize = "tgl")]
+    TagsList(/*page_number*/ u64) = 14,
+    #[strum(serialize = "stg")]
+    SelectTag(/*tag_index*/ u64) = 15,
+    #[strum(serialize = "clt")]
+    CocktailsListByTag(/*page_number*/ u64) = 16,
+    #[strum(serialize = "rtc")]
+    RateCocktail(
+        /*cocktail_id*/ String,
+        /*stars*/ u8,
+        /*prev_page_command*/ String,
+        /*prev_page_number*/ Option<u64>,
+    ) = 17,
+    #[strum(serialize = "efn")]
+    EditFavoriteNote(
+        /*cocktail_id*/ String,
+        /*prev_page_command*/ String,
+        /*prev_page_number*/ Option<u64>,
+    ) = 18,
+    #[strum(serialize = "rfn")]
+    RemoveFavoriteNote(
+        /*cocktail_id*/ String,
+        /*prev_page_command*/ String,
+        /*prev_page_number*/ Option<u64>,
+    ) = 19,
+    #[strum(serialize = "shc")]
+    ShowCollections(/*page_number*/ u64) = 20,
+    #[strum(serialize = "scl")]
+    SelectCollection(/*collection_id*/ String) = 21,
+    #[strum(serialize = "clc")]
+    CocktailsListByCollection(/*page_number*/ u64) = 22,
+    #[strum(serialize = "crc")]
+    CreateCollection = 23,
+    #[strum(serialize = "chc")]
+    ChooseCollection(
+        /*cocktail_id*/ String,
+        /*prev_page_command*/ String,
+        /*prev_page_number*/ Option<u64>,
+    ) = 24,
+    #[strum(serialize = "tcc")]
+    ToggleCollectionCocktail(/*collection_id*/ String) = 25,
+    #[strum(serialize = "myb")]
+    MyBar(/*page_number*/ u64) = 26,
+    #[strum(serialize = "abi")]
+    AddBarIngredient = 27,
+    #[strum(serialize = "sbg")]
+    SelectBarIngredient(/*suggestion_index*/ u64) = 28,
+    #[strum(serialize = "rbi")]
+    RemoveBarIngredient(/*ingredient_index*/ u64, /*page_number*/ u64) = 29,
+    #[strum(serialize = "wcm")]
+    WhatCanIMake(/*page_number*/ u64) = 30,
+    #[strum(serialize = "spl")]
+    ShoppingList(/*source_list_command*/ String) = 31,
+    #[strum(serialize = "rnd")]
+    RandomCocktail = 32,
+    #[strum(serialize = "dsb")]
+    DailySubscription = 33,
+    #[strum(serialize = "sdd")]
+    SubscribeDaily = 34,
+    #[strum(serialize = "udd")]
+    UnsubscribeDaily = 35,
+    #[strum(serialize = "cdt")]
+    ChooseDailyTime = 36,
+    #[strum(serialize = "sdt")]
+    SetDailyTime(/*hour*/ u32) = 37,
+    #[strum(serialize = "cdz")]
+    ChooseDailyTimezone = 38,
+    #[strum(serialize = "sdz")]
+    SetDailyTimezone(/*timezone_index*/ u64) = 39,
+    #[strum(serialize = "tdn")]
+    ToggleDailyNonAlcoholic = 40,
+    #[strum(serialize = "sdm")]
+    SetDailyMaxIngredients(/*max_ingredients, 0 for any*/ u32) = 41,
+    #[strum(serialize = "smc")]
+    SimilarCocktails(
+        /*cocktail_id*/ String,
+        /*prev_page_command*/ String,
+        /*prev_page_number*/ Option<u64>,
+    ) = 42,
+    #[strum(serialize = "rcm")]
+    Recommendations = 43,
+    #[strum(serialize = "lng")]
+    ChooseLanguage = 44,
+    #[strum(serialize = "sln")]
+    SetLanguage(/*language_code*/ String) = 45,
+    #[strum(serialize = "tgh")]
+    ToggleHistory(
+        /*cocktail_id*/ String,
+        /*show_history*/ bool,
+        /*prev_page_command*/ String,
+        /*prev_page_number*/ Option<u64>,
+    ) = 46,
 
     Unknown = 99999,
 }
@@ -148,6 +245,182 @@ impl MenuCommands {
         } else if cmd == MenuCommands::CocktailsListByName(0).as_ref() {
             let ulong_param = param.parse().unwrap_or_default();
             MenuCommands::CocktailsListByName(ulong_param)
+        }
+        /* Tags list */
+        else if cmd == MenuCommands::TagsList(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::TagsList(ulong_param)
+        }
+        /* Select tag */
+        else if cmd == MenuCommands::SelectTag(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::SelectTag(ulong_param)
+        }
+        /* Rate cocktail */
+        else if cmd
+            == MenuCommands::RateCocktail(String::new(), 0, String::new(), Some(0)).as_ref()
+        {
+            let params: Vec<&str> = param.trim().split(" ").collect();
+            MenuCommands::RateCocktail(
+                params[0].to_string(),
+                params[1].parse().unwrap_or_default(),
+                params[2].to_string(),
+                params[3].parse::<u64>().ok(),
+            )
+        }
+        /* Show or hide history on cocktail card */
+        else if cmd
+            == MenuCommands::ToggleHistory(String::new(), false, String::new(), Some(0)).as_ref()
+        {
+            let params: Vec<&str> = param.trim().split(" ").collect();
+            MenuCommands::ToggleHistory(
+                params[0].to_string(),
+                params[1].parse().unwrap_or_default(),
+                params[2].to_string(),
+                params[3].parse::<u64>().ok(),
+            )
+        }
+        /* Edit favorite note */
+        else if cmd
+            == MenuCommands::EditFavoriteNote(String::new(), String::new(), Some(0)).as_ref()
+        {
+            let params: Vec<&str> = param.trim().split(" ").collect();
+            MenuCommands::EditFavoriteNote(
+                params[0].to_string(),
+                params[1].to_string(),
+                params[2].parse::<u64>().ok(),
+            )
+        }
+        /* Remove favorite note */
+        else if cmd
+            == MenuCommands::RemoveFavoriteNote(String::new(), String::new(), Some(0)).as_ref()
+        {
+            let params: Vec<&str> = param.trim().split(" ").collect();
+            MenuCommands::RemoveFavoriteNote(
+                params[0].to_string(),
+                params[1].to_string(),
+                params[2].parse::<u64>().ok(),
+            )
+        }
+        /* Show collections */
+        else if cmd == MenuCommands::ShowCollections(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::ShowCollections(ulong_param)
+        }
+        /* Select collection */
+        else if cmd == MenuCommands::SelectCollection(String::new()).as_ref() {
+            MenuCommands::SelectCollection(param.to_string())
+        }
+        /* Cocktails list by collection */
+        else if cmd == MenuCommands::CocktailsListByCollection(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::CocktailsListByCollection(ulong_param)
+        }
+        /* Create collection */
+        else if cmd == MenuCommands::CreateCollection.as_ref() {
+            MenuCommands::CreateCollection
+        }
+        /* Choose collection for cocktail */
+        else if cmd
+            == MenuCommands::ChooseCollection(String::new(), String::new(), Some(0)).as_ref()
+        {
+            let params: Vec<&str> = param.trim().split(" ").collect();
+            MenuCommands::ChooseCollection(
+                params[0].to_string(),
+                params[1].to_string(),
+                params[2].parse::<u64>().ok(),
+            )
+        }
+        /* Toggle cocktail in collection */
+        else if cmd == MenuCommands::ToggleCollectionCocktail(String::new()).as_ref() {
+            MenuCommands::ToggleCollectionCocktail(param.to_string())
+        }
+        /* My bar */
+        else if cmd == MenuCommands::MyBar(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::MyBar(ulong_param)
+        }
+        /* Add bar ingredient */
+        else if cmd == MenuCommands::AddBarIngredient.as_ref() {
+            MenuCommands::AddBarIngredient
+        }
+        /* Select bar ingredient from suggestions */
+        else if cmd == MenuCommands::SelectBarIngredient(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::SelectBarIngredient(ulong_param)
+        }
+        /* Remove bar ingredient */
+        else if cmd == MenuCommands::RemoveBarIngredient(0, 0).as_ref() {
+            let params: Vec<&str> = param.trim().split(" ").collect();
+            MenuCommands::RemoveBarIngredient(
+                params[0].parse().unwrap_or_default(),
+                params
+                    .get(1)
+                    .and_then(|x| x.parse().ok())
+                    .unwrap_or_default(),
+            )
+        }
+        /* What can I make */
+        else if cmd == MenuCommands::WhatCanIMake(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::WhatCanIMake(ulong_param)
+        }
+        /* Random cocktail */
+        else if cmd == MenuCommands::RandomCocktail.as_ref() {
+            MenuCommands::RandomCocktail
+        }
+        /* Daily subscription */
+        else if cmd == MenuCommands::DailySubscription.as_ref() {
+            MenuCommands::DailySubscription
+        } else if cmd == MenuCommands::SubscribeDaily.as_ref() {
+            MenuCommands::SubscribeDaily
+        } else if cmd == MenuCommands::UnsubscribeDaily.as_ref() {
+            MenuCommands::UnsubscribeDaily
+        } else if cmd == MenuCommands::ChooseDailyTime.as_ref() {
+            MenuCommands::ChooseDailyTime
+        } else if cmd == MenuCommands::SetDailyTime(0).as_ref() {
+            let uint_param = param.parse().unwrap_or_default();
+            MenuCommands::SetDailyTime(uint_param)
+        } else if cmd == MenuCommands::ChooseDailyTimezone.as_ref() {
+            MenuCommands::ChooseDailyTimezone
+        } else if cmd == MenuCommands::SetDailyTimezone(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::SetDailyTimezone(ulong_param)
+        } else if cmd == MenuCommands::ToggleDailyNonAlcoholic.as_ref() {
+            MenuCommands::ToggleDailyNonAlcoholic
+        } else if cmd == MenuCommands::SetDailyMaxIngredients(0).as_ref() {
+            let uint_param = param.parse().unwrap_or_default();
+            MenuCommands::SetDailyMaxIngredients(uint_param)
+        }
+        /* Similar cocktails */
+        else if cmd
+            == MenuCommands::SimilarCocktails(String::new(), String::new(), Some(0)).as_ref()
+        {
+            let params: Vec<&str> = param.trim().split(" ").collect();
+            MenuCommands::SimilarCocktails(
+                params[0].to_string(),
+                params[1].to_string(),
+                params[2].parse::<u64>().ok(),
+            )
+        }
+        /* Recommendations */
+        else if cmd == MenuCommands::Recommendations.as_ref() {
+            MenuCommands::Recommendations
+        }
+        /* Language of the bot */
+        else if cmd == MenuCommands::ChooseLanguage.as_ref() {
+            MenuCommands::ChooseLanguage
+        } else if cmd == MenuCommands::SetLanguage(String::new()).as_ref() {
+            MenuCommands::SetLanguage(param.to_string())
+        }
+        /* Shopping list */
+        else if cmd == MenuCommands::ShoppingList(String::new()).as_ref() {
+            MenuCommands::ShoppingList(param.to_string())
+        }
+        /* Cocktails list by tag */
+        else if cmd == MenuCommands::CocktailsListByTag(0).as_ref() {
+            let ulong_param = param.parse().unwrap_or_default();
+            MenuCommands::CocktailsListByTag(ulong_param)
         } else {
             MenuCommands::Unknown
         }
@@ -168,6 +441,89 @@ impl MenuCommands {
         format!("{} {}", cmd, page.0)
     }
 
+    pub fn get_tags_list_command_string(page: &PageNumber) -> String {
+        let cmd = String::from(MenuCommands::TagsList(0).as_ref());
+        format!("{} {}", cmd, page.0)
+    }
+
+    pub fn get_select_tag_command_string(tag_index: &u64) -> String {
+        let cmd = String::from(MenuCommands::SelectTag(0).as_ref());
+        format!("{} {}", cmd, tag_index)
+    }
+
+    pub fn get_cocktails_list_by_tag_command_string(page: &PageNumber) -> String {
+        let cmd = String::from(MenuCommands::CocktailsListByTag(0).as_ref());
+        format!("{} {}", cmd, page.0)
+    }
+
+    pub fn get_collections_list_command_string(page: &PageNumber) -> String {
+        let cmd = String::from(MenuCommands::ShowCollections(0).as_ref());
+        format!("{} {}", cmd, page.0)
+    }
+
+    pub fn get_select_collection_command_string(collection_id: &uuid::Uuid) -> String {
+        let cmd = String::from(MenuCommands::SelectCollection(String::new()).as_ref());
+        format!("{} {}", cmd, collection_id)
+    }
+
+    pub fn get_cocktails_list_by_collection_command_string(page: &PageNumber) -> String {
+        let cmd = String::from(MenuCommands::CocktailsListByCollection(0).as_ref());
+        format!("{} {}", cmd, page.0)
+    }
+
+    pub fn get_toggle_collection_cocktail_command_string(collection_id: &uuid::Uuid) -> String {
+        let cmd = String::from(MenuCommands::ToggleCollectionCocktail(String::new()).as_ref());
+        format!("{} {}", cmd, collection_id)
+    }
+
+    pub fn get_my_bar_command_string(page: &PageNumber) -> String {
+        let cmd = String::from(MenuCommands::MyBar(0).as_ref());
+        format!("{} {}", cmd, page.0)
+    }
+
+    pub fn get_select_bar_ingredient_command_string(suggestion_index: &u64) -> String {
+        let cmd = String::from(MenuCommands::SelectBarIngredient(0).as_ref());
+        format!("{} {}", cmd, suggestion_index)
+    }
+
+    pub fn get_remove_bar_ingredient_command_string(
+        ingredient_index: &u64,
+        page: &PageNumber,
+    ) -> String {
+        let cmd = String::from(MenuCommands::RemoveBarIngredient(0, 0).as_ref());
+        format!("{} {} {}", cmd, ingredient_index, page.0)
+    }
+
+    pub fn get_what_can_i_make_command_string(page: &PageNumber) -> String {
+        let cmd = String::from(MenuCommands::WhatCanIMake(0).as_ref());
+        format!("{} {}", cmd, page.0)
+    }
+
+    pub fn get_shopping_list_command_string(source_list: &MenuCommands) -> String {
+        let cmd = String::from(MenuCommands::ShoppingList(String::new()).as_ref());
+        format!("{} {}", cmd, source_list.as_ref())
+    }
+
+    pub fn get_set_daily_time_command_string(hour: &u32) -> String {
+        let cmd = String::from(MenuCommands::SetDailyTime(0).as_ref());
+        format!("{} {}", cmd, hour)
+    }
+
+    pub fn get_set_daily_timezone_command_string(timezone_index: &u64) -> String {
+        let cmd = String::from(MenuCommands::SetDailyTimezone(0).as_ref());
+        format!("{} {}", cmd, timezone_index)
+    }
+
+    pub fn get_set_daily_max_ingredients_command_string(max_ingredients: &u32) -> String {
+        let cmd = String::from(MenuCommands::SetDailyMaxIngredients(0).as_ref());
+        format!("{} {}", cmd, max_ingredients)
+    }
+
+    pub fn get_set_language_command_string(language: &Language) -> String {
+        let cmd = String::from(MenuCommands::SetLanguage(String::new()).as_ref());
+        format!("{} {}", cmd, language.code())
+    }
+
     pub fn get_main_menu_command_string() -> String {
         String::from(MenuCommands::MainMenu.as_ref())
     }
@@ -191,6 +547,9 @@ impl MenuCommands {
             MenuCommands::CocktailsList(page) => Some(*page),
             MenuCommands::ShowFavorites(page) => Some(*page),
             MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
             _ => None,
         };
         format!(
@@ -214,6 +573,9 @@ impl MenuCommands {
             MenuCommands::CocktailsList(page) => Some(*page),
             MenuCommands::ShowFavorites(page) => Some(*page),
             MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
             _ => None,
         };
         let cmd = String::from(
@@ -241,6 +603,9 @@ impl MenuCommands {
             MenuCommands::CocktailsList(page) => Some(*page),
             MenuCommands::ShowFavorites(page) => Some(*page),
             MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
             _ => None,
         };
         let cmd = String::from(
@@ -259,4 +624,188 @@ impl MenuCommands {
             }
         )
     }
+
+    pub fn get_rate_cocktail_command_string(
+        cocktail_id: &uuid::Uuid,
+        stars: &u8,
+        source_page: &MenuCommands,
+    ) -> String {
+        let prev_list_page = match source_page {
+            MenuCommands::CocktailsList(page) => Some(*page),
+            MenuCommands::ShowFavorites(page) => Some(*page),
+            MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
+            _ => None,
+        };
+        let cmd = String::from(
+            MenuCommands::RateCocktail(String::new(), 0, String::new(), Some(0)).as_ref(),
+        );
+        let prev_page_command = String::from(source_page.as_ref());
+        format!(
+            "{} {} {} {} {}",
+            cmd,
+            cocktail_id,
+            stars,
+            prev_page_command,
+            if let Some(prev_list_page) = prev_list_page {
+                prev_list_page.to_string()
+            } else {
+                "".to_string()
+            }
+        )
+    }
+
+    pub fn get_toggle_history_command_string(
+        cocktail_id: &uuid::Uuid,
+        show_history: bool,
+        source_page: &MenuCommands,
+    ) -> String {
+        let prev_list_page = match source_page {
+            MenuCommands::CocktailsList(page) => Some(*page),
+            MenuCommands::ShowFavorites(page) => Some(*page),
+            MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
+            _ => None,
+        };
+        let cmd = String::from(
+            MenuCommands::ToggleHistory(String::new(), false, String::new(), Some(0)).as_ref(),
+        );
+        let prev_page_command = String::from(source_page.as_ref());
+        format!(
+            "{} {} {} {} {}",
+            cmd,
+            cocktail_id,
+            show_history,
+            prev_page_command,
+            if let Some(prev_list_page) = prev_list_page {
+                prev_list_page.to_string()
+            } else {
+                "".to_string()
+            }
+        )
+    }
+
+    pub fn get_edit_favorite_note_command_string(
+        cocktail_id: &uuid::Uuid,
+        source_page: &MenuCommands,
+    ) -> String {
+        let prev_list_page = match source_page {
+            MenuCommands::CocktailsList(page) => Some(*page),
+            MenuCommands::ShowFavorites(page) => Some(*page),
+            MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
+            _ => None,
+        };
+        let cmd = String::from(
+            MenuCommands::EditFavoriteNote(String::new(), String::new(), Some(0)).as_ref(),
+        );
+        let prev_page_command = String::from(source_page.as_ref());
+        format!(
+            "{} {} {} {}",
+            cmd,
+            cocktail_id,
+            prev_page_command,
+            if let Some(prev_list_page) = prev_list_page {
+                prev_list_page.to_string()
+            } else {
+                "".to_string()
+            }
+        )
+    }
+
+    pub fn get_remove_favorite_note_command_string(
+        cocktail_id: &uuid::Uuid,
+        source_page: &MenuCommands,
+    ) -> String {
+        let prev_list_page = match source_page {
+            MenuCommands::CocktailsList(page) => Some(*page),
+            MenuCommands::ShowFavorites(page) => Some(*page),
+            MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
+            _ => None,
+        };
+        let cmd = String::from(
+            MenuCommands::RemoveFavoriteNote(String::new(), String::new(), Some(0)).as_ref(),
+        );
+        let prev_page_command = String::from(source_page.as_ref());
+        format!(
+            "{} {} {} {}",
+            cmd,
+            cocktail_id,
+            prev_page_command,
+            if let Some(prev_list_page) = prev_list_page {
+                prev_list_page.to_string()
+            } else {
+                "".to_string()
+            }
+        )
+    }
+
+    pub fn get_choose_collection_command_string(
+        cocktail_id: &uuid::Uuid,
+        source_page: &MenuCommands,
+    ) -> String {
+        let prev_list_page = match source_page {
+            MenuCommands::CocktailsList(page) => Some(*page),
+            MenuCommands::ShowFavorites(page) => Some(*page),
+            MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
+            _ => None,
+        };
+        let cmd = String::from(
+            MenuCommands::ChooseCollection(String::new(), String::new(), Some(0)).as_ref(),
+        );
+        let prev_page_command = String::from(source_page.as_ref());
+        format!(
+            "{} {} {} {}",
+            cmd,
+            cocktail_id,
+            prev_page_command,
+            if let Some(prev_list_page) = prev_list_page {
+                prev_list_page.to_string()
+            } else {
+                "".to_string()
+            }
+        )
+    }
+
+    pub fn get_similar_cocktails_command_string(
+        cocktail_id: &uuid::Uuid,
+        source_page: &MenuCommands,
+    ) -> String {
+        let prev_list_page = match source_page {
+            MenuCommands::CocktailsList(page) => Some(*page),
+            MenuCommands::ShowFavorites(page) => Some(*page),
+            MenuCommands::CocktailsListByName(page) => Some(*page),
+            MenuCommands::CocktailsListByTag(page) => Some(*page),
+            MenuCommands::CocktailsListByCollection(page) => Some(*page),
+            MenuCommands::WhatCanIMake(page) => Some(*page),
+            _ => None,
+        };
+        let cmd = String::from(
+            MenuCommands::SimilarCocktails(String::new(), String::new(), Some(0)).as_ref(),
+        );
+        let prev_page_command = String::from(source_page.as_ref());
+        format!(
+            "{} {} {} {}",
+            cmd,
+            cocktail_id,
+            prev_page_command,
+            if let Some(prev_list_page) = prev_list_page {
+                prev_list_page.to_string()
+            } else {
+                "".to_string()
+            }
+        )
+    }
 }
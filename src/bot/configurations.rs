@@ -1,5 +1,5 @@
-use std::fmt::{Display, Formatter};
 use envconfig::Envconfig;
+use std::fmt::{Display, Formatter};
 
 #[derive(Envconfig, Debug, Clone)]
 pub struct BotConfig {
@@ -7,7 +7,7 @@ pub struct BotConfig {
     pub bot_token: String,
 }
 
-impl Display for BotConfig{
+impl Display for BotConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.bot_token)
     }
@@ -6,4 +6,24 @@ pub enum State {
     ReveivedCocktailName {
         cocktail_name: String,
     },
+    ReceivedTag {
+        tag_name: String,
+    },
+    ReceiveCollectionName,
+    ReceiveBarIngredient {
+        suggestions: Vec<String>,
+    },
+    ReceivedCollection {
+        collection_id: uuid::Uuid,
+    },
+    ChooseCollection {
+        cocktail_id: uuid::Uuid,
+        prev_page: String,
+        previous_state: Box<State>,
+    },
+    ReceiveFavoriteNote {
+        cocktail_id: uuid::Uuid,
+        prev_page: String,
+        previous_state: Box<State>,
+    },
 }
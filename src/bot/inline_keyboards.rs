@@ -1,5 +1,13 @@
 use super::commands::MenuCommands;
-use crate::domain::aggregates::cocktail::CocktailsPaged;
+use crate::domain::aggregates::{
+    cocktail::{CocktailsByIngredientsPaged, CocktailsPaged, TagWithCount},
+    collection::{Collection, CollectionsPaged},
+    rating::{MAX_STARS, MIN_STARS},
+    user::DailySubscription,
+};
+use crate::domain::locale::LocaleChain;
+use crate::domain::services::{recommendations::Recommendation, similarity::SimilarCocktail};
+use crate::shared::i18n::Language;
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 use teloxide::utils::markdown::escape;
 
@@ -27,41 +35,55 @@ pub enum ListCocktailsSource {
     CocktailList,
     Favorites,
     CocktailListByName,
+    CocktailListByTag,
+    CocktailListByCollection,
 }
 
 #[derive(Debug)]
 struct MenuButtonMeta<'a> {
+    /// Id of the button label in message catalogues.
     name: &'a str,
     callback_data: &'a MenuCommands,
 }
 
 const MAIN_MENU_BUTTONS_MAP: &[MenuButtonMeta] = &[
     MenuButtonMeta {
-        name: "📋 Список коктейлей",
+        name: "main-menu-cocktails-list",
         callback_data: &MenuCommands::CocktailsList(0),
     },
     MenuButtonMeta {
-        name: "🔎 Поиск по названию",
+        name: "main-menu-search-by-name",
         callback_data: &MenuCommands::SearchByName,
     },
+    MenuButtonMeta {
+        name: "main-menu-search-by-tags",
+        callback_data: &MenuCommands::TagsList(0),
+    },
+    MenuButtonMeta {
+        name: "main-menu-random-cocktail",
+        callback_data: &MenuCommands::RandomCocktail,
+    },
 ];
 
 const PROFILE_PAGE_MENU_BTN: &MenuButtonMeta = &MenuButtonMeta {
-    name: "🗄 Личная страница",
+    name: "main-menu-profile-page",
     callback_data: &MenuCommands::ProfilePage,
 };
 const REGISTER_PAGE_MENU_BTN: &MenuButtonMeta = &MenuButtonMeta {
-    name: "🔑 Регистрация",
+    name: "main-menu-register",
     callback_data: &MenuCommands::RegisterConfirmation,
 };
 
-pub fn get_main_menu_keyboard(user_registered: &bool) -> InlineKeyboardMarkup {
+pub fn get_main_menu_keyboard(user_registered: &bool, language: Language) -> InlineKeyboardMarkup {
     let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
     for button_row in MAIN_MENU_BUTTONS_MAP.chunks(1) {
         let row = button_row
             .iter()
             .map(|btn_info| {
-                InlineKeyboardButton::callback(btn_info.name, btn_info.callback_data.as_ref())
+                InlineKeyboardButton::callback(
+                    language.text(btn_info.name),
+                    btn_info.callback_data.as_ref(),
+                )
             })
             .collect();
         keyboard.push(row);
@@ -69,13 +91,13 @@ pub fn get_main_menu_keyboard(user_registered: &bool) -> InlineKeyboardMarkup {
     match user_registered {
         true => {
             keyboard.push(vec![InlineKeyboardButton::callback(
-                PROFILE_PAGE_MENU_BTN.name.to_string().to_owned(),
+                language.text(PROFILE_PAGE_MENU_BTN.name),
                 PROFILE_PAGE_MENU_BTN.callback_data.as_ref(),
             )]);
         }
         false => {
             keyboard.push(vec![InlineKeyboardButton::callback(
-                REGISTER_PAGE_MENU_BTN.name.to_string().to_owned(),
+                language.text(REGISTER_PAGE_MENU_BTN.name),
                 REGISTER_PAGE_MENU_BTN.callback_data.as_ref(),
             )]);
         }
@@ -84,14 +106,14 @@ pub fn get_main_menu_keyboard(user_registered: &bool) -> InlineKeyboardMarkup {
     InlineKeyboardMarkup::new(keyboard)
 }
 
-pub fn get_register_confirmation_keyboard() -> InlineKeyboardMarkup {
+pub fn get_register_confirmation_keyboard(language: Language) -> InlineKeyboardMarkup {
     let keyboard: Vec<Vec<InlineKeyboardButton>> = vec![
         vec![InlineKeyboardButton::callback(
-            "Подтвердить регистрацию",
+            language.text("register-confirm-button"),
             MenuCommands::Register.as_ref(),
         )],
         vec![InlineKeyboardButton::callback(
-            "👈 Назад",
+            language.text("button-back"),
             MenuCommands::get_main_menu_command_string(),
         )],
     ];
@@ -99,14 +121,14 @@ pub fn get_register_confirmation_keyboard() -> InlineKeyboardMarkup {
     InlineKeyboardMarkup::new(keyboard)
 }
 
-pub fn get_remove_user_confirmation_keyboard() -> InlineKeyboardMarkup {
+pub fn get_remove_user_confirmation_keyboard(language: Language) -> InlineKeyboardMarkup {
     let keyboard: Vec<Vec<InlineKeyboardButton>> = vec![
         vec![InlineKeyboardButton::callback(
-            "Подтвердить удаление",
+            language.text("remove-account-confirm-button"),
             MenuCommands::RemoveAccount.as_ref(),
         )],
         vec![InlineKeyboardButton::callback(
-            "👈 Назад",
+            language.text("button-back"),
             MenuCommands::ProfilePage.as_ref(),
         )],
     ];
@@ -119,6 +141,7 @@ pub fn get_cocktails_list_keyboard(
     current_page: &PageNumber,
     page_size: &u64,
     source: ListCocktailsSource,
+    language: Language,
 ) -> InlineKeyboardMarkup {
     let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
     for cocktail_info in cocktails_paged.items.chunks(1) {
@@ -134,6 +157,12 @@ pub fn get_cocktails_list_keyboard(
                     ListCocktailsSource::CocktailListByName => {
                         &MenuCommands::CocktailsListByName(current_page_v)
                     }
+                    ListCocktailsSource::CocktailListByTag => {
+                        &MenuCommands::CocktailsListByTag(current_page_v)
+                    }
+                    ListCocktailsSource::CocktailListByCollection => {
+                        &MenuCommands::CocktailsListByCollection(current_page_v)
+                    }
                 };
                 InlineKeyboardButton::callback(
                     cocktail_info.russian_name.to_owned(),
@@ -181,6 +210,22 @@ pub fn get_cocktails_list_keyboard(
                 &MenuCommands::CocktailsListByName(0),
             ),
         ),
+        ListCocktailsSource::CocktailListByTag => (
+            MenuCommands::get_cocktails_list_by_tag_command_string(&current_page.next()),
+            MenuCommands::get_cocktails_list_by_tag_command_string(&current_page.previous()),
+            MenuCommands::get_cocktail_pages_command_string(
+                &available_pages,
+                &MenuCommands::CocktailsListByTag(0),
+            ),
+        ),
+        ListCocktailsSource::CocktailListByCollection => (
+            MenuCommands::get_cocktails_list_by_collection_command_string(&current_page.next()),
+            MenuCommands::get_cocktails_list_by_collection_command_string(&current_page.previous()),
+            MenuCommands::get_cocktail_pages_command_string(
+                &available_pages,
+                &MenuCommands::CocktailsListByCollection(0),
+            ),
+        ),
     };
 
     let navigate_line: Vec<InlineKeyboardButton> = if current_page.0 == 0 {
@@ -208,8 +253,77 @@ pub fn get_cocktails_list_keyboard(
         ]
     };
     keyboard.push(navigate_line);
+    let shopping_list_source = match source {
+        ListCocktailsSource::Favorites => Some(MenuCommands::ShowFavorites(0)),
+        ListCocktailsSource::CocktailListByTag => Some(MenuCommands::CocktailsListByTag(0)),
+        ListCocktailsSource::CocktailListByCollection => {
+            Some(MenuCommands::CocktailsListByCollection(0))
+        }
+        ListCocktailsSource::CocktailList | ListCocktailsSource::CocktailListByName => None,
+    };
+    if let Some(shopping_list_source) = shopping_list_source {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            language.text("shopping-list-button"),
+            MenuCommands::get_shopping_list_command_string(&shopping_list_source),
+        )]);
+    }
     keyboard.push(vec![InlineKeyboardButton::callback(
-        "👈 Назад",
+        language.text("button-back"),
+        MenuCommands::get_main_menu_command_string(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_tags_list_keyboard(
+    tags: &[TagWithCount],
+    current_page: &PageNumber,
+    page_size: &u64,
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    let tags_on_page = tags
+        .iter()
+        .enumerate()
+        .skip((current_page.0 * page_size) as usize)
+        .take(*page_size as usize);
+    for (tag_index, tag) in tags_on_page {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            format!("{} ({})", tag.name, tag.cocktails_count),
+            MenuCommands::get_select_tag_command_string(&(tag_index as u64)),
+        )]);
+    }
+
+    let total_count = tags.len() as u64;
+    let available_pages: u64 = if !total_count.is_multiple_of(*page_size) {
+        (total_count / page_size) + 1
+    } else {
+        total_count / page_size
+    };
+    let mut navigate_line: Vec<InlineKeyboardButton> = vec![];
+    if current_page.0 > 0 {
+        navigate_line.push(InlineKeyboardButton::callback(
+            "👈",
+            MenuCommands::get_tags_list_command_string(&current_page.previous()),
+        ));
+    }
+    navigate_line.push(InlineKeyboardButton::callback(
+        format!(
+            "{}/{}",
+            current_page.human_readable_page_number().0,
+            available_pages
+        ),
+        MenuCommands::get_tags_list_command_string(current_page),
+    ));
+    if current_page.next().0 < available_pages {
+        navigate_line.push(InlineKeyboardButton::callback(
+            "👉",
+            MenuCommands::get_tags_list_command_string(&current_page.next()),
+        ));
+    }
+    keyboard.push(navigate_line);
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
         MenuCommands::get_main_menu_command_string(),
     )]);
 
@@ -241,6 +355,16 @@ pub fn get_cocktail_pages_keyboard(
                             page - 1,
                         ))
                     }
+                    MenuCommands::CocktailsListByTag(_) => {
+                        MenuCommands::get_cocktails_list_by_tag_command_string(&PageNumber(
+                            page - 1,
+                        ))
+                    }
+                    MenuCommands::CocktailsListByCollection(_) => {
+                        MenuCommands::get_cocktails_list_by_collection_command_string(&PageNumber(
+                            page - 1,
+                        ))
+                    }
                     _ => MenuCommands::get_cocktails_list_command_string(&PageNumber(page - 1)),
                 };
                 InlineKeyboardButton::callback(page.to_string(), list_command)
@@ -252,10 +376,14 @@ pub fn get_cocktail_pages_keyboard(
     InlineKeyboardMarkup::new(keyboard)
 }
 
+/// `show_history` is `None` for cocktails without history, otherwise whether it is shown.
 pub fn get_cocktail_card_navigate_keyboard(
     prev_page: &MenuCommands,
     cocktail_id: &uuid::Uuid,
     favorite: &Option<bool>,
+    user_stars: &Option<u8>,
+    show_history: &Option<bool>,
+    language: Language,
 ) -> InlineKeyboardMarkup {
     let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
 
@@ -270,21 +398,23 @@ pub fn get_cocktail_card_navigate_keyboard(
         MenuCommands::CocktailsListByName(page) => {
             MenuCommands::get_cocktails_list_by_name_command_string(&PageNumber(*page))
         }
-        MenuCommands::MainMenu => todo!(),
-        MenuCommands::SearchByName => todo!(),
-        MenuCommands::Register => todo!(),
-        MenuCommands::ProfilePage => todo!(),
-        MenuCommands::SearchById(_, _, _) => todo!(),
-        MenuCommands::CocktailsPages(_, _) => todo!(),
-        MenuCommands::Unknown => todo!(),
-        MenuCommands::AddToFavorite(_, _, _) => todo!(),
-        MenuCommands::RemoveFromFavorite(_, _, _) => todo!(),
-        MenuCommands::RegisterConfirmation => todo!(),
-        MenuCommands::RemoveAccount => todo!(),
-        MenuCommands::RemoveAccountConfirmation => todo!(),
+        MenuCommands::CocktailsListByTag(page) => {
+            MenuCommands::get_cocktails_list_by_tag_command_string(&PageNumber(*page))
+        }
+        MenuCommands::CocktailsListByCollection(page) => {
+            MenuCommands::get_cocktails_list_by_collection_command_string(&PageNumber(*page))
+        }
+        MenuCommands::WhatCanIMake(page) => {
+            MenuCommands::get_what_can_i_make_command_string(&PageNumber(*page))
+        }
+        MenuCommands::RandomCocktail | MenuCommands::MainMenu => {
+            MenuCommands::get_main_menu_command_string()
+        }
+        MenuCommands::Recommendations => String::from(MenuCommands::Recommendations.as_ref()),
+        _ => MenuCommands::get_main_menu_command_string(),
     };
     navigate_row.push(InlineKeyboardButton::callback(
-        "👈 Назад",
+        language.text("button-back"),
         prev_page_command_string,
     ));
     if favorite.is_some() {
@@ -304,26 +434,546 @@ pub fn get_cocktail_card_navigate_keyboard(
             ));
         }
     }
+    if favorite.is_some() {
+        navigate_row.push(InlineKeyboardButton::callback(
+            "📚",
+            MenuCommands::get_choose_collection_command_string(cocktail_id, prev_page),
+        ));
+    }
+    if favorite.unwrap_or(false) {
+        navigate_row.push(InlineKeyboardButton::callback(
+            "📝",
+            MenuCommands::get_edit_favorite_note_command_string(cocktail_id, prev_page),
+        ));
+    }
+    if favorite.is_some() {
+        let rate_row: Vec<InlineKeyboardButton> = (MIN_STARS..=MAX_STARS)
+            .map(|stars| {
+                let label = if Some(stars) == *user_stars {
+                    format!("✅{}", stars)
+                } else {
+                    format!("{}⭐", stars)
+                };
+                InlineKeyboardButton::callback(
+                    label,
+                    MenuCommands::get_rate_cocktail_command_string(cocktail_id, &stars, prev_page),
+                )
+            })
+            .collect();
+        keyboard.push(rate_row);
+    }
     keyboard.push(navigate_row);
+    if let Some(show_history) = show_history {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            language.text(if *show_history {
+                "card-hide-history-button"
+            } else {
+                "card-show-history-button"
+            }),
+            MenuCommands::get_toggle_history_command_string(cocktail_id, !show_history, prev_page),
+        )]);
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("card-similar-button"),
+        MenuCommands::get_similar_cocktails_command_string(cocktail_id, prev_page),
+    )]);
+    if let MenuCommands::RandomCocktail = prev_page {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            language.text("card-another-random-button"),
+            String::from(MenuCommands::RandomCocktail.as_ref()),
+        )]);
+    }
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_favorite_note_form_keyboard(
+    prev_page: &MenuCommands,
+    cocktail_id: &uuid::Uuid,
+    has_note: bool,
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    if has_note {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            language.text("note-remove-button"),
+            MenuCommands::get_remove_favorite_note_command_string(cocktail_id, prev_page),
+        )]);
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-cancel"),
+        MenuCommands::get_cocktail_by_id_command_string(cocktail_id, prev_page),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_collections_list_keyboard(
+    collections_paged: &CollectionsPaged,
+    current_page: &PageNumber,
+    page_size: &u64,
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    for collection in &collections_paged.items {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            format!("{} ({})", collection.name, collection.cocktail_ids.len()),
+            MenuCommands::get_select_collection_command_string(&collection.id),
+        )]);
+    }
+
+    let navigate_line = get_simple_navigate_line(
+        current_page,
+        collections_paged.total_count,
+        page_size,
+        MenuCommands::get_collections_list_command_string,
+    );
+    if !navigate_line.is_empty() {
+        keyboard.push(navigate_line);
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("collection-create-button"),
+        MenuCommands::CreateCollection.as_ref(),
+    )]);
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::ProfilePage.as_ref(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_collection_chooser_keyboard(
+    collections: &[Collection],
+    cocktail_id: &uuid::Uuid,
+    prev_page: &MenuCommands,
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    for collection in collections {
+        let label = if collection.contains(cocktail_id) {
+            format!("✅ {}", collection.name)
+        } else {
+            collection.name.to_owned()
+        };
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            label,
+            MenuCommands::get_toggle_collection_cocktail_command_string(&collection.id),
+        )]);
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::get_cocktail_by_id_command_string(cocktail_id, prev_page),
+    )]);
 
     InlineKeyboardMarkup::new(keyboard)
 }
 
-pub fn get_profile_page_keyboard() -> InlineKeyboardMarkup {
+fn get_simple_navigate_line(
+    current_page: &PageNumber,
+    total_count: u64,
+    page_size: &u64,
+    page_command: fn(&PageNumber) -> String,
+) -> Vec<InlineKeyboardButton> {
+    let available_pages: u64 = if !total_count.is_multiple_of(*page_size) {
+        (total_count / page_size) + 1
+    } else {
+        total_count / page_size
+    };
+    let mut navigate_line: Vec<InlineKeyboardButton> = vec![];
+    if available_pages <= 1 {
+        return navigate_line;
+    }
+    if current_page.0 > 0 {
+        navigate_line.push(InlineKeyboardButton::callback(
+            "👈",
+            page_command(&current_page.previous()),
+        ));
+    }
+    navigate_line.push(InlineKeyboardButton::callback(
+        format!(
+            "{}/{}",
+            current_page.human_readable_page_number().0,
+            available_pages
+        ),
+        page_command(current_page),
+    ));
+    if current_page.next().0 < available_pages {
+        navigate_line.push(InlineKeyboardButton::callback(
+            "👉",
+            page_command(&current_page.next()),
+        ));
+    }
+    navigate_line
+}
+
+pub fn get_my_bar_keyboard(
+    ingredients: &[String],
+    current_page: &PageNumber,
+    page_size: &u64,
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    let ingredients_on_page = ingredients
+        .iter()
+        .enumerate()
+        .skip((current_page.0 * page_size) as usize)
+        .take(*page_size as usize);
+    for (ingredient_index, ingredient) in ingredients_on_page {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            format!("❌ {}", ingredient),
+            MenuCommands::get_remove_bar_ingredient_command_string(
+                &(ingredient_index as u64),
+                current_page,
+            ),
+        )]);
+    }
+    let navigate_line = get_simple_navigate_line(
+        current_page,
+        ingredients.len() as u64,
+        page_size,
+        MenuCommands::get_my_bar_command_string,
+    );
+    if !navigate_line.is_empty() {
+        keyboard.push(navigate_line);
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("bar-add-button"),
+        MenuCommands::AddBarIngredient.as_ref(),
+    )]);
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("bar-what-can-i-make-button"),
+        MenuCommands::get_what_can_i_make_command_string(&PageNumber(0)),
+    )]);
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::ProfilePage.as_ref(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_bar_ingredient_suggestions_keyboard(
+    suggestions: &[String],
+    bar_ingredients: &[String],
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    for (suggestion_index, suggestion) in suggestions.iter().enumerate() {
+        let label = if bar_ingredients.contains(suggestion) {
+            format!("✅ {}", suggestion)
+        } else {
+            suggestion.to_owned()
+        };
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            label,
+            MenuCommands::get_select_bar_ingredient_command_string(&(suggestion_index as u64)),
+        )]);
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-done"),
+        MenuCommands::get_my_bar_command_string(&PageNumber(0)),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_what_can_i_make_keyboard(
+    cocktails_paged: &CocktailsByIngredientsPaged,
+    current_page: &PageNumber,
+    page_size: &u64,
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    for cocktail in &cocktails_paged.items {
+        let label = if cocktail.missing_ingredients.is_empty() {
+            format!("✅ {}", cocktail.russian_name)
+        } else {
+            language.text_with(
+                "what-can-i-make-missing",
+                &[
+                    ("name", cocktail.russian_name.as_str().into()),
+                    ("count", cocktail.missing_ingredients.len().into()),
+                ],
+            )
+        };
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            label,
+            MenuCommands::get_cocktail_by_id_command_string(
+                &cocktail.id,
+                &MenuCommands::WhatCanIMake(current_page.0),
+            ),
+        )]);
+    }
+    let navigate_line = get_simple_navigate_line(
+        current_page,
+        cocktails_paged.total_count,
+        page_size,
+        MenuCommands::get_what_can_i_make_command_string,
+    );
+    if !navigate_line.is_empty() {
+        keyboard.push(navigate_line);
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::get_my_bar_command_string(&PageNumber(0)),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_profile_page_keyboard(language: Language) -> InlineKeyboardMarkup {
     let keyboard: Vec<Vec<InlineKeyboardButton>> = vec![
         vec![InlineKeyboardButton::callback(
-            "❤ Показать избранное",
+            language.text("profile-favorites"),
             MenuCommands::get_favorite_cocktails_command_string(&PageNumber(0)),
         )],
         vec![InlineKeyboardButton::callback(
-            "🗑 Удалить учетную запись",
+            language.text("profile-collections"),
+            MenuCommands::get_collections_list_command_string(&PageNumber(0)),
+        )],
+        vec![InlineKeyboardButton::callback(
+            language.text("profile-bar"),
+            MenuCommands::get_my_bar_command_string(&PageNumber(0)),
+        )],
+        vec![InlineKeyboardButton::callback(
+            language.text("profile-recommendations"),
+            MenuCommands::Recommendations.as_ref(),
+        )],
+        vec![InlineKeyboardButton::callback(
+            language.text("profile-daily"),
+            MenuCommands::DailySubscription.as_ref(),
+        )],
+        vec![InlineKeyboardButton::callback(
+            language.text_with(
+                "profile-language",
+                &[("language", language.text("language-name").into())],
+            ),
+            MenuCommands::ChooseLanguage.as_ref(),
+        )],
+        vec![InlineKeyboardButton::callback(
+            language.text("profile-remove-account"),
             MenuCommands::RemoveAccountConfirmation.as_ref(),
         )],
         vec![InlineKeyboardButton::callback(
-            "👈 Назад",
+            language.text("button-back"),
             MenuCommands::MainMenu.as_ref(),
         )],
     ];
 
     InlineKeyboardMarkup::new(keyboard)
 }
+
+/// Cards of similar cocktails are opened with back button to main menu,
+/// because callback data has no room to keep the way back to the source cocktail.
+pub fn get_similar_cocktails_keyboard(
+    similar: &[SimilarCocktail],
+    cocktail_id: &uuid::Uuid,
+    prev_page: &MenuCommands,
+    language: Language,
+    locales: &LocaleChain,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = similar
+        .iter()
+        .map(|similar| {
+            vec![InlineKeyboardButton::callback(
+                format!(
+                    "{} ({}%)",
+                    similar.localized_name(locales),
+                    (similar.score * 100.0).round()
+                ),
+                MenuCommands::get_cocktail_by_id_command_string(
+                    &similar.id,
+                    &MenuCommands::MainMenu,
+                ),
+            )]
+        })
+        .collect();
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::get_cocktail_by_id_command_string(cocktail_id, prev_page),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_recommendations_keyboard(
+    recommendations: &[Recommendation],
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = recommendations
+        .iter()
+        .map(|recommendation| {
+            vec![InlineKeyboardButton::callback(
+                recommendation.russian_name.to_owned(),
+                MenuCommands::get_cocktail_by_id_command_string(
+                    &recommendation.id,
+                    &MenuCommands::Recommendations,
+                ),
+            )]
+        })
+        .collect();
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::ProfilePage.as_ref(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+/// Time zones offered for daily subscription: id of button label in message catalogues
+/// and IANA name.
+pub const DAILY_SUBSCRIPTION_TIMEZONES: &[(&str, &str)] = &[
+    ("timezone-kaliningrad", "Europe/Kaliningrad"),
+    ("timezone-moscow", "Europe/Moscow"),
+    ("timezone-samara", "Europe/Samara"),
+    ("timezone-yekaterinburg", "Asia/Yekaterinburg"),
+    ("timezone-omsk", "Asia/Omsk"),
+    ("timezone-novosibirsk", "Asia/Novosibirsk"),
+    ("timezone-irkutsk", "Asia/Irkutsk"),
+    ("timezone-yakutsk", "Asia/Yakutsk"),
+    ("timezone-vladivostok", "Asia/Vladivostok"),
+    ("timezone-magadan", "Asia/Magadan"),
+    ("timezone-kamchatka", "Asia/Kamchatka"),
+];
+
+/// Values the max ingredients button switches through, 0 means any number.
+const DAILY_MAX_INGREDIENTS_STEPS: &[u32] = &[0, 3, 5, 7];
+
+pub fn get_daily_subscription_keyboard(
+    subscription: &Option<DailySubscription>,
+    language: Language,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = vec![];
+    match subscription {
+        Some(subscription) => {
+            keyboard.push(vec![InlineKeyboardButton::callback(
+                language.text_with(
+                    "daily-time",
+                    &[(
+                        "time",
+                        subscription.send_at.format("%H:%M").to_string().into(),
+                    )],
+                ),
+                MenuCommands::ChooseDailyTime.as_ref(),
+            )]);
+            keyboard.push(vec![InlineKeyboardButton::callback(
+                language.text_with(
+                    "daily-timezone",
+                    &[("timezone", subscription.timezone.as_str().into())],
+                ),
+                MenuCommands::ChooseDailyTimezone.as_ref(),
+            )]);
+            keyboard.push(vec![InlineKeyboardButton::callback(
+                if subscription.filter.non_alcoholic {
+                    language.text("daily-non-alcoholic-on")
+                } else {
+                    language.text("daily-non-alcoholic-off")
+                },
+                MenuCommands::ToggleDailyNonAlcoholic.as_ref(),
+            )]);
+            let max_ingredients = subscription.filter.max_ingredients.unwrap_or(0);
+            let next_max_ingredients = DAILY_MAX_INGREDIENTS_STEPS
+                .iter()
+                .position(|step| *step == max_ingredients)
+                .map(|index| {
+                    DAILY_MAX_INGREDIENTS_STEPS[(index + 1) % DAILY_MAX_INGREDIENTS_STEPS.len()]
+                })
+                .unwrap_or(0);
+            keyboard.push(vec![InlineKeyboardButton::callback(
+                if max_ingredients == 0 {
+                    language.text("daily-max-ingredients-any")
+                } else {
+                    language.text_with(
+                        "daily-max-ingredients",
+                        &[("max_ingredients", max_ingredients.into())],
+                    )
+                },
+                MenuCommands::get_set_daily_max_ingredients_command_string(&next_max_ingredients),
+            )]);
+            keyboard.push(vec![InlineKeyboardButton::callback(
+                language.text("daily-unsubscribe"),
+                MenuCommands::UnsubscribeDaily.as_ref(),
+            )]);
+        }
+        None => {
+            keyboard.push(vec![InlineKeyboardButton::callback(
+                language.text("daily-subscribe"),
+                MenuCommands::SubscribeDaily.as_ref(),
+            )]);
+        }
+    }
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::ProfilePage.as_ref(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_daily_time_keyboard(language: Language) -> InlineKeyboardMarkup {
+    let hours: Vec<u32> = (0..24).collect();
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = hours
+        .chunks(6)
+        .map(|hours_row| {
+            hours_row
+                .iter()
+                .map(|hour| {
+                    InlineKeyboardButton::callback(
+                        format!("{:02}:00", hour),
+                        MenuCommands::get_set_daily_time_command_string(hour),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::DailySubscription.as_ref(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn get_daily_timezone_keyboard(language: Language) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = DAILY_SUBSCRIPTION_TIMEZONES
+        .iter()
+        .enumerate()
+        .map(|(index, (label, _))| {
+            vec![InlineKeyboardButton::callback(
+                language.text(label),
+                MenuCommands::get_set_daily_timezone_command_string(&(index as u64)),
+            )]
+        })
+        .collect();
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::DailySubscription.as_ref(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+/// Every language is named in itself, so it can be found whatever language is chosen now.
+pub fn get_language_chooser_keyboard(language: Language) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = Language::ALL
+        .iter()
+        .map(|option| {
+            let name = option.text("language-name");
+            vec![InlineKeyboardButton::callback(
+                if *option == language {
+                    format!("✅ {}", name)
+                } else {
+                    name
+                },
+                MenuCommands::get_set_language_command_string(option),
+            )]
+        })
+        .collect();
+    keyboard.push(vec![InlineKeyboardButton::callback(
+        language.text("button-back"),
+        MenuCommands::ProfilePage.as_ref(),
+    )]);
+
+    InlineKeyboardMarkup::new(keyboard)
+}
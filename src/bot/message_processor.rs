@@ -1,44 +1,68 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use chrono::NaiveTime;
 use teloxide::dispatching::dialogue::GetChatId;
 use teloxide::utils::markdown::escape;
 use uuid::Uuid;
 
 use super::commands::MenuCommands;
 use super::inline_keyboards::{self, ListCocktailsSource};
+use super::COCKTAIL_PAYLOAD_PREFIX;
 use crate::bot::inline_keyboards::PageNumber;
-use crate::domain::aggregates::cocktail::{CocktailFilter, CocktailsPaged};
-use crate::domain::aggregates::user::User;
+use crate::domain::aggregates::cocktail::{
+    CocktailFilter, CocktailImage, CocktailsPaged, ImageSize,
+};
+use crate::domain::aggregates::user::{DailySubscription, User, MAX_FAVORITE_NOTE_LENGTH};
+use crate::domain::services::recommendations::{Recommendation, RecommendationReason};
+use crate::domain::services::shopping_list::{self, CocktailServings, OwnedIngredient};
+use crate::domain::services::{ratings, recommendations, search, similarity};
+use crate::shared::card::{self, CardFormat, CocktailCard};
+use crate::shared::i18n::{self, Language};
 use crate::shared::CommandHandler;
 use crate::{
     bot::TgBotProvider,
     domain::{
         aggregates::{
             cocktail::CocktailRepo,
+            collection::{Collection, CollectionFilter, CollectionRepo},
+            rating::RatingRepo,
             user::UserRepo,
         },
         Pagination,
     },
     infrastructure,
 };
-use teloxide::payloads::{AnswerCallbackQuerySetters, EditMessageTextSetters};
-use teloxide::types::{CallbackQuery, MessageId};
+use teloxide::payloads::{
+    AnswerCallbackQuerySetters, AnswerInlineQuerySetters, EditMessageCaptionSetters,
+    EditMessageTextSetters, SendPhotoSetters,
+};
+use teloxide::types::{
+    CallbackQuery, InlineKeyboardMarkup, InlineQuery, InlineQueryResult, InlineQueryResultArticle,
+    InputFile, InputMessageContent, InputMessageContentText, MessageId, ParseMode,
+};
 use teloxide::{
     payloads::SendMessageSetters,
     prelude::Requester,
     types::{ChatId, UserId},
 };
 
+/// Telegram shows at most 50 results of inline query, a few best matches are enough.
+const INLINE_QUERY_RESULTS_LIMIT: u64 = 10;
+
 #[derive(Debug, Clone)]
-pub struct MessageProcessor<TUserRepo, TCocktailRepo> {
+pub struct MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> {
     bot_provider: TgBotProvider,
     user_repo: TUserRepo,
     cocktail_repo: TCocktailRepo,
+    rating_repo: TRatingRepo,
+    collection_repo: TCollectionRepo,
 }
 
-impl MessageProcessor<(), ()> {
+impl MessageProcessor<(), (), (), ()> {
     /// .
-    pub async fn new() -> Result<MessageProcessor<impl UserRepo, impl CocktailRepo>> {
+    pub async fn new() -> Result<
+        MessageProcessor<impl UserRepo, impl CocktailRepo, impl RatingRepo, impl CollectionRepo>,
+    > {
         let bt_prvdr = TgBotProvider::global().clone();
         let repository_factory = infrastructure::RepositoryFactory::global().clone();
         let user_repository = repository_factory
@@ -49,11 +73,190 @@ impl MessageProcessor<(), ()> {
             .get_cocktails_repository()
             .await
             .context("failed to create cocktail repo in message processor")?;
+        let rating_repository = repository_factory
+            .get_ratings_repository()
+            .await
+            .context("failed to create rating repo in message processor")?;
+        let collection_repository = repository_factory
+            .get_collections_repository()
+            .await
+            .context("failed to create collection repo in message processor")?;
 
         Ok(MessageProcessor {
             bot_provider: bt_prvdr,
             user_repo: user_repository,
             cocktail_repo: cocktail_repository,
+            rating_repo: rating_repository,
+            collection_repo: collection_repository,
+        })
+    }
+}
+
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+{
+    /// Language of the bot for user, `language_code` is the one of user's Telegram client.
+    pub async fn get_language(
+        &self,
+        user_id: &UserId,
+        language_code: Option<&str>,
+    ) -> Result<Language> {
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        Ok(Language::choose(user.as_ref(), language_code))
+    }
+
+    /// Replaces the message, if any, with notice about missing cocktail and main menu.
+    async fn send_cocktail_not_found(
+        &self,
+        user_id: UserId,
+        chat_id: ChatId,
+        message_id: Option<MessageId>,
+        language: Language,
+    ) -> Result<()> {
+        let user_registered = self.user_repo.is_exist_by_telegram_id(&user_id.0).await?;
+        let keyboard = inline_keyboards::get_main_menu_keyboard(&user_registered, language);
+        let text = escape(&language.text("cocktail-not-found"));
+        if let Some(message_id) = message_id {
+            self.bot_provider
+                .bot
+                .edit_message_text(chat_id, message_id, text)
+                .reply_markup(keyboard)
+                .await?;
+        } else {
+            self.bot_provider
+                .bot
+                .send_message(chat_id, text)
+                .reply_markup(keyboard)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+{
+    /// Replaces the message, if any, with cocktail card. With `image` the card is a photo
+    /// with the text truncated to fit into a caption, otherwise the text is split into
+    /// messages, the keyboard is attached to the last one.
+    async fn send_cocktail_card(
+        &self,
+        chat_id: ChatId,
+        message_id: Option<MessageId>,
+        photo_message: bool,
+        image: Option<&CocktailImage>,
+        text: &str,
+        keyboard: InlineKeyboardMarkup,
+    ) -> Result<()> {
+        let bot = &self.bot_provider.bot;
+        let caption = card::truncate(text, card::MAX_CAPTION_LENGTH);
+        let parts = card::split(text, card::MAX_MESSAGE_LENGTH);
+        match message_id {
+            Some(message_id) if photo_message && image.is_some() => {
+                bot.edit_message_caption(chat_id, message_id)
+                    .caption(caption)
+                    .reply_markup(keyboard)
+                    .await?;
+                return Ok(());
+            }
+            Some(message_id) if !photo_message && image.is_none() && parts.len() == 1 => {
+                bot.edit_message_text(chat_id, message_id, &parts[0])
+                    .reply_markup(keyboard)
+                    .await?;
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        // a photo can't become a text message and vice versa, and a long card takes
+        // several messages, so the card is sent anew
+        let photo = match image {
+            Some(image) => get_card_photo(image).await,
+            None => None,
+        };
+        if let Some(message_id) = message_id {
+            if let Err(err) = bot.delete_message(chat_id, message_id).await {
+                log::warn!("Failed to delete message before sending card: {:?}", err);
+            }
+        }
+        if let Some(photo) = photo {
+            bot.send_photo(chat_id, photo)
+                .caption(caption)
+                .reply_markup(keyboard)
+                .await?;
+            return Ok(());
+        }
+        let Some((last, first)) = parts.split_last() else {
+            return Ok(());
+        };
+        for part in first {
+            bot.send_message(chat_id, part).await?;
+        }
+        bot.send_message(chat_id, last)
+            .reply_markup(keyboard)
+            .await?;
+        Ok(())
+    }
+
+    async fn cocktail_share_link(&self, slug: &str) -> Result<String> {
+        Ok(format!(
+            "https://t.me/{}?start={}{}",
+            self.bot_provider.username().await?,
+            COCKTAIL_PAYLOAD_PREFIX,
+            slug
+        ))
+    }
+}
+
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    /// Returns page of cocktails keeping the order of given ids.
+    async fn get_cocktails_page_by_ids(
+        &self,
+        ids: &[Uuid],
+        page: u64,
+        page_size: u64,
+    ) -> Result<CocktailsPaged> {
+        let page_ids: Vec<Uuid> = ids
+            .iter()
+            .skip((page * page_size) as usize)
+            .take(page_size as usize)
+            .cloned()
+            .collect();
+        if page_ids.is_empty() {
+            return Ok(CocktailsPaged {
+                items: vec![],
+                total_count: ids.len() as u64,
+            });
+        }
+
+        let cocktails_filter = CocktailFilter {
+            ids: Some(page_ids.clone()),
+            names: None,
+            russian_names: None,
+            tags: None,
+            countries: None,
+            sort: None,
+            pagination: Pagination {
+                page: 0,
+                items_per_page: page_size,
+            },
+        };
+        let mut page_cocktails = self.cocktail_repo.get_names(&cocktails_filter).await?;
+        page_cocktails.items.sort_by_key(|cocktail| {
+            page_ids
+                .iter()
+                .position(|id| *id == cocktail.id)
+                .unwrap_or(usize::MAX)
+        });
+        Ok(CocktailsPaged {
+            items: page_cocktails.items,
+            total_count: ids.len() as u64,
         })
     }
 }
@@ -63,33 +266,39 @@ pub struct GetMainMenuCommand {
     pub chat_id: ChatId,
     pub message_id: MessageId,
     pub edit_message: bool,
+    pub language_code: Option<String>,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetMainMenuCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<GetMainMenuCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetMainMenuCommand) -> Result<()> {
         let user_registered = self
             .user_repo
             .is_exist_by_telegram_id(&command.user_id.0)
             .await?;
-        let keyboard = inline_keyboards::get_main_menu_keyboard(&user_registered);
+        let language = self
+            .get_language(&command.user_id, command.language_code.as_deref())
+            .await?;
+        let keyboard = inline_keyboards::get_main_menu_keyboard(&user_registered, language);
+        let text = escape(&language.text("main-menu"));
 
         if command.edit_message {
-            let mut edit_message_text = self.bot_provider.bot.edit_message_text(
-                command.chat_id,
-                command.message_id,
-                "Основное меню: ",
-            );
+            let mut edit_message_text =
+                self.bot_provider
+                    .bot
+                    .edit_message_text(command.chat_id, command.message_id, text);
             edit_message_text = edit_message_text.reply_markup(keyboard.clone());
             edit_message_text.await?;
         } else {
             self.bot_provider
                 .bot
-                .send_message(command.chat_id, "Основное меню:")
+                .send_message(command.chat_id, text)
                 .reply_markup(keyboard)
                 .await?;
         }
@@ -102,37 +311,50 @@ pub struct GetCocktailsListCommand {
     pub next_page: u64,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetCocktailsListCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<GetCocktailsListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetCocktailsListCommand) -> Result<()> {
         let page_size: u64 = 10;
         let cocktails_filter = CocktailFilter {
-            ids: Some(vec![]),
+            ids: None,
             names: None,
             russian_names: None,
+            tags: None,
+            countries: None,
+            sort: None,
             pagination: Pagination {
                 page: command.next_page,
                 items_per_page: page_size,
             },
         };
         let _cocktails_names = self.cocktail_repo.get_names(&cocktails_filter).await?;
+        let language = self
+            .get_language(
+                &command.callback.from.id,
+                command.callback.from.language_code.as_deref(),
+            )
+            .await?;
         let keyboard = inline_keyboards::get_cocktails_list_keyboard(
             &_cocktails_names,
             &PageNumber(command.next_page),
             &page_size,
             ListCocktailsSource::CocktailList,
+            language,
         );
         let callback_cloned = command.callback.clone();
         let chat_id = callback_cloned.chat_id().unwrap();
         let message_id = callback_cloned.message.unwrap().id();
-        let mut edit_message_text =
-            self.bot_provider
-                .bot
-                .edit_message_text(chat_id, message_id, "Коктейли: ");
+        let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+            chat_id,
+            message_id,
+            escape(&language.text("cocktails")),
+        );
         edit_message_text = edit_message_text.reply_markup(keyboard.clone());
         edit_message_text.await?;
 
@@ -141,49 +363,90 @@ where
 }
 
 pub struct GetCocktailsFilterByNameListCommand {
+    pub user_id: UserId,
     pub chat_id: ChatId,
     pub message_id: Option<MessageId>,
     pub cocktail_name_for_filter: String,
     pub next_page: u64,
+    pub language_code: Option<String>,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetCocktailsFilterByNameListCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetCocktailsFilterByNameListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetCocktailsFilterByNameListCommand) -> Result<()> {
         let page_size: u64 = 10;
         let cocktails_filter = CocktailFilter {
             ids: None,
-            names: Some(vec![command.cocktail_name_for_filter.clone()]),
-            russian_names: Some(vec![command.cocktail_name_for_filter.clone()]),
+            names: None,
+            russian_names: None,
+            tags: None,
+            countries: None,
+            sort: None,
             pagination: Pagination {
                 page: command.next_page,
                 items_per_page: page_size,
             },
         };
-        let _cocktails_names = self.cocktail_repo.get_by_filter(&cocktails_filter).await?;
+        let mut _cocktails_names = search::search_cocktails(
+            &self.cocktail_repo,
+            &command.cocktail_name_for_filter,
+            &cocktails_filter,
+        )
+        .await?;
+        let language = self
+            .get_language(&command.user_id, command.language_code.as_deref())
+            .await?;
+        let mut text = escape(&language.text("cocktails"));
+        // nothing matched by name, look for the words in history, recipe and ingredients
+        if _cocktails_names.total_count == 0 {
+            let found = search::text_search(
+                &self.cocktail_repo,
+                &command.cocktail_name_for_filter,
+                &cocktails_filter.pagination,
+            )
+            .await?;
+            if found.total_count > 0 {
+                text = escape(&language.text("search-found-in-description"));
+                for hit in found.items.iter() {
+                    text.push_str(&format!("\n\n🍸 *{}*", escape(&hit.cocktail.russian_name)));
+                    // one snippet per cocktail keeps the message under telegram length limit
+                    if let Some(snippet) = hit
+                        .snippets
+                        .iter()
+                        .find(|snippet| snippet.field != search::SnippetField::Name)
+                    {
+                        text.push_str(&format!("\n{}", format_search_snippet(snippet)));
+                    }
+                }
+            }
+            _cocktails_names = CocktailsPaged {
+                items: found.items.into_iter().map(|hit| hit.cocktail).collect(),
+                total_count: found.total_count,
+            };
+        }
         let keyboard = inline_keyboards::get_cocktails_list_keyboard(
             &_cocktails_names,
             &PageNumber(command.next_page),
             &page_size,
             ListCocktailsSource::CocktailListByName,
+            language,
         );
         let _ = if let Some(message_id) = command.message_id {
-            let mut send_message = self.bot_provider.bot.edit_message_text(
-                command.chat_id,
-                message_id,
-                escape("Коктейли: "),
-            );
+            let mut send_message =
+                self.bot_provider
+                    .bot
+                    .edit_message_text(command.chat_id, message_id, text);
             send_message = send_message.reply_markup(keyboard.clone());
             send_message.await?;
         } else {
-            let mut send_message = self
-                .bot_provider
-                .bot
-                .send_message(command.chat_id, escape("Коктейли: "));
+            let mut send_message = self.bot_provider.bot.send_message(command.chat_id, text);
             send_message = send_message.reply_markup(keyboard.clone());
             send_message.await?;
         };
@@ -192,16 +455,134 @@ where
     }
 }
 
+/// Formats snippet for MarkdownV2 with matched words in bold.
+fn format_search_snippet(snippet: &search::SearchSnippet) -> String {
+    let chars: Vec<char> = snippet.text.chars().collect();
+    let mut result = String::new();
+    let mut position = 0;
+    for (start, end) in snippet.highlights.iter() {
+        result.push_str(&escape(&chars[position..*start].iter().collect::<String>()));
+        result.push_str(&format!(
+            "*{}*",
+            escape(&chars[*start..*end].iter().collect::<String>())
+        ));
+        position = *end;
+    }
+    result.push_str(&escape(&chars[position..].iter().collect::<String>()));
+    format!("_{}_", result)
+}
+
+pub struct GetTagsListCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: MessageId,
+    pub page: u64,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<GetTagsListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetTagsListCommand) -> Result<()> {
+        let page_size: u64 = 10;
+        let tags = self.cocktail_repo.get_tags().await?;
+        let language = self
+            .get_language(&command.user_id, command.language_code.as_deref())
+            .await?;
+        let keyboard = inline_keyboards::get_tags_list_keyboard(
+            &tags,
+            &PageNumber(command.page),
+            &page_size,
+            language,
+        );
+        let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+            command.chat_id,
+            command.message_id,
+            escape(&language.text("tags")),
+        );
+        edit_message_text = edit_message_text.reply_markup(keyboard);
+        edit_message_text.await?;
+
+        Ok(())
+    }
+}
+
+pub struct GetCocktailsFilterByTagListCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: MessageId,
+    pub tag_name: String,
+    pub next_page: u64,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetCocktailsFilterByTagListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetCocktailsFilterByTagListCommand) -> Result<()> {
+        let page_size: u64 = 10;
+        let cocktails_filter = CocktailFilter {
+            ids: None,
+            names: None,
+            russian_names: None,
+            tags: Some(vec![command.tag_name.clone()]),
+            countries: None,
+            sort: None,
+            pagination: Pagination {
+                page: command.next_page,
+                items_per_page: page_size,
+            },
+        };
+        let cocktails_names = self.cocktail_repo.get_names(&cocktails_filter).await?;
+        let language = self
+            .get_language(&command.user_id, command.language_code.as_deref())
+            .await?;
+        let keyboard = inline_keyboards::get_cocktails_list_keyboard(
+            &cocktails_names,
+            &PageNumber(command.next_page),
+            &page_size,
+            ListCocktailsSource::CocktailListByTag,
+            language,
+        );
+        let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+            command.chat_id,
+            command.message_id,
+            escape(&language.text_with(
+                "cocktails-by-tag",
+                &[("tag", command.tag_name.as_str().into())],
+            )),
+        );
+        edit_message_text = edit_message_text.reply_markup(keyboard);
+        edit_message_text.await?;
+
+        Ok(())
+    }
+}
+
 pub struct GetFavoriteCocktailsListCommand {
     pub callback: CallbackQuery,
     pub next_page: u64,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetFavoriteCocktailsListCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetFavoriteCocktailsListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetFavoriteCocktailsListCommand) -> Result<()> {
         let user_id = command.callback.from.id;
@@ -211,33 +592,24 @@ where
         let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
         if let Some(user) = user {
             let page_size: u64 = 10;
-            let cocktails_names = if user.favorite_cocktails.is_empty() {
-                CocktailsPaged {
-                    items: vec![],
-                    total_count: 0,
-                }
-            } else {
-                let cocktails_filter = CocktailFilter {
-                    ids: Some(user.favorite_cocktails),
-                    names: None,
-                    russian_names: None,
-                    pagination: Pagination {
-                        page: command.next_page,
-                        items_per_page: page_size,
-                    },
-                };
-                self.cocktail_repo.get_names(&cocktails_filter).await?
-            };
+            let language =
+                Language::choose(Some(&user), command.callback.from.language_code.as_deref());
+            let favorite_ids = user.get_favorite_ids_by_recency();
+            let cocktails_names = self
+                .get_cocktails_page_by_ids(&favorite_ids, command.next_page, page_size)
+                .await?;
             let keyboard = inline_keyboards::get_cocktails_list_keyboard(
                 &cocktails_names,
                 &PageNumber(command.next_page),
                 &page_size,
                 ListCocktailsSource::Favorites,
+                language,
+            );
+            let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+                chat_id,
+                message_id,
+                escape(&language.text("cocktails")),
             );
-            let mut edit_message_text =
-                self.bot_provider
-                    .bot
-                    .edit_message_text(chat_id, message_id, "Коктейли: ");
             edit_message_text = edit_message_text.reply_markup(keyboard.clone());
             edit_message_text.await?;
         };
@@ -252,23 +624,32 @@ pub struct GetCocktailPagesCommand {
     pub total_pages: u64,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetCocktailPagesCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<GetCocktailPagesCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetCocktailPagesCommand) -> Result<()> {
         let callback_cloned = command.callback.clone();
         let chat_id = callback_cloned.chat_id().unwrap();
         let message_id = callback_cloned.message.unwrap().id();
 
+        let language = self
+            .get_language(
+                &command.callback.from.id,
+                command.callback.from.language_code.as_deref(),
+            )
+            .await?;
         let keyboard =
             inline_keyboards::get_cocktail_pages_keyboard(&command.total_pages, &command.prev_page);
-        let mut edit_message_text =
-            self.bot_provider
-                .bot
-                .edit_message_text(chat_id, message_id, "Доступные страницы: ");
+        let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+            chat_id,
+            message_id,
+            escape(&language.text("available-pages")),
+        );
         edit_message_text = edit_message_text.reply_markup(keyboard.clone());
         edit_message_text.await?;
 
@@ -277,92 +658,235 @@ where
 }
 
 pub struct GetCocktailPageByIdCommand {
-    pub callback: CallbackQuery,
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: Option<MessageId>,
+    /// `message_id` is a photo card, so its caption is edited instead of the text.
+    pub photo_message: bool,
+    /// History is collapsed until the user presses the button to show it.
+    pub show_history: bool,
     pub prev_page: MenuCommands,
     pub cocktail_id: uuid::Uuid,
+    /// Telegram `language_code` of the user, used while user has not chosen the language
+    /// of the bot.
+    pub language_code: Option<String>,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetCocktailPageByIdCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetCocktailPageByIdCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetCocktailPageByIdCommand) -> Result<()> {
-        let chat_id = command.callback.chat_id().unwrap();
-        let message_id = command.callback.message.unwrap().id();
-        let user_id = command.callback.from.id;
+        let user_id = command.user_id;
 
         let cocktail = self.cocktail_repo.get_by_id(&command.cocktail_id).await?;
         match cocktail {
             Some(cock) => {
-                let mut result_string = format!("🍸*Коктейль:* {}\n", escape(&cock.russian_name));
-                result_string.push_str(&format!(
-                    "*Английское название:* {}\n",
-                    escape(&cock.name.unwrap())
-                ));
-                result_string.push_str("\n*Ингредиенты:*\n");
-                for com_el in cock.composition_elements.unwrap() {
-                    result_string.push_str(&format!(
-                        "👉 {} {}{}\n",
-                        escape(&com_el.name),
-                        com_el.count,
-                        escape(&com_el.unit)
-                    ));
-                }
-                result_string.push_str("\n*Требуемые инструменты:*\n");
-                for tool in cock.tools.unwrap() {
-                    result_string.push_str(&format!(
-                        "👉 {} {}{}\n",
-                        escape(&tool.name),
-                        tool.count,
-                        escape(&tool.unit)
-                    ));
-                }
-                result_string.push_str("\n*Способ приготовления:*\n");
-                for (i, recipe_step) in cock.recipe.unwrap().steps.iter().enumerate() {
-                    result_string.push_str(&format!("{}\\. {}\n", i + 1, escape(recipe_step)));
-                }
-                result_string.push_str("\n*История для этого коктейля:*\n");
-                result_string.push_str(&escape(&cock.history.unwrap()));
-                result_string.push_str("\n\n*Теги:*\n");
-                for tag in cock.tags.unwrap() {
-                    result_string.push_str(&format!("\\#{} ", tag.name.replace(" ", "\\_")));
-                }
-
                 let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
-
-                let mut edit_message_text =
-                    self.bot_provider
-                        .bot
-                        .edit_message_text(chat_id, message_id, &result_string);
-                let keyboard = if let Some(user) = user {
-                    if user.favorite_cocktails.contains(&command.cocktail_id) {
-                        inline_keyboards::get_cocktail_card_navigate_keyboard(
-                            &command.prev_page,
-                            &command.cocktail_id,
-                            &Some(true),
-                        )
-                    } else {
-                        inline_keyboards::get_cocktail_card_navigate_keyboard(
-                            &command.prev_page,
-                            &command.cocktail_id,
-                            &Some(false),
-                        )
+                if let Some(user) = &user {
+                    self.user_repo
+                        .record_cocktail_view(&user.id, &command.cocktail_id)
+                        .await?;
+                }
+                let user_rating = match &user {
+                    Some(user) => {
+                        self.rating_repo
+                            .get_by_user_and_cocktail(&user.id, &command.cocktail_id)
+                            .await?
                     }
-                } else {
-                    inline_keyboards::get_cocktail_card_navigate_keyboard(
-                        &command.prev_page,
-                        &command.cocktail_id,
-                        &None,
-                    )
+                    None => None,
                 };
-                edit_message_text = edit_message_text.reply_markup(keyboard);
 
-                edit_message_text.await?;
-                Ok(())
+                let language = Language::choose(user.as_ref(), command.language_code.as_deref());
+                let locales =
+                    i18n::content_locales(user.as_ref(), command.language_code.as_deref());
+                let note = user
+                    .as_ref()
+                    .and_then(|user| user.get_favorite(&command.cocktail_id))
+                    .and_then(|favorite| favorite.note.clone());
+                let mut card = CocktailCard::new(&cock, language, &locales);
+                card.user_rating = user_rating.as_ref().map(|rating| rating.stars);
+                card.note = note.as_deref();
+                // coming from "what can I make" list, so mark ingredients user already has
+                card.bar_ingredients = match (&command.prev_page, &user) {
+                    (MenuCommands::WhatCanIMake(_), Some(user)) => Some(&user.bar_ingredients),
+                    _ => None,
+                };
+                card.share_link = match &cock.slug {
+                    Some(slug) => Some(self.cocktail_share_link(slug).await?),
+                    None => None,
+                };
+                card.show_history = command.show_history;
+                let text = card.render(card::CHAT_CARD_TEMPLATE, CardFormat::Markdown);
+
+                let favorite = user
+                    .as_ref()
+                    .map(|user| user.is_favorite(&command.cocktail_id));
+                let show_history = card.has_history().then_some(command.show_history);
+                let keyboard = inline_keyboards::get_cocktail_card_navigate_keyboard(
+                    &command.prev_page,
+                    &command.cocktail_id,
+                    &favorite,
+                    &card.user_rating,
+                    &show_history,
+                    language,
+                );
+                // the photo is left out when the user asks for history, which does not fit
+                // into a caption, otherwise the caption is truncated
+                let image = cock.image.as_ref().filter(|_| {
+                    !command.show_history || card::message_length(&text) <= card::MAX_CAPTION_LENGTH
+                });
+                self.send_cocktail_card(
+                    command.chat_id,
+                    command.message_id,
+                    command.photo_message,
+                    image,
+                    &text,
+                    keyboard,
+                )
+                .await
+            }
+            // cocktail was deleted, but favourites or old messages still point to it
+            None => {
+                let language = self
+                    .get_language(&user_id, command.language_code.as_deref())
+                    .await?;
+                self.send_cocktail_not_found(user_id, command.chat_id, command.message_id, language)
+                    .await
+            }
+        }
+    }
+}
+
+/// Query of inline mode, `@bot <name>` in any chat offers cards of matching cocktails.
+pub struct AnswerInlineQueryCommand {
+    pub query: InlineQuery,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<AnswerInlineQueryCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: AnswerInlineQueryCommand) -> Result<()> {
+        let from = &command.query.from;
+        let user = self.user_repo.get_by_telegram_id(&from.id.0).await?;
+        let language = Language::choose(user.as_ref(), from.language_code.as_deref());
+        let locales = i18n::content_locales(user.as_ref(), from.language_code.as_deref());
+
+        let query = command.query.query.trim();
+        let cocktails = if query.is_empty() {
+            vec![]
+        } else {
+            let cocktails_filter = CocktailFilter {
+                ids: None,
+                names: None,
+                russian_names: None,
+                tags: None,
+                countries: None,
+                sort: None,
+                pagination: Pagination {
+                    page: 0,
+                    items_per_page: INLINE_QUERY_RESULTS_LIMIT,
+                },
+            };
+            search::search_cocktails(&self.cocktail_repo, query, &cocktails_filter)
+                .await?
+                .items
+        };
+
+        let mut results = vec![];
+        for cocktail in &cocktails {
+            let mut card = CocktailCard::new(cocktail, language, &locales);
+            card.share_link = match &cocktail.slug {
+                Some(slug) => Some(self.cocktail_share_link(slug).await?),
+                None => None,
+            };
+            let text = card::truncate(
+                &card.render(card::SHARED_CARD_TEMPLATE, CardFormat::Markdown),
+                card::MAX_MESSAGE_LENGTH,
+            );
+            let content = InputMessageContent::Text(
+                InputMessageContentText::new(text).parse_mode(ParseMode::MarkdownV2),
+            );
+            let mut article = InlineQueryResultArticle::new(
+                cocktail.id.to_string(),
+                cocktail.localized_name(&locales),
+                content,
+            );
+            if let Some(name) = cocktail.name.as_deref().filter(|name| !name.is_empty()) {
+                article = article.description(name);
+            }
+            // Telegram loads thumbnails by itself, so only absolute URLs are usable
+            let thumbnail_url = cocktail
+                .image
+                .as_ref()
+                .and_then(|image| image.variant(ImageSize::Thumbnail))
+                .and_then(|variant| url::Url::parse(&variant.url).ok());
+            if let Some(thumbnail_url) = thumbnail_url {
+                article = article.thumbnail_url(thumbnail_url);
+            }
+            results.push(InlineQueryResult::Article(article));
+        }
+
+        self.bot_provider
+            .bot
+            .answer_inline_query(command.query.id.clone(), results)
+            // results depend on the language of the user
+            .is_personal(true)
+            .await?;
+        Ok(())
+    }
+}
+
+pub struct GetCocktailPageBySlugCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    /// Current or previous slug of the cocktail.
+    pub slug: String,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetCocktailPageBySlugCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetCocktailPageBySlugCommand) -> Result<()> {
+        match self.cocktail_repo.get_by_slug(&command.slug).await? {
+            Some(cocktail) => {
+                self.handle(GetCocktailPageByIdCommand {
+                    user_id: command.user_id,
+                    chat_id: command.chat_id,
+                    message_id: None,
+                    photo_message: false,
+                    show_history: false,
+                    prev_page: MenuCommands::MainMenu,
+                    cocktail_id: cocktail.id,
+                    language_code: command.language_code,
+                })
+                .await
+            }
+            None => {
+                let language = self
+                    .get_language(&command.user_id, command.language_code.as_deref())
+                    .await?;
+                self.send_cocktail_not_found(command.user_id, command.chat_id, None, language)
+                    .await
             }
-            None => panic!("Coctail not found"),
         }
     }
 }
@@ -370,27 +894,28 @@ where
 pub struct GetRegisterUserConfigrationCommand {
     pub chat_id: ChatId,
     pub message_id: MessageId,
+    /// User is not registered yet, so only the language of Telegram client is known.
+    pub language_code: Option<String>,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetRegisterUserConfigrationCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetRegisterUserConfigrationCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetRegisterUserConfigrationCommand) -> Result<()> {
-        let mut registration_confirmation_text = "Подтверждая регистрацию, вы соглашаетесь на то, что мы сохраняем ваш идентификатор пользователя Telegram. Другую информацию мы не собираем.\n\n".to_string();
-        registration_confirmation_text.push_str("У вас появляется возможность сохранять любимые коктейли в свою личную подборку, чтобы проще было их искать.\n\n");
-        registration_confirmation_text
-            .push_str("В любой момент вы можете полностью удалить свой профиль.\n");
-        registration_confirmation_text.push_str("Приятного использования ☺️");
+        let language = Language::choose(None, command.language_code.as_deref());
         let mut edit_message_text = self.bot_provider.bot.edit_message_text(
             command.chat_id,
             command.message_id,
-            escape(&registration_confirmation_text),
+            escape(&language.text("register-confirmation")),
         );
         let registration_confirmation_keyboard =
-            inline_keyboards::get_register_confirmation_keyboard();
+            inline_keyboards::get_register_confirmation_keyboard(language);
         edit_message_text = edit_message_text.reply_markup(registration_confirmation_keyboard);
         edit_message_text.await?;
 
@@ -402,11 +927,13 @@ pub struct RegisterUserCommand {
     pub callback: CallbackQuery,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<RegisterUserCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<RegisterUserCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: RegisterUserCommand) -> Result<()> {
         let user_id = command.callback.from.id;
@@ -417,15 +944,22 @@ where
             id: Uuid::new_v4(),
             telegram_id: user_id.0,
             favorite_cocktails: vec![],
+            bar_ingredients: vec![],
+            daily_subscription: None,
+            daily_last_sent_on: None,
+            viewed_cocktails: vec![],
+            language: None,
         };
         self.user_repo.create(&user_to_add).await?;
 
+        let language_code = command.callback.from.language_code;
+        let language = Language::choose(Some(&user_to_add), language_code.as_deref());
         let callback_query_answer = self
             .bot_provider
             .bot
             .answer_callback_query(&command.callback.id)
             .show_alert(true)
-            .text("Вы успешно зарегистрированы".to_string())
+            .text(language.text("register-success"))
             .await?;
         log::info!("Send callback register result {:?}", callback_query_answer);
 
@@ -434,6 +968,7 @@ where
             chat_id,
             message_id,
             edit_message: true,
+            language_code,
         })
         .await?;
 
@@ -442,29 +977,33 @@ where
 }
 
 pub struct GetRemoveUserConfirmationCommand {
+    pub user_id: UserId,
     pub chat_id: ChatId,
     pub message_id: MessageId,
+    pub language_code: Option<String>,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetRemoveUserConfirmationCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetRemoveUserConfirmationCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetRemoveUserConfirmationCommand) -> Result<()> {
-        let mut remove_user_confirmation_text =
-            "Вы точно хотите удалить свой профиль?\n\n".to_string();
-
-        remove_user_confirmation_text.push_str("Все избранные коктейли будут удалены. 😔\n");
-
+        let language = self
+            .get_language(&command.user_id, command.language_code.as_deref())
+            .await?;
         let mut edit_message_text = self.bot_provider.bot.edit_message_text(
             command.chat_id,
             command.message_id,
-            escape(&remove_user_confirmation_text),
+            escape(&language.text("remove-account-confirmation")),
+        );
+        edit_message_text = edit_message_text.reply_markup(
+            inline_keyboards::get_remove_user_confirmation_keyboard(language),
         );
-        edit_message_text = edit_message_text
-            .reply_markup(inline_keyboards::get_remove_user_confirmation_keyboard());
         edit_message_text.await?;
         Ok(())
     }
@@ -474,11 +1013,13 @@ pub struct RemoveUserCommand {
     pub callback: CallbackQuery,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<RemoveUserCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<RemoveUserCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: RemoveUserCommand) -> Result<()> {
         let user_id = command.callback.from.id;
@@ -488,12 +1029,14 @@ where
         let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
         if let Some(user) = user {
             self.user_repo.delete(&user).await?;
+            let language_code = command.callback.from.language_code;
+            let language = Language::choose(Some(&user), language_code.as_deref());
             let callback_query_answer = self
                 .bot_provider
                 .bot
                 .answer_callback_query(&command.callback.id)
                 .show_alert(true)
-                .text("Вы успешно удалили свою учетную запись".to_string())
+                .text(language.text("remove-account-success"))
                 .await?;
             log::info!(
                 "Send callback remove user result {:?}",
@@ -505,6 +1048,7 @@ where
                 chat_id,
                 message_id,
                 edit_message: true,
+                language_code,
             })
             .await?;
         }
@@ -513,51 +1057,135 @@ where
 }
 
 pub struct GetProfilePageCommand {
+    pub user_id: UserId,
     pub chat_id: ChatId,
     pub message_id: MessageId,
+    pub language_code: Option<String>,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<GetProfilePageCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<GetProfilePageCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: GetProfilePageCommand) -> Result<()> {
+        let language = self
+            .get_language(&command.user_id, command.language_code.as_deref())
+            .await?;
         let mut edit_message_text = self.bot_provider.bot.edit_message_text(
             command.chat_id,
             command.message_id,
-            "Личный кабинет:",
+            escape(&language.text("profile-page")),
         );
         edit_message_text =
-            edit_message_text.reply_markup(inline_keyboards::get_profile_page_keyboard());
+            edit_message_text.reply_markup(inline_keyboards::get_profile_page_keyboard(language));
+        edit_message_text.await?;
+        Ok(())
+    }
+}
+
+pub struct GetLanguageChooserCommand {
+    pub callback: CallbackQuery,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetLanguageChooserCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetLanguageChooserCommand) -> Result<()> {
+        let language = self
+            .get_language(
+                &command.callback.from.id,
+                command.callback.from.language_code.as_deref(),
+            )
+            .await?;
+        let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+            command.callback.chat_id().unwrap(),
+            command.callback.message.as_ref().unwrap().id(),
+            escape(&language.text("language-chooser")),
+        );
+        edit_message_text = edit_message_text
+            .reply_markup(inline_keyboards::get_language_chooser_keyboard(language));
         edit_message_text.await?;
         Ok(())
     }
 }
 
+pub struct SetLanguageCommand {
+    pub callback: CallbackQuery,
+    pub language: Language,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<SetLanguageCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: SetLanguageCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        if let Some(mut user) = user {
+            user.language = Some(command.language.code().to_string());
+            self.user_repo.update(&user).await?;
+            self.handle(GetProfilePageCommand {
+                user_id,
+                chat_id: command.callback.chat_id().unwrap(),
+                message_id: command.callback.message.as_ref().unwrap().id(),
+                language_code: command.callback.from.language_code,
+            })
+            .await?;
+        } else {
+            log::warn!("User with id {} not found in store", user_id.0);
+        }
+        Ok(())
+    }
+}
+
 pub struct AddCocktailToFavoriteCommand {
     pub callback: CallbackQuery,
     pub prev_page: MenuCommands,
     pub cocktail_id: uuid::Uuid,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<AddCocktailToFavoriteCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<AddCocktailToFavoriteCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: AddCocktailToFavoriteCommand) -> Result<()> {
         let user_id = command.callback.from.id;
         let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
         if let Some(mut user) = user {
-            user.favorite_cocktails.push(command.cocktail_id);
+            user.add_favorite(&command.cocktail_id);
             self.user_repo.update(&user).await?;
             self.handle(GetCocktailPageByIdCommand {
-                callback: command.callback.clone(),
+                user_id,
+                chat_id: command.callback.chat_id().unwrap(),
+                message_id: command
+                    .callback
+                    .message
+                    .as_ref()
+                    .map(|message| message.id()),
+                photo_message: is_photo_message(&command.callback),
+                show_history: false,
                 prev_page: command.prev_page,
                 cocktail_id: command.cocktail_id,
+                language_code: command.callback.from.language_code.clone(),
             })
             .await?;
             Ok(())
@@ -574,27 +1202,92 @@ pub struct RemoveCocktailFromFavoriteCommand {
     pub cocktail_id: uuid::Uuid,
 }
 #[async_trait]
-impl<TUserRepo, TCocktailRepo> CommandHandler<RemoveCocktailFromFavoriteCommand>
-    for MessageProcessor<TUserRepo, TCocktailRepo>
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<RemoveCocktailFromFavoriteCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
 where
     TUserRepo: UserRepo + Sync,
     TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
 {
     async fn handle(&self, command: RemoveCocktailFromFavoriteCommand) -> Result<()> {
         let user_id = command.callback.from.id;
         let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
         if let Some(mut user) = user {
-            let index = user
-                .favorite_cocktails
-                .iter()
-                .position(|x| *x == command.cocktail_id)
-                .unwrap();
-            user.favorite_cocktails.remove(index);
+            user.remove_favorite(&command.cocktail_id);
             self.user_repo.update(&user).await?;
             self.handle(GetCocktailPageByIdCommand {
-                callback: command.callback.clone(),
+                user_id,
+                chat_id: command.callback.chat_id().unwrap(),
+                message_id: command
+                    .callback
+                    .message
+                    .as_ref()
+                    .map(|message| message.id()),
+                photo_message: is_photo_message(&command.callback),
+                show_history: false,
+                prev_page: command.prev_page,
+                cocktail_id: command.cocktail_id,
+                language_code: command.callback.from.language_code.clone(),
+            })
+            .await?;
+            Ok(())
+        } else {
+            log::warn!("User with id {} not found in store", user_id.0);
+            Ok(())
+        }
+    }
+}
+
+pub struct RateCocktailCommand {
+    pub callback: CallbackQuery,
+    pub prev_page: MenuCommands,
+    pub cocktail_id: uuid::Uuid,
+    pub stars: u8,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<RateCocktailCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: RateCocktailCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        if let Some(user) = user {
+            ratings::rate_cocktail(
+                &self.rating_repo,
+                &self.cocktail_repo,
+                &user.id,
+                &command.cocktail_id,
+                command.stars,
+                None,
+            )
+            .await?;
+            let language =
+                Language::choose(Some(&user), command.callback.from.language_code.as_deref());
+            self.bot_provider
+                .bot
+                .answer_callback_query(&command.callback.id)
+                .text(language.text("rating-thanks"))
+                .await?;
+            self.handle(GetCocktailPageByIdCommand {
+                user_id,
+                chat_id: command.callback.chat_id().unwrap(),
+                message_id: command
+                    .callback
+                    .message
+                    .as_ref()
+                    .map(|message| message.id()),
+                photo_message: is_photo_message(&command.callback),
+                show_history: false,
                 prev_page: command.prev_page,
                 cocktail_id: command.cocktail_id,
+                language_code: command.callback.from.language_code.clone(),
             })
             .await?;
             Ok(())
@@ -604,3 +1297,1139 @@ where
         }
     }
 }
+
+pub struct GetFavoriteNoteFormCommand {
+    pub callback: CallbackQuery,
+    pub prev_page: MenuCommands,
+    pub cocktail_id: uuid::Uuid,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetFavoriteNoteFormCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetFavoriteNoteFormCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let chat_id = command.callback.chat_id().unwrap();
+        let message_id = command.callback.message.unwrap().id();
+
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        let note = user
+            .as_ref()
+            .and_then(|user| user.get_favorite(&command.cocktail_id))
+            .and_then(|favorite| favorite.note.clone());
+
+        let language = Language::choose(
+            user.as_ref(),
+            command.callback.from.language_code.as_deref(),
+        );
+        let mut note_form_text = format!(
+            "{}\n",
+            escape(&language.text_with(
+                "note-form",
+                &[("max_length", MAX_FAVORITE_NOTE_LENGTH.into())]
+            ))
+        );
+        if let Some(note) = &note {
+            note_form_text.push_str(&format!(
+                "\n*{}*\n📝 {}\n",
+                escape(&language.text("note-current")),
+                escape(note)
+            ));
+        }
+        let mut edit_message_text =
+            self.bot_provider
+                .bot
+                .edit_message_text(chat_id, message_id, note_form_text);
+        edit_message_text =
+            edit_message_text.reply_markup(inline_keyboards::get_favorite_note_form_keyboard(
+                &command.prev_page,
+                &command.cocktail_id,
+                note.is_some(),
+                language,
+            ));
+        edit_message_text.await?;
+        Ok(())
+    }
+}
+
+pub struct SetFavoriteNoteCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: Option<MessageId>,
+    pub prev_page: MenuCommands,
+    pub cocktail_id: uuid::Uuid,
+    pub note: Option<String>,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<SetFavoriteNoteCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: SetFavoriteNoteCommand) -> Result<()> {
+        let user = self
+            .user_repo
+            .get_by_telegram_id(&command.user_id.0)
+            .await?;
+        if let Some(mut user) = user {
+            if user.set_favorite_note(&command.cocktail_id, command.note) {
+                self.user_repo.update(&user).await?;
+            } else {
+                log::warn!(
+                    "Cocktail {} not found in favorites of user {}",
+                    command.cocktail_id,
+                    command.user_id.0
+                );
+            }
+            self.handle(GetCocktailPageByIdCommand {
+                user_id: command.user_id,
+                chat_id: command.chat_id,
+                message_id: command.message_id,
+                photo_message: false,
+                show_history: false,
+                prev_page: command.prev_page,
+                cocktail_id: command.cocktail_id,
+                language_code: command.language_code,
+            })
+            .await?;
+        } else {
+            log::warn!("User with id {} not found in store", command.user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub struct GetCollectionsListCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: Option<MessageId>,
+    pub page: u64,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetCollectionsListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetCollectionsListCommand) -> Result<()> {
+        let user = self
+            .user_repo
+            .get_by_telegram_id(&command.user_id.0)
+            .await?;
+        if let Some(user) = user {
+            let page_size: u64 = 10;
+            let collections = self
+                .collection_repo
+                .get_by_filter(&CollectionFilter {
+                    ids: None,
+                    owner_id: Some(user.id),
+                    pagination: Pagination {
+                        page: command.page,
+                        items_per_page: page_size,
+                    },
+                })
+                .await?;
+            let language = Language::choose(Some(&user), command.language_code.as_deref());
+            let keyboard = inline_keyboards::get_collections_list_keyboard(
+                &collections,
+                &PageNumber(command.page),
+                &page_size,
+                language,
+            );
+            let text = escape(&language.text("collections"));
+            if let Some(message_id) = command.message_id {
+                let mut edit_message_text =
+                    self.bot_provider
+                        .bot
+                        .edit_message_text(command.chat_id, message_id, text);
+                edit_message_text = edit_message_text.reply_markup(keyboard);
+                edit_message_text.await?;
+            } else {
+                let mut send_message = self.bot_provider.bot.send_message(command.chat_id, text);
+                send_message = send_message.reply_markup(keyboard);
+                send_message.await?;
+            }
+        } else {
+            log::warn!("User with id {} not found in store", command.user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub struct GetCocktailsFilterByCollectionListCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: Option<MessageId>,
+    pub collection_id: uuid::Uuid,
+    pub next_page: u64,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetCocktailsFilterByCollectionListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetCocktailsFilterByCollectionListCommand) -> Result<()> {
+        let page_size: u64 = 10;
+        let collection = self
+            .collection_repo
+            .get_by_id(&command.collection_id)
+            .await?;
+        let language = self
+            .get_language(&command.user_id, command.language_code.as_deref())
+            .await?;
+        let (result_string, keyboard) = match collection {
+            Some(collection) => {
+                let cocktails_names = self
+                    .get_cocktails_page_by_ids(
+                        &collection.cocktail_ids,
+                        command.next_page,
+                        page_size,
+                    )
+                    .await?;
+                let keyboard = inline_keyboards::get_cocktails_list_keyboard(
+                    &cocktails_names,
+                    &PageNumber(command.next_page),
+                    &page_size,
+                    ListCocktailsSource::CocktailListByCollection,
+                    language,
+                );
+
+                let share_link = format!(
+                    "https://t.me/{}?start=col_{}",
+                    self.bot_provider.username().await?,
+                    collection.id
+                );
+                let mut result_string = format!(
+                    "📚*{}* {}\n",
+                    escape(&language.text("collection")),
+                    escape(&collection.name)
+                );
+                if let Some(description) = &collection.description {
+                    result_string.push_str(&format!("{}\n", escape(description)));
+                }
+                result_string.push_str(&format!(
+                    "\n{}\n",
+                    escape(&language.text_with("collection-share", &[("link", share_link.into())]))
+                ));
+                (result_string, Some(keyboard))
+            }
+            None => (escape(&language.text("collection-not-found")), None),
+        };
+
+        if let Some(message_id) = command.message_id {
+            let mut edit_message_text =
+                self.bot_provider
+                    .bot
+                    .edit_message_text(command.chat_id, message_id, result_string);
+            if let Some(keyboard) = keyboard {
+                edit_message_text = edit_message_text.reply_markup(keyboard);
+            }
+            edit_message_text.await?;
+        } else {
+            let mut send_message = self
+                .bot_provider
+                .bot
+                .send_message(command.chat_id, result_string);
+            if let Some(keyboard) = keyboard {
+                send_message = send_message.reply_markup(keyboard);
+            }
+            send_message.await?;
+        }
+        Ok(())
+    }
+}
+
+pub struct CreateCollectionCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub name: String,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<CreateCollectionCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: CreateCollectionCommand) -> Result<()> {
+        let user = self
+            .user_repo
+            .get_by_telegram_id(&command.user_id.0)
+            .await?;
+        if let Some(user) = user {
+            let collection = Collection::new(user.id, command.name, None);
+            self.collection_repo.create(&collection).await?;
+            self.handle(GetCollectionsListCommand {
+                user_id: command.user_id,
+                chat_id: command.chat_id,
+                message_id: None,
+                page: 0,
+                language_code: command.language_code,
+            })
+            .await?;
+        } else {
+            log::warn!("User with id {} not found in store", command.user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub struct GetCollectionChooserCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: MessageId,
+    pub prev_page: MenuCommands,
+    pub cocktail_id: uuid::Uuid,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetCollectionChooserCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetCollectionChooserCommand) -> Result<()> {
+        let user = self
+            .user_repo
+            .get_by_telegram_id(&command.user_id.0)
+            .await?;
+        if let Some(user) = user {
+            let collections = self
+                .collection_repo
+                .get_by_filter(&CollectionFilter {
+                    ids: None,
+                    owner_id: Some(user.id),
+                    pagination: Pagination {
+                        page: 0,
+                        items_per_page: 50,
+                    },
+                })
+                .await?;
+            let language = Language::choose(Some(&user), command.language_code.as_deref());
+            let text = if collections.items.is_empty() {
+                language.text("collection-chooser-empty")
+            } else {
+                language.text("collection-chooser")
+            };
+            let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+                command.chat_id,
+                command.message_id,
+                escape(&text),
+            );
+            edit_message_text =
+                edit_message_text.reply_markup(inline_keyboards::get_collection_chooser_keyboard(
+                    &collections.items,
+                    &command.cocktail_id,
+                    &command.prev_page,
+                    language,
+                ));
+            edit_message_text.await?;
+        } else {
+            log::warn!("User with id {} not found in store", command.user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub struct ToggleCollectionCocktailCommand {
+    pub callback: CallbackQuery,
+    pub prev_page: MenuCommands,
+    pub cocktail_id: uuid::Uuid,
+    pub collection_id: uuid::Uuid,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<ToggleCollectionCocktailCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: ToggleCollectionCocktailCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        let collection = self
+            .collection_repo
+            .get_by_id(&command.collection_id)
+            .await?;
+        match (user, collection) {
+            // only owner can change collection, shared collections are read only
+            (Some(user), Some(mut collection)) if collection.owner_id == user.id => {
+                let language_code = command.callback.from.language_code.clone();
+                let language = Language::choose(Some(&user), language_code.as_deref());
+                let answer_text = if collection.contains(&command.cocktail_id) {
+                    collection.remove_cocktail(&command.cocktail_id);
+                    language.text("collection-cocktail-removed")
+                } else {
+                    collection.add_cocktail(&command.cocktail_id);
+                    language.text("collection-cocktail-added")
+                };
+                self.collection_repo.update(&collection).await?;
+                self.bot_provider
+                    .bot
+                    .answer_callback_query(&command.callback.id)
+                    .text(answer_text)
+                    .await?;
+                self.handle(GetCollectionChooserCommand {
+                    user_id,
+                    chat_id: command.callback.chat_id().unwrap(),
+                    message_id: command.callback.message.unwrap().id(),
+                    prev_page: command.prev_page,
+                    cocktail_id: command.cocktail_id,
+                    language_code,
+                })
+                .await?;
+            }
+            _ => {
+                log::warn!(
+                    "Collection {} of user {} not found in store",
+                    command.collection_id,
+                    user_id.0
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct GetMyBarPageCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: Option<MessageId>,
+    pub page: u64,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<GetMyBarPageCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetMyBarPageCommand) -> Result<()> {
+        let user = self
+            .user_repo
+            .get_by_telegram_id(&command.user_id.0)
+            .await?;
+        if let Some(user) = user {
+            let page_size: u64 = 10;
+            let language = Language::choose(Some(&user), command.language_code.as_deref());
+            let text = if user.bar_ingredients.is_empty() {
+                escape(&language.text("bar-empty"))
+            } else {
+                escape(&language.text("bar"))
+            };
+            let keyboard = inline_keyboards::get_my_bar_keyboard(
+                &user.bar_ingredients,
+                &PageNumber(command.page),
+                &page_size,
+                language,
+            );
+            if let Some(message_id) = command.message_id {
+                let mut edit_message_text =
+                    self.bot_provider
+                        .bot
+                        .edit_message_text(command.chat_id, message_id, text);
+                edit_message_text = edit_message_text.reply_markup(keyboard);
+                edit_message_text.await?;
+            } else {
+                let mut send_message = self.bot_provider.bot.send_message(command.chat_id, text);
+                send_message = send_message.reply_markup(keyboard);
+                send_message.await?;
+            }
+        } else {
+            log::warn!("User with id {} not found in store", command.user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub struct GetBarIngredientSuggestionsCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: Option<MessageId>,
+    pub suggestions: Vec<String>,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetBarIngredientSuggestionsCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetBarIngredientSuggestionsCommand) -> Result<()> {
+        let user = self
+            .user_repo
+            .get_by_telegram_id(&command.user_id.0)
+            .await?;
+        let language = Language::choose(user.as_ref(), command.language_code.as_deref());
+        let bar_ingredients = user.map(|user| user.bar_ingredients).unwrap_or_default();
+        let text = if command.suggestions.is_empty() {
+            escape(&language.text("bar-suggestions-empty"))
+        } else {
+            escape(&language.text("bar-suggestions"))
+        };
+        let keyboard = inline_keyboards::get_bar_ingredient_suggestions_keyboard(
+            &command.suggestions,
+            &bar_ingredients,
+            language,
+        );
+        if let Some(message_id) = command.message_id {
+            let mut edit_message_text =
+                self.bot_provider
+                    .bot
+                    .edit_message_text(command.chat_id, message_id, text);
+            edit_message_text = edit_message_text.reply_markup(keyboard);
+            edit_message_text.await?;
+        } else {
+            let mut send_message = self.bot_provider.bot.send_message(command.chat_id, text);
+            send_message = send_message.reply_markup(keyboard);
+            send_message.await?;
+        }
+        Ok(())
+    }
+}
+
+pub struct AddBarIngredientCommand {
+    pub callback: CallbackQuery,
+    pub ingredient: String,
+    pub suggestions: Vec<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<AddBarIngredientCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: AddBarIngredientCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        if let Some(mut user) = user {
+            user.add_bar_ingredient(&command.ingredient);
+            self.user_repo.update(&user).await?;
+            let language =
+                Language::choose(Some(&user), command.callback.from.language_code.as_deref());
+            self.bot_provider
+                .bot
+                .answer_callback_query(&command.callback.id)
+                .text(language.text_with(
+                    "bar-ingredient-added",
+                    &[("ingredient", command.ingredient.as_str().into())],
+                ))
+                .await?;
+            self.handle(GetBarIngredientSuggestionsCommand {
+                user_id,
+                chat_id: command.callback.chat_id().unwrap(),
+                message_id: command
+                    .callback
+                    .message
+                    .as_ref()
+                    .map(|message| message.id()),
+                suggestions: command.suggestions,
+                language_code: command.callback.from.language_code.clone(),
+            })
+            .await?;
+        } else {
+            log::warn!("User with id {} not found in store", user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub struct RemoveBarIngredientCommand {
+    pub callback: CallbackQuery,
+    pub ingredient_index: u64,
+    pub page: u64,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<RemoveBarIngredientCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: RemoveBarIngredientCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        if let Some(mut user) = user {
+            if let Some(ingredient) = user
+                .bar_ingredients
+                .get(command.ingredient_index as usize)
+                .cloned()
+            {
+                user.remove_bar_ingredient(&ingredient);
+                self.user_repo.update(&user).await?;
+            }
+            // stay on the same page unless it became empty
+            let page_size: u64 = 10;
+            let page = if command.page * page_size >= user.bar_ingredients.len() as u64 {
+                command.page.saturating_sub(1)
+            } else {
+                command.page
+            };
+            self.handle(GetMyBarPageCommand {
+                user_id,
+                chat_id: command.callback.chat_id().unwrap(),
+                message_id: command
+                    .callback
+                    .message
+                    .as_ref()
+                    .map(|message| message.id()),
+                page,
+                language_code: command.callback.from.language_code.clone(),
+            })
+            .await?;
+        } else {
+            log::warn!("User with id {} not found in store", user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub struct GetWhatCanIMakeCommand {
+    pub user_id: UserId,
+    pub chat_id: ChatId,
+    pub message_id: MessageId,
+    pub page: u64,
+    pub language_code: Option<String>,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<GetWhatCanIMakeCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetWhatCanIMakeCommand) -> Result<()> {
+        let user = self
+            .user_repo
+            .get_by_telegram_id(&command.user_id.0)
+            .await?;
+        if let Some(user) = user {
+            let page_size: u64 = 10;
+            let cocktails = self
+                .cocktail_repo
+                .get_by_available_ingredients(
+                    &user.bar_ingredients,
+                    &Pagination {
+                        page: command.page,
+                        items_per_page: page_size,
+                    },
+                )
+                .await?;
+            let language = Language::choose(Some(&user), command.language_code.as_deref());
+            let keyboard = inline_keyboards::get_what_can_i_make_keyboard(
+                &cocktails,
+                &PageNumber(command.page),
+                &page_size,
+                language,
+            );
+            let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+                command.chat_id,
+                command.message_id,
+                escape(&language.text("what-can-i-make")),
+            );
+            edit_message_text = edit_message_text.reply_markup(keyboard);
+            edit_message_text.await?;
+        } else {
+            log::warn!("User with id {} not found in store", command.user_id.0);
+        }
+        Ok(())
+    }
+}
+
+/// Cocktails the shopping list is built for.
+pub enum ShoppingListSource {
+    Favorites,
+    Tag(String),
+    Collection(Uuid),
+}
+
+pub struct SendShoppingListCommand {
+    pub callback: CallbackQuery,
+    pub source: ShoppingListSource,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo> CommandHandler<SendShoppingListCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: SendShoppingListCommand) -> Result<()> {
+        self.bot_provider
+            .bot
+            .answer_callback_query(&command.callback.id)
+            .await?;
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        let language = Language::choose(
+            user.as_ref(),
+            command.callback.from.language_code.as_deref(),
+        );
+        let cocktail_ids = match command.source {
+            ShoppingListSource::Favorites => match &user {
+                Some(user) => user
+                    .favorite_cocktails
+                    .iter()
+                    .map(|favorite| favorite.cocktail_id)
+                    .collect(),
+                None => {
+                    log::warn!("User with id {} not found in store", user_id.0);
+                    return Ok(());
+                }
+            },
+            ShoppingListSource::Tag(tag_name) => {
+                shopping_list::get_cocktail_ids_by_tag(&self.cocktail_repo, &tag_name).await?
+            }
+            ShoppingListSource::Collection(collection_id) => {
+                match self.collection_repo.get_by_id(&collection_id).await? {
+                    Some(collection) => collection.cocktail_ids,
+                    None => {
+                        log::warn!("Collection with id {} not found in store", collection_id);
+                        return Ok(());
+                    }
+                }
+            }
+        };
+        let servings: Vec<CocktailServings> = cocktail_ids
+            .iter()
+            .map(|cocktail_id| CocktailServings {
+                cocktail_id: *cocktail_id,
+                servings: 1,
+            })
+            .collect();
+        // everything user keeps in the bar is treated as already bought
+        let owned: Vec<OwnedIngredient> = user
+            .map(|user| user.bar_ingredients)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|name| OwnedIngredient {
+                name,
+                amount: None,
+                unit: None,
+            })
+            .collect();
+        let list =
+            shopping_list::build_shopping_list(&self.cocktail_repo, &servings, &owned).await?;
+
+        let mut text = format!(
+            "*{}*",
+            escape(&language.text_with("shopping-list", &[("count", servings.len().into())]))
+        );
+        if list.items.is_empty() {
+            text.push_str(&format!(
+                "\n\n{}",
+                escape(&language.text("shopping-list-nothing"))
+            ));
+        }
+        for item in list.items.iter() {
+            let amount = if item.amount.fract() == 0.0 {
+                format!("{}", item.amount)
+            } else {
+                format!("{:.1}", item.amount)
+            };
+            text.push_str(&escape(
+                format!("\n• {} — {} {}", item.name, amount, item.unit).as_str(),
+            ));
+        }
+        if !list.owned.is_empty() {
+            text.push_str(&format!(
+                "\n\n*{}* {}",
+                escape(&language.text("shopping-list-owned")),
+                escape(list.owned.join(", ").as_str())
+            ));
+        }
+        self.bot_provider
+            .bot
+            .send_message(command.callback.chat_id().unwrap(), text)
+            .await?;
+        Ok(())
+    }
+}
+
+pub struct GetDailySubscriptionPageCommand {
+    pub callback: CallbackQuery,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetDailySubscriptionPageCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetDailySubscriptionPageCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        if let Some(user) = user {
+            let language =
+                Language::choose(Some(&user), command.callback.from.language_code.as_deref());
+            let text = match &user.daily_subscription {
+                Some(subscription) => format!(
+                    "*{}*\n\n{}",
+                    escape(&language.text("daily")),
+                    escape(&language.text_with(
+                        "daily-subscribed",
+                        &[
+                            (
+                                "time",
+                                subscription.send_at.format("%H:%M").to_string().into()
+                            ),
+                            ("timezone", subscription.timezone.as_str().into()),
+                        ],
+                    ))
+                ),
+                None => format!(
+                    "*{}*\n\n{}",
+                    escape(&language.text("daily")),
+                    escape(&language.text("daily-not-subscribed"))
+                ),
+            };
+            let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+                command.callback.chat_id().unwrap(),
+                command.callback.message.as_ref().unwrap().id(),
+                text,
+            );
+            edit_message_text =
+                edit_message_text.reply_markup(inline_keyboards::get_daily_subscription_keyboard(
+                    &user.daily_subscription,
+                    language,
+                ));
+            edit_message_text.await?;
+        } else {
+            log::warn!("User with id {} not found in store", user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub enum DailySubscriptionChange {
+    Subscribe,
+    Unsubscribe,
+    SetTime(u32),
+    SetTimezone(u64),
+    ToggleNonAlcoholic,
+    SetMaxIngredients(u32),
+}
+
+pub struct ChangeDailySubscriptionCommand {
+    pub callback: CallbackQuery,
+    pub change: DailySubscriptionChange,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<ChangeDailySubscriptionCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: ChangeDailySubscriptionCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        if let Some(mut user) = user {
+            let default_send_at = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+            match command.change {
+                DailySubscriptionChange::Subscribe => {
+                    if user.daily_subscription.is_none() {
+                        user.daily_subscription = Some(DailySubscription::new(
+                            DailySubscription::DEFAULT_TIMEZONE,
+                            default_send_at,
+                        ));
+                    }
+                }
+                DailySubscriptionChange::Unsubscribe => {
+                    user.daily_subscription = None;
+                }
+                DailySubscriptionChange::SetTime(hour) => {
+                    if let (Some(subscription), Some(send_at)) = (
+                        user.daily_subscription.as_mut(),
+                        NaiveTime::from_hms_opt(hour, 0, 0),
+                    ) {
+                        subscription.send_at = send_at;
+                    }
+                }
+                DailySubscriptionChange::SetTimezone(timezone_index) => {
+                    if let (Some(subscription), Some((_, timezone))) = (
+                        user.daily_subscription.as_mut(),
+                        inline_keyboards::DAILY_SUBSCRIPTION_TIMEZONES.get(timezone_index as usize),
+                    ) {
+                        subscription.timezone = timezone.to_string();
+                    }
+                }
+                DailySubscriptionChange::ToggleNonAlcoholic => {
+                    if let Some(subscription) = user.daily_subscription.as_mut() {
+                        subscription.filter.non_alcoholic = !subscription.filter.non_alcoholic;
+                    }
+                }
+                DailySubscriptionChange::SetMaxIngredients(max_ingredients) => {
+                    if let Some(subscription) = user.daily_subscription.as_mut() {
+                        subscription.filter.max_ingredients =
+                            Some(max_ingredients).filter(|max_ingredients| *max_ingredients > 0);
+                    }
+                }
+            }
+            self.user_repo.update(&user).await?;
+            self.handle(GetDailySubscriptionPageCommand {
+                callback: command.callback,
+            })
+            .await?;
+        } else {
+            log::warn!("User with id {} not found in store", user_id.0);
+        }
+        Ok(())
+    }
+}
+
+pub enum DailySubscriptionChooser {
+    Time,
+    Timezone,
+}
+
+pub struct GetDailySubscriptionChooserCommand {
+    pub callback: CallbackQuery,
+    pub chooser: DailySubscriptionChooser,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetDailySubscriptionChooserCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetDailySubscriptionChooserCommand) -> Result<()> {
+        let language = self
+            .get_language(
+                &command.callback.from.id,
+                command.callback.from.language_code.as_deref(),
+            )
+            .await?;
+        let (text, keyboard) = match command.chooser {
+            DailySubscriptionChooser::Time => (
+                language.text("daily-time-chooser"),
+                inline_keyboards::get_daily_time_keyboard(language),
+            ),
+            DailySubscriptionChooser::Timezone => (
+                language.text("daily-timezone-chooser"),
+                inline_keyboards::get_daily_timezone_keyboard(language),
+            ),
+        };
+        let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+            command.callback.chat_id().unwrap(),
+            command.callback.message.as_ref().unwrap().id(),
+            escape(&text),
+        );
+        edit_message_text = edit_message_text.reply_markup(keyboard);
+        edit_message_text.await?;
+        Ok(())
+    }
+}
+
+pub struct GetSimilarCocktailsCommand {
+    pub callback: CallbackQuery,
+    pub prev_page: MenuCommands,
+    pub cocktail_id: uuid::Uuid,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetSimilarCocktailsCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetSimilarCocktailsCommand) -> Result<()> {
+        let similar =
+            similarity::get_similar_cocktails(&self.cocktail_repo, &command.cocktail_id, 8).await?;
+        match similar {
+            Some(similar) => {
+                let from = &command.callback.from;
+                let user = self.user_repo.get_by_telegram_id(&from.id.0).await?;
+                let language = Language::choose(user.as_ref(), from.language_code.as_deref());
+                let locales = i18n::content_locales(user.as_ref(), from.language_code.as_deref());
+                let text = if similar.is_empty() {
+                    language.text("similar-empty")
+                } else {
+                    language.text("similar")
+                };
+                let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+                    command.callback.chat_id().unwrap(),
+                    command.callback.message.as_ref().unwrap().id(),
+                    escape(&text),
+                );
+                edit_message_text = edit_message_text.reply_markup(
+                    inline_keyboards::get_similar_cocktails_keyboard(
+                        &similar,
+                        &command.cocktail_id,
+                        &command.prev_page,
+                        language,
+                        &locales,
+                    ),
+                );
+                edit_message_text.await?;
+            }
+            None => {
+                log::warn!(
+                    "Cocktail with id {} not found in store",
+                    command.cocktail_id
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct GetRecommendationsCommand {
+    pub callback: CallbackQuery,
+}
+#[async_trait]
+impl<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+    CommandHandler<GetRecommendationsCommand>
+    for MessageProcessor<TUserRepo, TCocktailRepo, TRatingRepo, TCollectionRepo>
+where
+    TUserRepo: UserRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+{
+    async fn handle(&self, command: GetRecommendationsCommand) -> Result<()> {
+        let user_id = command.callback.from.id;
+        let user = self.user_repo.get_by_telegram_id(&user_id.0).await?;
+        if let Some(user) = user {
+            let recommendations = recommendations::get_recommendations(
+                &self.cocktail_repo,
+                &self.rating_repo,
+                &user,
+                8,
+            )
+            .await?;
+            let language =
+                Language::choose(Some(&user), command.callback.from.language_code.as_deref());
+            let mut text = format!("*{}*\n", escape(&language.text("recommendations")));
+            if recommendations.is_empty() {
+                text.push_str(&format!(
+                    "\n{}",
+                    escape(&language.text("recommendations-empty"))
+                ));
+            }
+            for recommendation in recommendations.iter() {
+                text.push_str(&format!(
+                    "\n🍸 *{}*\n_{}_\n",
+                    escape(&recommendation.russian_name),
+                    escape(&recommendation_explanation(recommendation, language))
+                ));
+            }
+            let mut edit_message_text = self.bot_provider.bot.edit_message_text(
+                command.callback.chat_id().unwrap(),
+                command.callback.message.as_ref().unwrap().id(),
+                text,
+            );
+            edit_message_text = edit_message_text.reply_markup(
+                inline_keyboards::get_recommendations_keyboard(&recommendations, language),
+            );
+            edit_message_text.await?;
+        } else {
+            log::warn!("User with id {} not found in store", user_id.0);
+        }
+        Ok(())
+    }
+}
+
+/// Photo for cocktail card, `None` if the file can not be read, then the card is sent as text.
+async fn get_card_photo(image: &CocktailImage) -> Option<InputFile> {
+    let variant = image
+        .variant(ImageSize::Large)
+        .or_else(|| image.variant(ImageSize::Original))?;
+    let blob_store = match infrastructure::RepositoryFactory::global().get_blob_store() {
+        Ok(blob_store) => blob_store,
+        Err(err) => {
+            log::warn!("Failed to create blob store: {:?}", err);
+            return None;
+        }
+    };
+    match blob_store.get(&variant.key).await {
+        Ok(Some(blob)) => Some(InputFile::memory(blob.data).file_name("cocktail.jpg")),
+        Ok(None) => {
+            log::warn!("Image file {} not found", variant.key);
+            None
+        }
+        Err(err) => {
+            log::warn!("Failed to read image file {}: {:?}", variant.key, err);
+            None
+        }
+    }
+}
+
+pub fn is_photo_message(callback: &CallbackQuery) -> bool {
+    callback
+        .regular_message()
+        .is_some_and(|message| message.photo().is_some())
+}
+
+fn recommendation_explanation(recommendation: &Recommendation, language: Language) -> String {
+    let id = match recommendation.reason {
+        RecommendationReason::Favorite => "recommendation-favorite",
+        RecommendationReason::Rated => "recommendation-rated",
+        RecommendationReason::Viewed => "recommendation-viewed",
+    };
+    language.text_with(
+        id,
+        &[("name", recommendation.because_of_name.as_str().into())],
+    )
+}
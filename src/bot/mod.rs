@@ -1,6 +1,7 @@
 // public modules
 pub mod commands;
 pub mod configurations;
+pub mod scheduler;
 
 // private modules
 mod callback_handlers;
@@ -8,13 +9,24 @@ mod dialogue;
 mod inline_keyboards;
 mod message_processor;
 
-use callback_handlers::{default_callback_handler, receive_cocktail_name_callback_handler};
-use commands::MainCommands;
+use callback_handlers::{
+    default_callback_handler, receive_bar_ingredient_callback_handler,
+    receive_cocktail_name_callback_handler, receive_collection_callback_handler,
+    receive_collection_choice_callback_handler, receive_favorite_note_callback_handler,
+    receive_tag_callback_handler,
+};
+use commands::{MainCommands, MenuCommands};
 use dialogue::State;
 use message_processor::{
+    AnswerInlineQueryCommand, CreateCollectionCommand, GetBarIngredientSuggestionsCommand,
+    GetCocktailPageBySlugCommand, GetCocktailsFilterByCollectionListCommand,
     GetCocktailsFilterByNameListCommand, GetMainMenuCommand, MessageProcessor,
+    SetFavoriteNoteCommand,
+};
+use std::{
+    error::Error,
+    sync::{Arc, OnceLock},
 };
-use std::{error::Error, sync::OnceLock};
 use teloxide::{
     adaptors::DefaultParseMode,
     dispatching::{
@@ -23,28 +35,45 @@ use teloxide::{
     },
     dptree::{self, case},
     prelude::{Dialogue, Dispatcher, LoggingErrorHandler, Requester, RequesterExt},
-    types::{Message, ParseMode, Update},
+    types::{CallbackQuery, InlineQuery, MaybeInaccessibleMessage, Message, ParseMode, Update},
     utils::markdown::escape,
     Bot as TBot,
 };
+use tokio::sync::OnceCell;
 
-use crate::{bot::configurations::BotConfig, shared::CommandHandler};
+use crate::{
+    bot::configurations::BotConfig,
+    domain::{
+        aggregates::{
+            collection::MAX_COLLECTION_NAME_LENGTH,
+            user::{UserRepo, MAX_FAVORITE_NOTE_LENGTH},
+        },
+        services::bar,
+    },
+    infrastructure,
+    shared::{i18n::Language, CommandHandler},
+};
 
 type BotDialogue = Dialogue<State, InMemStorage<State>>;
 type HandlerResult = Result<(), Box<dyn Error + Send + Sync>>;
 
+const SHARED_COLLECTION_PAYLOAD_PREFIX: &str = "col_";
+const COCKTAIL_PAYLOAD_PREFIX: &str = "c_";
+
 pub type Bot = DefaultParseMode<TBot>;
 pub static INSTANCE: OnceLock<TgBotProvider> = OnceLock::new();
 
 #[derive(Debug, Clone)]
 pub struct TgBotProvider {
     bot: Bot,
+    username: Arc<OnceCell<String>>,
 }
 
 impl TgBotProvider {
     pub fn new(config: &BotConfig) -> Self {
         TgBotProvider {
             bot: TBot::new(&config.bot_token).parse_mode(ParseMode::MarkdownV2),
+            username: Arc::new(OnceCell::new()),
         }
     }
 
@@ -53,6 +82,18 @@ impl TgBotProvider {
             .get()
             .expect("Can't get TgBotProvider from global instance")
     }
+
+    /// Username of the bot for `t.me` links. Requested once, it doesn't change while running.
+    pub async fn username(&self) -> Result<&str, teloxide::RequestError> {
+        let username = self
+            .username
+            .get_or_try_init(|| async {
+                let me = self.bot.get_me().await?;
+                Ok::<_, teloxide::RequestError>(me.username().to_string())
+            })
+            .await?;
+        Ok(username)
+    }
 }
 
 impl TgBotProvider {
@@ -74,30 +115,122 @@ impl TgBotProvider {
 
     fn schema(&self) -> UpdateHandler<Box<dyn Error + Send + Sync + 'static>> {
         let main_commands_handler = teloxide::filter_command::<MainCommands, _>()
-            .branch(case![MainCommands::Menu].endpoint(main_commands_menu_handler));
+            .branch(case![MainCommands::Menu].endpoint(main_commands_menu_handler))
+            .branch(case![MainCommands::Start(payload)].endpoint(main_commands_start_handler));
 
-        let text_handler = Message::filter_text().branch(
-            case![State::ReceiveCocktailName]
-                .endpoint(filter_cocktails_by_name_dialogue_receive_cocktail_name),
-        );
+        let text_handler = Message::filter_text()
+            .branch(
+                case![State::ReceiveCocktailName]
+                    .endpoint(filter_cocktails_by_name_dialogue_receive_cocktail_name),
+            )
+            .branch(
+                case![State::ReceiveFavoriteNote {
+                    cocktail_id,
+                    prev_page,
+                    previous_state
+                }]
+                .endpoint(favorite_note_dialogue_receive_note),
+            )
+            .branch(
+                case![State::ReceiveCollectionName]
+                    .endpoint(create_collection_dialogue_receive_name),
+            )
+            .branch(
+                case![State::ReceiveBarIngredient { suggestions }]
+                    .endpoint(bar_ingredient_dialogue_receive_name),
+            );
         let message_handler = Update::filter_message()
             .branch(main_commands_handler)
             .branch(text_handler);
 
         let callback_query_handler = Update::filter_callback_query()
+            .map_async(leave_photo_card)
             .branch(case![State::Start].endpoint(default_callback_handler))
             .branch(case![State::ReceiveCocktailName].endpoint(default_callback_handler))
             .branch(
                 case![State::ReveivedCocktailName { cocktail_name }]
                     .endpoint(receive_cocktail_name_callback_handler),
+            )
+            .branch(case![State::ReceivedTag { tag_name }].endpoint(receive_tag_callback_handler))
+            .branch(
+                case![State::ReceiveFavoriteNote {
+                    cocktail_id,
+                    prev_page,
+                    previous_state
+                }]
+                .endpoint(receive_favorite_note_callback_handler),
+            )
+            .branch(case![State::ReceiveCollectionName].endpoint(default_callback_handler))
+            .branch(
+                case![State::ReceiveBarIngredient { suggestions }]
+                    .endpoint(receive_bar_ingredient_callback_handler),
+            )
+            .branch(
+                case![State::ReceivedCollection { collection_id }]
+                    .endpoint(receive_collection_callback_handler),
+            )
+            .branch(
+                case![State::ChooseCollection {
+                    cocktail_id,
+                    prev_page,
+                    previous_state
+                }]
+                .endpoint(receive_collection_choice_callback_handler),
             );
 
-        teloxide::dispatching::dialogue::enter::<Update, InMemStorage<State>, State, _>()
-            .branch(message_handler)
-            .branch(callback_query_handler)
+        // inline queries come from any chat, so they have no dialogue
+        let inline_query_handler = Update::filter_inline_query().endpoint(inline_query_handler);
+
+        dptree::entry().branch(inline_query_handler).branch(
+            teloxide::dispatching::dialogue::enter::<Update, InMemStorage<State>, State, _>()
+                .branch(message_handler)
+                .branch(callback_query_handler),
+        )
     }
 }
 
+async fn inline_query_handler(query: InlineQuery) -> HandlerResult {
+    let processor = MessageProcessor::new().await?;
+    processor.handle(AnswerInlineQueryCommand { query }).await?;
+    Ok(())
+}
+
+/// Photo card can't be edited into a text message, so for buttons leading away from the card
+/// the photo is replaced with a text message, which handlers edit as usual. Buttons changing
+/// the card itself keep the photo.
+async fn leave_photo_card(bot: Bot, mut callback: CallbackQuery) -> CallbackQuery {
+    let Some(message) = callback
+        .regular_message()
+        .filter(|message| message.photo().is_some())
+    else {
+        return callback;
+    };
+    let keeps_card = callback.data.as_deref().is_some_and(|data| {
+        matches!(
+            MenuCommands::parse(data),
+            MenuCommands::AddToFavorite(..)
+                | MenuCommands::RemoveFromFavorite(..)
+                | MenuCommands::RateCocktail(..)
+                | MenuCommands::ToggleHistory(..)
+        )
+    });
+    if keeps_card {
+        return callback;
+    }
+
+    let (chat_id, message_id) = (message.chat.id, message.id);
+    if let Err(err) = bot.delete_message(chat_id, message_id).await {
+        log::warn!("Failed to delete photo card: {:?}", err);
+    }
+    match bot.send_message(chat_id, "⏳").await {
+        Ok(placeholder) => {
+            callback.message = Some(MaybeInaccessibleMessage::Regular(placeholder));
+        }
+        Err(err) => log::warn!("Failed to send message instead of photo card: {:?}", err),
+    }
+    callback
+}
+
 /// .
 ///
 /// # Errors
@@ -109,40 +242,112 @@ async fn main_commands_menu_handler(
     _dialogue: BotDialogue,
 ) -> HandlerResult {
     let processor = MessageProcessor::new().await?;
-    let user_id = msg
+    let user = msg
         .from
         .clone()
-        .expect("Can't get user info from telegram message")
-        .id;
+        .expect("Can't get user info from telegram message");
     let chat_id = msg
         .chat_id()
         .expect("Can't get chat id from telegram message");
     processor
         .handle(GetMainMenuCommand {
-            user_id,
+            user_id: user.id,
             chat_id,
             message_id: msg.id,
             edit_message: false,
+            language_code: user.language_code,
         })
         .await?;
     Ok(())
 }
 
+/// Handles `/start` with optional deep link payload.
+/// `col_<collection_id>` opens shared collection, `c_<slug>` opens cocktail card,
+/// otherwise main menu is shown.
+async fn main_commands_start_handler(
+    msg: Message,
+    _bot: Bot,
+    dialogue: BotDialogue,
+    payload: String,
+) -> HandlerResult {
+    let processor = MessageProcessor::new().await?;
+    let chat_id = msg
+        .chat_id()
+        .expect("Can't get chat id from telegram message");
+    let user = msg
+        .from
+        .clone()
+        .expect("Can't get user info from telegram message");
+    let payload = payload.trim();
+    if let Some(slug) = payload
+        .strip_prefix(COCKTAIL_PAYLOAD_PREFIX)
+        .filter(|slug| !slug.is_empty())
+    {
+        processor
+            .handle(GetCocktailPageBySlugCommand {
+                user_id: user.id,
+                chat_id,
+                slug: slug.to_string(),
+                language_code: user.language_code,
+            })
+            .await?;
+        return Ok(());
+    }
+    let shared_collection_id = payload
+        .strip_prefix(SHARED_COLLECTION_PAYLOAD_PREFIX)
+        .and_then(|collection_id| uuid::Uuid::parse_str(collection_id).ok());
+    match shared_collection_id {
+        Some(collection_id) => {
+            processor
+                .handle(GetCocktailsFilterByCollectionListCommand {
+                    user_id: user.id,
+                    chat_id,
+                    message_id: None,
+                    collection_id,
+                    next_page: 0,
+                    language_code: user.language_code,
+                })
+                .await?;
+            dialogue
+                .update(State::ReceivedCollection { collection_id })
+                .await?;
+        }
+        None => {
+            processor
+                .handle(GetMainMenuCommand {
+                    user_id: user.id,
+                    chat_id,
+                    message_id: msg.id,
+                    edit_message: false,
+                    language_code: user.language_code,
+                })
+                .await?;
+        }
+    }
+    Ok(())
+}
+
 async fn filter_cocktails_by_name_dialogue_receive_cocktail_name(
     bot: Bot,
     dialogue: BotDialogue,
     msg: Message,
 ) -> HandlerResult {
+    let user = msg
+        .from
+        .clone()
+        .expect("Can't get user info from telegram message");
     match msg.text() {
         Some(text) => {
             let message_proc = MessageProcessor::new().await?;
 
             message_proc
                 .handle(GetCocktailsFilterByNameListCommand {
+                    user_id: user.id,
                     chat_id: msg.chat_id().unwrap(),
                     message_id: None,
                     cocktail_name_for_filter: text.to_string(),
                     next_page: 0,
+                    language_code: user.language_code,
                 })
                 .await?;
             dialogue
@@ -152,12 +357,149 @@ async fn filter_cocktails_by_name_dialogue_receive_cocktail_name(
                 .await?;
         }
         None => {
+            let language = get_user_language(&user).await?;
+            bot.send_message(msg.chat.id, escape(&language.text("search-by-name-retry")))
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn favorite_note_dialogue_receive_note(
+    bot: Bot,
+    dialogue: BotDialogue,
+    msg: Message,
+    (cocktail_id, prev_page, previous_state): (uuid::Uuid, String, Box<State>),
+) -> HandlerResult {
+    let user = msg
+        .from
+        .clone()
+        .expect("Can't get user info from telegram message");
+    match msg.text() {
+        Some(text) if text.chars().count() <= MAX_FAVORITE_NOTE_LENGTH => {
+            let message_proc = MessageProcessor::new().await?;
+
+            message_proc
+                .handle(SetFavoriteNoteCommand {
+                    user_id: user.id,
+                    chat_id: msg.chat_id().unwrap(),
+                    message_id: None,
+                    prev_page: MenuCommands::parse(&prev_page),
+                    cocktail_id,
+                    note: Some(text.to_string()),
+                    language_code: user.language_code,
+                })
+                .await?;
+            dialogue.update(*previous_state).await?;
+        }
+        _ => {
+            let language = get_user_language(&user).await?;
             bot.send_message(
                 msg.chat.id,
-                escape("Отправь мне название коктейля или его часть."),
+                escape(&language.text_with(
+                    "favorite-note-retry",
+                    &[("max_length", MAX_FAVORITE_NOTE_LENGTH.into())],
+                )),
             )
             .await?;
         }
     }
     Ok(())
 }
+
+async fn create_collection_dialogue_receive_name(
+    bot: Bot,
+    dialogue: BotDialogue,
+    msg: Message,
+) -> HandlerResult {
+    let user = msg
+        .from
+        .clone()
+        .expect("Can't get user info from telegram message");
+    match msg.text().map(|text| text.trim()) {
+        Some(text) if !text.is_empty() && text.chars().count() <= MAX_COLLECTION_NAME_LENGTH => {
+            let message_proc = MessageProcessor::new().await?;
+
+            message_proc
+                .handle(CreateCollectionCommand {
+                    user_id: user.id,
+                    chat_id: msg.chat_id().unwrap(),
+                    name: text.to_string(),
+                    language_code: user.language_code,
+                })
+                .await?;
+            dialogue.exit().await?;
+        }
+        _ => {
+            let language = get_user_language(&user).await?;
+            bot.send_message(
+                msg.chat.id,
+                escape(&language.text_with(
+                    "collection-name-retry",
+                    &[("max_length", MAX_COLLECTION_NAME_LENGTH.into())],
+                )),
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn bar_ingredient_dialogue_receive_name(
+    bot: Bot,
+    dialogue: BotDialogue,
+    msg: Message,
+    _suggestions: Vec<String>,
+) -> HandlerResult {
+    let telegram_user = msg
+        .from
+        .clone()
+        .expect("Can't get user info from telegram message");
+    match msg.text() {
+        Some(text) => {
+            let user_id = telegram_user.id;
+            let repository_factory = infrastructure::RepositoryFactory::global();
+            let user = repository_factory
+                .get_user_repository()
+                .await?
+                .get_by_telegram_id(&user_id.0)
+                .await?;
+            let Some(user) = user else {
+                log::warn!("User with id {} not found in store", user_id.0);
+                dialogue.exit().await?;
+                return Ok(());
+            };
+            let cocktail_repository = repository_factory.get_cocktails_repository().await?;
+            let suggestions =
+                bar::suggest_ingredients(&cocktail_repository, &user, text, 10).await?;
+
+            let message_proc = MessageProcessor::new().await?;
+            message_proc
+                .handle(GetBarIngredientSuggestionsCommand {
+                    user_id,
+                    chat_id: msg.chat_id().unwrap(),
+                    message_id: None,
+                    suggestions: suggestions.clone(),
+                    language_code: telegram_user.language_code,
+                })
+                .await?;
+            dialogue
+                .update(State::ReceiveBarIngredient { suggestions })
+                .await?;
+        }
+        None => {
+            let language = get_user_language(&telegram_user).await?;
+            bot.send_message(msg.chat.id, escape(&language.text("bar-ingredient-retry")))
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Language of the bot interface for the author of the message.
+async fn get_user_language(user: &teloxide::types::User) -> Result<Language, anyhow::Error> {
+    MessageProcessor::new()
+        .await?
+        .get_language(&user.id, user.language_code.as_deref())
+        .await
+}
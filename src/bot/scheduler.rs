@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use teloxide::{types::ChatId, types::UserId, ApiError, RequestError};
+
+use crate::{
+    domain::aggregates::{
+        cocktail::CocktailRepo,
+        user::{User, UserRepo},
+    },
+    infrastructure,
+    shared::CommandHandler,
+};
+
+use super::{
+    commands::MenuCommands,
+    message_processor::{GetCocktailPageByIdCommand, MessageProcessor},
+};
+
+/// How often subscriptions are checked for due deliveries.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// Pause between messages to stay under Telegram limit of ~30 messages per second.
+const SEND_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Sends daily cocktails to subscribed users. Runs until the application stops.
+pub async fn start_daily_subscription_scheduler() {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        if let Err(err) = send_due_daily_cocktails().await {
+            log::error!("Failed to send daily cocktails: {:?}", err);
+        }
+    }
+}
+
+async fn send_due_daily_cocktails() -> Result<()> {
+    let repository_factory = infrastructure::RepositoryFactory::global().clone();
+    let user_repository = repository_factory
+        .get_user_repository()
+        .await
+        .context("failed to create user repo in scheduler")?;
+    let cocktail_repository = repository_factory
+        .get_cocktails_repository()
+        .await
+        .context("failed to create cocktail repo in scheduler")?;
+
+    let now = chrono::Utc::now();
+    for user in user_repository.get_with_daily_subscription().await? {
+        let Some(subscription) = &user.daily_subscription else {
+            continue;
+        };
+        let Some(delivery_date) = subscription.get_due_delivery_date(&now) else {
+            continue;
+        };
+        if user.daily_last_sent_on == Some(delivery_date) {
+            continue;
+        }
+        // claim before sending: after restart or in parallel instance the same day is skipped
+        match user_repository
+            .claim_daily_delivery(&user.id, &delivery_date)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(err) => {
+                log::error!(
+                    "Failed to claim daily cocktail of user {}: {:?}",
+                    user.telegram_id,
+                    err
+                );
+                continue;
+            }
+        }
+        let sent = match cocktail_repository.get_random(&subscription.filter).await {
+            Ok(Some(cocktail)) => send_daily_cocktail(&user, &cocktail.id).await,
+            Ok(None) => {
+                log::warn!(
+                    "No cocktails match daily subscription filter of user {}",
+                    user.telegram_id
+                );
+                true
+            }
+            Err(err) => {
+                log::error!(
+                    "Failed to get daily cocktail for user {}: {:?}",
+                    user.telegram_id,
+                    err
+                );
+                false
+            }
+        };
+        if !sent {
+            if let Err(err) = user_repository
+                .release_daily_delivery(&user.id, &delivery_date, &user.daily_last_sent_on)
+                .await
+            {
+                log::error!(
+                    "Failed to release daily cocktail of user {}: {:?}",
+                    user.telegram_id,
+                    err
+                );
+            }
+        }
+        tokio::time::sleep(SEND_INTERVAL).await;
+    }
+
+    Ok(())
+}
+
+/// Returns `false` if the cocktail was not sent and the delivery should be retried.
+async fn send_daily_cocktail(user: &User, cocktail_id: &uuid::Uuid) -> bool {
+    let command = || GetCocktailPageByIdCommand {
+        user_id: UserId(user.telegram_id),
+        chat_id: ChatId(user.telegram_id as i64),
+        message_id: None,
+        photo_message: false,
+        show_history: false,
+        prev_page: MenuCommands::RandomCocktail,
+        cocktail_id: *cocktail_id,
+        // there is no update from the user to take the language from
+        language_code: None,
+    };
+    let message_proc = match MessageProcessor::new().await {
+        Ok(message_proc) => message_proc,
+        Err(err) => {
+            log::error!("Failed to create message processor: {:?}", err);
+            return false;
+        }
+    };
+    let mut result = message_proc.handle(command()).await;
+    if let Some(RequestError::RetryAfter(retry_after)) = result
+        .as_ref()
+        .err()
+        .and_then(|err| err.downcast_ref::<RequestError>())
+    {
+        tokio::time::sleep(retry_after.duration()).await;
+        result = message_proc.handle(command()).await;
+    }
+    match result {
+        Ok(()) => true,
+        Err(err) => match err.downcast_ref::<RequestError>() {
+            // retrying makes no sense until the user unblocks the bot
+            Some(RequestError::Api(ApiError::BotBlocked)) => {
+                log::info!(
+                    "User {} blocked the bot, daily cocktail skipped",
+                    user.telegram_id
+                );
+                true
+            }
+            _ => {
+                log::error!(
+                    "Failed to send daily cocktail to user {}: {:?}",
+                    user.telegram_id,
+                    err
+                );
+                false
+            }
+        },
+    }
+}
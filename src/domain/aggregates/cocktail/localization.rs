@@ -0,0 +1,58 @@
+use super::{Cocktail, CocktailItem, Recipe};
+use crate::domain::locale::{LocaleChain, LocalizedText, DEFAULT_LOCALE, ENGLISH_LOCALE};
+
+/// Cocktail name in the first locale of the chain having one. `name` is the english name.
+pub(crate) fn localized_cocktail_name<'a>(
+    chain: &LocaleChain,
+    russian_name: &'a str,
+    name: Option<&'a str>,
+    name_translations: &'a LocalizedText,
+) -> &'a str {
+    chain
+        .locales()
+        .find_map(|locale| match locale {
+            DEFAULT_LOCALE => Some(russian_name),
+            ENGLISH_LOCALE => name.filter(|name| !name.trim().is_empty()),
+            _ => name_translations.get(locale),
+        })
+        .unwrap_or(russian_name)
+}
+
+impl Cocktail {
+    /// Name in the first locale of the chain having one.
+    pub fn localized_name(&self, chain: &LocaleChain) -> &str {
+        localized_cocktail_name(
+            chain,
+            &self.russian_name,
+            self.name.as_deref(),
+            &self.name_translations,
+        )
+    }
+
+    pub fn localized_history(&self, chain: &LocaleChain) -> Option<&str> {
+        self.history_translations
+            .resolve(chain, self.history.as_deref())
+    }
+}
+
+impl CocktailItem {
+    pub fn localized_name(&self, chain: &LocaleChain) -> &str {
+        self.display_names
+            .resolve(chain, Some(&self.name))
+            .unwrap_or(&self.name)
+    }
+}
+
+impl Recipe {
+    /// Every step in the first locale of the chain having its translation.
+    pub fn localized_steps(&self, chain: &LocaleChain) -> Vec<&str> {
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| match self.step_translations.get(index) {
+                Some(translations) => translations.resolve(chain, Some(step)).unwrap_or(step),
+                None => step,
+            })
+            .collect()
+    }
+}
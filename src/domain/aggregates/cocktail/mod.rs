@@ -3,21 +3,53 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::rating::RatingSummary;
+use crate::domain::locale::LocalizedText;
+
+pub mod localization;
+pub mod spirits;
+pub mod validation;
+
+pub use validation::FieldError;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct Cocktail {
     pub(crate) id: Uuid,
     pub(crate) url: Option<String>,
     pub(crate) name: Option<String>,
     pub(crate) russian_name: String,
+    /// Names in locales other than `ru` and `en`, which are `russian_name` and `name`.
+    pub(crate) name_translations: LocalizedText,
     pub(crate) country_of_origin: Option<String>,
     pub(crate) history: Option<String>,
+    /// Translations of `history`, which is in `DEFAULT_LOCALE`.
+    pub(crate) history_translations: LocalizedText,
     pub(crate) tags: Option<Vec<Tag>>,
-    pub(crate) tools: Option<Vec<CocktailItem>>,
+    pub(crate) glass: Option<String>,
+    pub(crate) method: Option<PreparationMethod>,
+    pub(crate) tools: Option<Vec<CocktailTool>>,
     pub(crate) composition_elements: Option<Vec<CocktailItem>>,
     pub(crate) recipe: Option<Recipe>,
+    pub(crate) rating: Option<RatingSummary>,
+    /// Time of the last change, `None` for cocktails stored before changes were tracked.
+    pub(crate) updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Incremented on every change of the content, used to detect concurrent edits.
+    /// Cocktails stored before versions were tracked have version 0.
+    pub(crate) version: u64,
+    /// Time of moving to trash, deleted cocktails are hidden from all queries except trash listing.
+    pub(crate) deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// URL-friendly name, unique among all cocktails including trash.
+    /// `None` for cocktails stored before slugs were introduced until they are backfilled.
+    pub(crate) slug: Option<String>,
+    /// Slugs the cocktail had before renames, lookups by them lead to the current slug.
+    pub(crate) previous_slugs: Vec<String>,
+    /// Photo of the cocktail. It is not a part of the content, so changing it does not
+    /// change the version and is not recorded in revision history.
+    pub(crate) image: Option<CocktailImage>,
 }
 
 impl Cocktail {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: Option<String>,
         russian_name: String,
@@ -25,7 +57,9 @@ impl Cocktail {
         country_of_origin: Option<String>,
         history: Option<String>,
         tags: Option<Vec<Tag>>,
-        tools: Option<Vec<CocktailItem>>,
+        glass: Option<String>,
+        method: Option<PreparationMethod>,
+        tools: Option<Vec<CocktailTool>>,
         composition_elements: Option<Vec<CocktailItem>>,
         recipe: Option<Recipe>,
     ) -> Self {
@@ -34,47 +68,301 @@ impl Cocktail {
             url,
             name,
             russian_name,
+            name_translations: LocalizedText::default(),
             country_of_origin,
             history,
+            history_translations: LocalizedText::default(),
             tags,
+            glass,
+            method,
             tools,
             composition_elements,
             recipe,
+            rating: None,
+            updated_at: Some(chrono::Utc::now()),
+            version: 1,
+            deleted_at: None,
+            slug: None,
+            previous_slugs: vec![],
+            image: None,
         }
     }
+
+    /// Replaces source tags with the target tag, keeping the order of other tags.
+    /// Returns `false` if the cocktail has none of the source tags.
+    pub fn merge_tags(&mut self, sources: &[String], target: &str) -> bool {
+        let Some(tags) = &mut self.tags else {
+            return false;
+        };
+        let count = tags.len();
+        tags.retain(|tag| tag.name == target || !sources.contains(&tag.name));
+        if tags.len() == count {
+            return false;
+        }
+        if !tags.iter().any(|tag| tag.name == target) {
+            tags.push(Tag {
+                name: target.to_string(),
+            });
+        }
+
+        true
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(thiserror::Error, Debug)]
+pub enum CocktailError {
+    #[error("cocktail with id {id} was changed or deleted since version {version}")]
+    VersionConflict { id: Uuid, version: u64 },
+    #[error("cocktail is invalid: {0:?}")]
+    Invalid(Vec<FieldError>),
+    #[error("slug {0} is taken by another cocktail")]
+    SlugTaken(String),
+}
+
+/// Changed fields of a cocktail. `None` keeps the field as is, `Some(None)` removes it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CocktailPatch {
+    pub(crate) url: Option<Option<String>>,
+    pub(crate) name: Option<Option<String>>,
+    pub(crate) russian_name: Option<String>,
+    pub(crate) name_translations: Option<LocalizedText>,
+    pub(crate) country_of_origin: Option<Option<String>>,
+    pub(crate) history: Option<Option<String>>,
+    pub(crate) history_translations: Option<LocalizedText>,
+    pub(crate) tags: Option<Option<Vec<Tag>>>,
+    pub(crate) glass: Option<Option<String>>,
+    pub(crate) method: Option<Option<PreparationMethod>>,
+    pub(crate) tools: Option<Option<Vec<CocktailTool>>>,
+    pub(crate) composition_elements: Option<Option<Vec<CocktailItem>>>,
+    pub(crate) recipe: Option<Option<Recipe>>,
+    /// Slug is never removed, only replaced.
+    pub(crate) slug: Option<String>,
+    pub(crate) previous_slugs: Option<Vec<String>>,
+}
+
+impl CocktailPatch {
+    /// Patch turning `before` into `after`, only differing fields are included.
+    pub fn between(before: &Cocktail, after: &Cocktail) -> Self {
+        fn changed<T: PartialEq + Clone>(before: &T, after: &T) -> Option<T> {
+            (before != after).then(|| after.clone())
+        }
+        CocktailPatch {
+            url: changed(&before.url, &after.url),
+            name: changed(&before.name, &after.name),
+            russian_name: changed(&before.russian_name, &after.russian_name),
+            name_translations: changed(&before.name_translations, &after.name_translations),
+            country_of_origin: changed(&before.country_of_origin, &after.country_of_origin),
+            history: changed(&before.history, &after.history),
+            history_translations: changed(
+                &before.history_translations,
+                &after.history_translations,
+            ),
+            tags: changed(&before.tags, &after.tags),
+            glass: changed(&before.glass, &after.glass),
+            method: changed(&before.method, &after.method),
+            tools: changed(&before.tools, &after.tools),
+            composition_elements: changed(
+                &before.composition_elements,
+                &after.composition_elements,
+            ),
+            recipe: changed(&before.recipe, &after.recipe),
+            slug: changed(&before.slug, &after.slug).flatten(),
+            previous_slugs: changed(&before.previous_slugs, &after.previous_slugs),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.url.is_none()
+            && self.name.is_none()
+            && self.russian_name.is_none()
+            && self.name_translations.is_none()
+            && self.country_of_origin.is_none()
+            && self.history.is_none()
+            && self.history_translations.is_none()
+            && self.tags.is_none()
+            && self.glass.is_none()
+            && self.method.is_none()
+            && self.tools.is_none()
+            && self.composition_elements.is_none()
+            && self.recipe.is_none()
+            && self.slug.is_none()
+            && self.previous_slugs.is_none()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub(crate) struct Tag {
     pub(crate) name: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PreparationMethod {
+    Shake,
+    Stir,
+    Build,
+    Blend,
+    Throw,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct CocktailTool {
+    pub(crate) name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub(crate) struct CocktailItem {
+    /// Identifies the ingredient in bars, search and shopping lists, shown in `DEFAULT_LOCALE`.
     pub(crate) name: String,
     pub(crate) count: i32,
     pub(crate) unit: String,
+    /// Names shown in other locales.
+    pub(crate) display_names: LocalizedText,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub(crate) struct Recipe {
     pub(crate) steps: Vec<String>,
+    /// Translations of the step with the same index, steps without one are shown as is.
+    pub(crate) step_translations: Vec<LocalizedText>,
+}
+
+/// Uploaded photo of a cocktail, its files are kept in `BlobStore`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct CocktailImage {
+    pub(crate) id: Uuid,
+    pub(crate) uploaded_at: chrono::DateTime<chrono::Utc>,
+    /// Uploaded file first, then resized copies from the largest to the smallest.
+    pub(crate) variants: Vec<ImageVariant>,
+}
+
+impl CocktailImage {
+    pub fn variant(&self, size: ImageSize) -> Option<&ImageVariant> {
+        self.variants.iter().find(|variant| variant.size == size)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ImageSize {
+    Original,
+    Large,
+    Medium,
+    Thumbnail,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct ImageVariant {
+    pub(crate) size: ImageSize,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) content_type: String,
+    /// Key of the file in `BlobStore`.
+    pub(crate) key: String,
+    /// Public URL of the file.
+    pub(crate) url: String,
 }
 
 #[async_trait]
 pub trait CocktailRepo {
-    /// .
-    async fn create(&self, entity: &Cocktail);
-    /// .
-    async fn delete(&self, entity: &Cocktail);
-    /// .
-    async fn update(&self, entity: &Cocktail);
-    /// .
+    /// Stores new cocktail, fails with `CocktailError::SlugTaken` if its slug is taken.
+    async fn create(&self, entity: &Cocktail) -> Result<()>;
+    /// Moves cocktail to trash if its stored version equals `entity.version`,
+    /// fails with `CocktailError::VersionConflict` otherwise.
+    async fn delete(&self, entity: &Cocktail) -> Result<()>;
+    /// Returns cocktails in trash, the most recently deleted first.
+    async fn get_deleted(&self, pagination: &crate::domain::Pagination) -> Result<CocktailsPaged>;
+    /// Returns cocktail in trash by id.
+    async fn get_deleted_by_id(&self, id: &Uuid) -> Result<Option<Cocktail>>;
+    /// Takes cocktail out of trash if its stored version equals `entity.version` and increments
+    /// the version, fails with `CocktailError::VersionConflict` otherwise.
+    async fn restore_deleted(&self, entity: &Cocktail) -> Result<Cocktail>;
+    /// Returns id, russian name and image of cocktails deleted before the time.
+    async fn get_expired_deleted(
+        &self,
+        deleted_before: &chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Cocktail>>;
+    /// Removes the cocktails for good if they are still deleted before the time.
+    /// Returns id, russian name and image of removed cocktails.
+    async fn purge_deleted(
+        &self,
+        expired: &[Cocktail],
+        deleted_before: &chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Cocktail>>;
+    /// Replaces cocktail content if its stored version equals `entity.version` and increments
+    /// the version, fails with `CocktailError::VersionConflict` otherwise
+    /// and with `CocktailError::SlugTaken` if its slug is taken.
+    async fn update(&self, entity: &Cocktail) -> Result<()>;
+    /// Changes only the fields present in the patch, with the same version check as `update`.
+    async fn patch(&self, id: &Uuid, version: u64, patch: &CocktailPatch) -> Result<()>;
+    /// Returns id and russian name of cocktails matching the filter, like `get_by_filter`.
     async fn get_names(&self, filter: &CocktailFilter) -> Result<CocktailsPaged>;
     /// .
     async fn get_by_id(&self, id: &Uuid) -> Result<Option<Cocktail>>;
+    /// Returns cocktail by its current or previous slug.
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<Cocktail>>;
+    /// Checks whether the slug is current or previous slug of any other cocktail, trash included.
+    async fn is_slug_taken(&self, slug: &str, except_id: &Uuid) -> Result<bool>;
+    /// Returns cocktails stored before slugs were introduced, trash included.
+    async fn get_without_slug(&self) -> Result<Vec<Cocktail>>;
+    /// Stores slug of a cocktail which has none, the version is not changed.
+    async fn set_slug(&self, id: &Uuid, slug: &str) -> Result<()>;
+    /// Replaces or removes image of a cocktail if its stored version equals `entity.version`
+    /// and increments the version, fails with `CocktailError::VersionConflict` otherwise.
+    async fn set_image(&self, entity: &Cocktail, image: Option<&CocktailImage>) -> Result<()>;
+    /// Returns cocktails with the same english or russian name, case is ignored.
+    async fn get_by_exact_names(
+        &self,
+        name: Option<&str>,
+        russian_name: &str,
+    ) -> Result<Vec<Cocktail>>;
     /// .
     async fn get_by_filter(&self, filter: &CocktailFilter) -> Result<CocktailsPaged>;
+    /// Returns id, names, tags and country of all cocktails not in trash.
+    async fn get_names_for_search(&self) -> Result<Vec<Cocktail>>;
+    /// Returns whole catalogue.
+    async fn get_all(&self) -> Result<Vec<Cocktail>>;
+    /// Stores denormalised rating summary in cocktail.
+    async fn update_rating(&self, id: &Uuid, summary: &RatingSummary) -> Result<()>;
+    /// Returns all tags used by cocktails with the number of cocktails per tag, ordered by name.
+    async fn get_tags(&self) -> Result<Vec<TagWithCount>>;
+    /// Returns cocktails having any of the tags, also in trash.
+    async fn get_by_tags(&self, tags: &[String]) -> Result<Vec<Cocktail>>;
+    /// Replaces source tags with target tag in the cocktail, also in trash, if its stored version
+    /// equals `entity.version` and increments the version,
+    /// fails with `CocktailError::VersionConflict` otherwise.
+    async fn merge_tags(&self, entity: &Cocktail, sources: &[String], target: &str) -> Result<()>;
+    /// Returns names of all ingredients used by cocktails, ordered by name.
+    async fn get_ingredients(&self) -> Result<Vec<String>>;
+    /// Returns cocktails ranked by the number of ingredients missing from the given list.
+    async fn get_by_available_ingredients(
+        &self,
+        ingredients: &[String],
+        pagination: &crate::domain::Pagination,
+    ) -> Result<CocktailsByIngredientsPaged>;
+    /// Full-text search over names, history, recipe steps, ingredients and tags,
+    /// ordered by relevance. Russian texts are matched with russian stemming, english name
+    /// and translations with english stemming.
+    async fn text_search(
+        &self,
+        query: &str,
+        pagination: &crate::domain::Pagination,
+    ) -> Result<CocktailsTextSearchPaged>;
+    /// Returns numbers of cocktails per tag, country, base spirit, glass and method
+    /// among cocktails matching ids and tags of the filter.
+    async fn get_facets(&self, filter: &CocktailFilter) -> Result<CocktailFacets>;
+    /// Returns totals over the whole catalogue.
+    async fn get_stats(&self) -> Result<CatalogueStats>;
+    /// Returns random cocktail matching the constraints.
+    async fn get_random(&self, filter: &RandomCocktailFilter) -> Result<Option<Cocktail>>;
+    /// Returns number of cocktails matching the constraints.
+    async fn count_by_random_filter(&self, filter: &RandomCocktailFilter) -> Result<u64>;
+    /// Returns cocktail at the position among cocktails matching the constraints ordered by id.
+    async fn get_nth_by_random_filter(
+        &self,
+        filter: &RandomCocktailFilter,
+        index: u64,
+    ) -> Result<Option<Cocktail>>;
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -83,10 +371,128 @@ pub struct CocktailsPaged {
     pub total_count: u64,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TagWithCount {
+    pub name: String,
+    pub cocktails_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CocktailWithMissingIngredients {
+    pub id: Uuid,
+    pub russian_name: String,
+    pub missing_ingredients: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CocktailsByIngredientsPaged {
+    pub items: Vec<CocktailWithMissingIngredients>,
+    pub total_count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct FacetValue {
+    pub value: String,
+    pub count: u64,
+}
+
+/// Values with cocktail counts, each list is ordered by count descending, then by value.
+#[derive(Clone, Debug, Default)]
+pub struct CocktailFacets {
+    pub tags: Vec<FacetValue>,
+    pub countries: Vec<FacetValue>,
+    /// Spirits from `BASE_SPIRITS` with the largest amount in composition.
+    pub base_spirits: Vec<FacetValue>,
+    pub glasses: Vec<FacetValue>,
+    pub methods: Vec<FacetValue>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CatalogueStats {
+    pub cocktails_count: u64,
+    pub tags_count: u64,
+    pub ingredients_count: u64,
+    pub countries_count: u64,
+    pub glasses_count: u64,
+    pub rated_cocktails_count: u64,
+    pub ratings_count: u64,
+    /// Average of all ratings, `None` if nothing is rated yet.
+    pub average_rating: Option<f64>,
+    pub facets: CocktailFacets,
+}
+
+#[derive(Clone, Debug)]
+pub struct CocktailWithTextScore {
+    pub cocktail: Cocktail,
+    pub score: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct CocktailsTextSearchPaged {
+    pub items: Vec<CocktailWithTextScore>,
+    pub total_count: u64,
+}
+
 #[derive(Clone, Debug)]
 pub struct CocktailFilter {
     pub ids: Option<Vec<Uuid>>,
     pub names: Option<Vec<String>>,
     pub russian_names: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+    pub countries: Option<Vec<String>>,
+    pub sort: Option<CocktailSort>,
     pub pagination: crate::domain::Pagination,
 }
+
+/// Words of ingredient names recognised as a spirit and the spirit they belong to,
+/// used to find the base of a cocktail. A word matches only as a whole word,
+/// so "ginger beer" is not gin and "ромашка" is not rum.
+pub const BASE_SPIRITS: &[(&str, &str)] = &[
+    ("джин", "Джин"),
+    ("gin", "Джин"),
+    ("ром", "Ром"),
+    ("rum", "Ром"),
+    ("водка", "Водка"),
+    ("vodka", "Водка"),
+    ("текила", "Текила"),
+    ("tequila", "Текила"),
+    ("мескаль", "Мескаль"),
+    ("mezcal", "Мескаль"),
+    ("виски", "Виски"),
+    ("whisky", "Виски"),
+    ("whiskey", "Виски"),
+    ("бурбон", "Виски"),
+    ("bourbon", "Виски"),
+    ("скотч", "Виски"),
+    ("коньяк", "Бренди"),
+    ("cognac", "Бренди"),
+    ("бренди", "Бренди"),
+    ("brandy", "Бренди"),
+    ("кальвадос", "Бренди"),
+    ("calvados", "Бренди"),
+    ("писко", "Писко"),
+    ("pisco", "Писко"),
+    ("кашаса", "Кашаса"),
+    ("cachaca", "Кашаса"),
+    ("cachaça", "Кашаса"),
+    ("абсент", "Абсент"),
+    ("absinthe", "Абсент"),
+];
+
+/// Tags marking cocktails without alcohol.
+pub const NON_ALCOHOLIC_TAGS: &[&str] = &["Безалкогольные", "Безалкогольный", "Non-alcoholic"];
+
+/// Constraints for random cocktail and cocktail of the day.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RandomCocktailFilter {
+    pub tag: Option<String>,
+    /// Only cocktails marked with one of `NON_ALCOHOLIC_TAGS`.
+    pub non_alcoholic: bool,
+    pub max_ingredients: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CocktailSort {
+    Name,
+    Rating,
+}
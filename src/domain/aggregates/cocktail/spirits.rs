@@ -0,0 +1,129 @@
+use super::{Cocktail, BASE_SPIRITS};
+use crate::domain::units::{normalize_amount, MILLILITRES};
+
+/// Spirit from `BASE_SPIRITS` named by a whole word of the ingredient name.
+pub fn spirit_of(ingredient_name: &str) -> Option<&'static str> {
+    let name = ingredient_name.to_lowercase();
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .collect();
+    BASE_SPIRITS
+        .iter()
+        .find(|(word, _)| words.contains(word))
+        .map(|(_, spirit)| *spirit)
+}
+
+/// Regex matching `word` as a whole word, letters of any alphabet are parts of words.
+/// Used in queries, where `\b` knows only latin letters.
+pub fn whole_word_regex(word: &str) -> String {
+    format!(r"(^|[^\p{{L}}])({})([^\p{{L}}]|$)", word)
+}
+
+/// Amount compared between spirits: millilitres, or zero for other units.
+pub fn spirit_amount(count: i32, unit: &str) -> f64 {
+    match normalize_amount(count as f64, unit) {
+        (amount, unit) if unit == MILLILITRES => amount,
+        _ => 0.0,
+    }
+}
+
+impl Cocktail {
+    /// Spirit with the largest amount in composition, the first one of equal amounts.
+    pub fn base_spirit(&self) -> Option<&'static str> {
+        self.composition_elements
+            .iter()
+            .flatten()
+            .filter_map(|element| {
+                spirit_of(&element.name)
+                    .map(|spirit| (spirit_amount(element.count, &element.unit), spirit))
+            })
+            .fold(
+                None,
+                |base: Option<(f64, &'static str)>, candidate| match base {
+                    Some(base) if base.0 >= candidate.0 => Some(base),
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(_, spirit)| spirit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{aggregates::cocktail::CocktailItem, locale::LocalizedText};
+
+    fn cocktail(ingredients: &[(&str, i32, &str)]) -> Cocktail {
+        Cocktail::new(
+            None,
+            "Коктейль".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(
+                ingredients
+                    .iter()
+                    .map(|(name, count, unit)| CocktailItem {
+                        name: name.to_string(),
+                        count: *count,
+                        unit: unit.to_string(),
+                        display_names: LocalizedText::default(),
+                    })
+                    .collect(),
+            ),
+            None,
+        )
+    }
+
+    #[test]
+    fn spirit_of_matches_whole_words() {
+        assert_eq!(spirit_of("Лондонский сухой джин"), Some("Джин"));
+        assert_eq!(spirit_of("Gin"), Some("Джин"));
+        assert_eq!(spirit_of("Белый ром"), Some("Ром"));
+        assert_eq!(spirit_of("Ginger beer"), None);
+        assert_eq!(spirit_of("Имбирный сироп (ginger syrup)"), None);
+        assert_eq!(spirit_of("Чай с ромашкой"), None);
+        assert_eq!(spirit_of("Ромашка"), None);
+    }
+
+    #[test]
+    fn whole_word_regex_requires_non_letters_around_word() {
+        assert_eq!(
+            whole_word_regex("gin|ром"),
+            r"(^|[^\p{L}])(gin|ром)([^\p{L}]|$)"
+        );
+    }
+
+    #[test]
+    fn base_spirit_ignores_ginger_beer() {
+        let moscow_mule = cocktail(&[
+            ("Водка", 50, "мл"),
+            ("Имбирное пиво (ginger beer)", 120, "мл"),
+            ("Сок лайма", 10, "мл"),
+        ]);
+        assert_eq!(moscow_mule.base_spirit(), Some("Водка"));
+    }
+
+    #[test]
+    fn base_spirit_ignores_chamomile() {
+        let chamomile_toddy = cocktail(&[("Ромашковый чай", 100, "мл"), ("Виски", 40, "мл")]);
+        assert_eq!(chamomile_toddy.base_spirit(), Some("Виски"));
+    }
+
+    #[test]
+    fn base_spirit_compares_amounts_in_millilitres() {
+        let cocktail = cocktail(&[("Ром", 40, "мл"), ("Бурбон", 2, "oz"), ("Джин", 3, "cl")]);
+        assert_eq!(cocktail.base_spirit(), Some("Виски"));
+    }
+
+    #[test]
+    fn base_spirit_keeps_first_of_equal_amounts() {
+        let cocktail = cocktail(&[("Джин", 30, "мл"), ("Ром", 3, "cl")]);
+        assert_eq!(cocktail.base_spirit(), Some("Джин"));
+    }
+}
@@ -0,0 +1,374 @@
+use super::Cocktail;
+use crate::domain::locale::{normalize_locale, LocalizedText, DEFAULT_LOCALE, ENGLISH_LOCALE};
+
+pub const MAX_NAME_LENGTH: usize = 100;
+pub const MAX_URL_LENGTH: usize = 2048;
+pub const MAX_HISTORY_LENGTH: usize = 5000;
+pub const MAX_TAGS: usize = 20;
+pub const MAX_TAG_LENGTH: usize = 30;
+pub const MAX_TOOLS: usize = 20;
+pub const MAX_INGREDIENTS: usize = 30;
+pub const MAX_UNIT_LENGTH: usize = 20;
+pub const MAX_STEPS: usize = 50;
+pub const MAX_STEP_LENGTH: usize = 1000;
+/// Besides letters and digits of any alphabet.
+pub const TAG_PUNCTUATION: &str = " -_'";
+pub const MAX_TRANSLATIONS: usize = 20;
+
+/// Violated constraint of a cocktail field.
+/// `field` is a path in the cocktail, like `composition_elements[2].count`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        FieldError {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Cocktail {
+    /// Checks constraints of the cocktail fields. Uniqueness of names needs other cocktails
+    /// and is checked by `services::validation`.
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = vec![];
+        check_text(
+            &mut errors,
+            "russian_name",
+            Some(&self.russian_name),
+            MAX_NAME_LENGTH,
+        );
+        check_text(&mut errors, "name", self.name.as_ref(), MAX_NAME_LENGTH);
+        check_translations(
+            &mut errors,
+            "name_translations",
+            &self.name_translations,
+            &[DEFAULT_LOCALE, ENGLISH_LOCALE],
+            MAX_NAME_LENGTH,
+        );
+        check_text(
+            &mut errors,
+            "country_of_origin",
+            self.country_of_origin.as_ref(),
+            MAX_NAME_LENGTH,
+        );
+        check_length(
+            &mut errors,
+            "history",
+            self.history.as_ref(),
+            MAX_HISTORY_LENGTH,
+        );
+        check_translations(
+            &mut errors,
+            "history_translations",
+            &self.history_translations,
+            &[DEFAULT_LOCALE],
+            MAX_HISTORY_LENGTH,
+        );
+        check_text(&mut errors, "glass", self.glass.as_ref(), MAX_NAME_LENGTH);
+        if let Some(url) = &self.url {
+            check_url(&mut errors, url);
+        }
+
+        let tags = self.tags.as_deref().unwrap_or_default();
+        check_count(&mut errors, "tags", tags.len(), MAX_TAGS);
+        for (index, tag) in tags.iter().enumerate() {
+            let field = format!("tags[{}].name", index);
+            check_text(&mut errors, &field, Some(&tag.name), MAX_TAG_LENGTH);
+            if !tag
+                .name
+                .chars()
+                .all(|c| c.is_alphanumeric() || TAG_PUNCTUATION.contains(c))
+            {
+                errors.push(FieldError::new(
+                    field,
+                    "may contain only letters, digits, spaces, hyphens, underscores and apostrophes",
+                ));
+            }
+        }
+
+        let tools = self.tools.as_deref().unwrap_or_default();
+        check_count(&mut errors, "tools", tools.len(), MAX_TOOLS);
+        for (index, tool) in tools.iter().enumerate() {
+            let field = format!("tools[{}].name", index);
+            check_text(&mut errors, &field, Some(&tool.name), MAX_NAME_LENGTH);
+        }
+
+        let ingredients = self.composition_elements.as_deref().unwrap_or_default();
+        if ingredients.is_empty() {
+            errors.push(FieldError::new(
+                "composition_elements",
+                "at least one ingredient is required",
+            ));
+        }
+        check_count(
+            &mut errors,
+            "composition_elements",
+            ingredients.len(),
+            MAX_INGREDIENTS,
+        );
+        for (index, ingredient) in ingredients.iter().enumerate() {
+            let field = format!("composition_elements[{}]", index);
+            check_text(
+                &mut errors,
+                &format!("{}.name", field),
+                Some(&ingredient.name),
+                MAX_NAME_LENGTH,
+            );
+            if ingredient.count < 0 {
+                errors.push(FieldError::new(
+                    format!("{}.count", field),
+                    "must not be negative",
+                ));
+            }
+            check_length(
+                &mut errors,
+                &format!("{}.unit", field),
+                Some(&ingredient.unit),
+                MAX_UNIT_LENGTH,
+            );
+            check_translations(
+                &mut errors,
+                &format!("{}.display_names", field),
+                &ingredient.display_names,
+                &[DEFAULT_LOCALE],
+                MAX_NAME_LENGTH,
+            );
+        }
+
+        if let Some(recipe) = &self.recipe {
+            check_count(&mut errors, "recipe.steps", recipe.steps.len(), MAX_STEPS);
+            for (index, step) in recipe.steps.iter().enumerate() {
+                let field = format!("recipe.steps[{}]", index);
+                check_text(&mut errors, &field, Some(step), MAX_STEP_LENGTH);
+            }
+            if recipe.step_translations.len() > recipe.steps.len() {
+                errors.push(FieldError::new(
+                    "recipe.step_translations",
+                    "must not have more items than steps",
+                ));
+            }
+            for (index, translations) in recipe.step_translations.iter().enumerate() {
+                check_translations(
+                    &mut errors,
+                    &format!("recipe.step_translations[{}]", index),
+                    translations,
+                    &[DEFAULT_LOCALE],
+                    MAX_STEP_LENGTH,
+                );
+            }
+        }
+
+        errors
+    }
+}
+
+/// Present text must not be blank and must fit the length.
+fn check_text(errors: &mut Vec<FieldError>, field: &str, value: Option<&String>, max: usize) {
+    if let Some(value) = value {
+        if value.trim().is_empty() {
+            errors.push(FieldError::new(field, "must not be blank"));
+        }
+    }
+    check_length(errors, field, value, max);
+}
+
+fn check_length(errors: &mut Vec<FieldError>, field: &str, value: Option<&String>, max: usize) {
+    if value.is_some_and(|value| value.chars().count() > max) {
+        errors.push(FieldError::new(
+            field,
+            format!("must be at most {} characters long", max),
+        ));
+    }
+}
+
+/// Locales must be normalized and must not duplicate the main field, like `ru` for `history`.
+fn check_translations(
+    errors: &mut Vec<FieldError>,
+    field: &str,
+    translations: &LocalizedText,
+    reserved_locales: &[&str],
+    max: usize,
+) {
+    check_count(errors, field, translations.len(), MAX_TRANSLATIONS);
+    for (locale, text) in translations.iter() {
+        let locale_field = format!("{}.{}", field, locale);
+        if normalize_locale(locale).as_deref() != Some(locale) {
+            errors.push(FieldError::new(
+                locale_field,
+                "locale must be a lowercase language code with optional region, like en or pt-br",
+            ));
+        } else if reserved_locales.contains(&locale) {
+            errors.push(FieldError::new(
+                locale_field,
+                "text in this locale is kept in the main field",
+            ));
+        } else {
+            check_text(errors, &locale_field, Some(&text.to_string()), max);
+        }
+    }
+}
+
+fn check_count(errors: &mut Vec<FieldError>, field: &str, count: usize, max: usize) {
+    if count > max {
+        errors.push(FieldError::new(
+            field,
+            format!("must have at most {} items", max),
+        ));
+    }
+}
+
+fn check_url(errors: &mut Vec<FieldError>, url: &str) {
+    if url.len() > MAX_URL_LENGTH {
+        errors.push(FieldError::new(
+            "url",
+            format!("must be at most {} characters long", MAX_URL_LENGTH),
+        ));
+        return;
+    }
+    let is_web_url = url::Url::parse(url)
+        .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.host().is_some());
+    if !is_web_url {
+        errors.push(FieldError::new(
+            "url",
+            "must be an absolute http or https URL",
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::domain::aggregates::cocktail::{CocktailItem, CocktailTool, Recipe, Tag};
+
+    fn ingredient(name: &str, count: i32) -> CocktailItem {
+        CocktailItem {
+            name: name.to_string(),
+            count,
+            unit: "мл".to_string(),
+            display_names: LocalizedText::default(),
+        }
+    }
+
+    fn translations(texts: &[(&str, &str)]) -> LocalizedText {
+        texts
+            .iter()
+            .map(|(locale, text)| (locale.to_string(), text.to_string()))
+            .collect::<BTreeMap<_, _>>()
+            .into()
+    }
+
+    fn cocktail() -> Cocktail {
+        Cocktail::new(
+            Some("Negroni".to_string()),
+            "Негрони".to_string(),
+            Some("https://example.com/negroni".to_string()),
+            None,
+            None,
+            Some(vec![Tag {
+                name: "классика".to_string(),
+            }]),
+            None,
+            None,
+            None,
+            Some(vec![
+                ingredient("Джин", 30),
+                ingredient("Кампари", 30),
+                ingredient("Красный вермут", 30),
+            ]),
+            Some(Recipe {
+                steps: vec!["Смешать в стакане со льдом".to_string()],
+                step_translations: vec![],
+            }),
+        )
+    }
+
+    fn fields(cocktail: &Cocktail) -> Vec<String> {
+        cocktail
+            .validate()
+            .into_iter()
+            .map(|error| error.field)
+            .collect()
+    }
+
+    #[test]
+    fn valid_cocktail_has_no_errors() {
+        assert_eq!(cocktail().validate(), vec![]);
+    }
+
+    #[test]
+    fn ingredient_errors_point_to_its_index() {
+        let mut cocktail = cocktail();
+        let ingredients = cocktail.composition_elements.as_mut().unwrap();
+        ingredients[2].count = -1;
+        ingredients[1].name = " ".to_string();
+        ingredients[0].unit = "u".repeat(MAX_UNIT_LENGTH + 1);
+        assert_eq!(
+            fields(&cocktail),
+            vec![
+                "composition_elements[0].unit",
+                "composition_elements[1].name",
+                "composition_elements[2].count",
+            ]
+        );
+    }
+
+    #[test]
+    fn translation_errors_point_to_locale() {
+        let mut cocktail = cocktail();
+        cocktail.name_translations = translations(&[("en", "Negroni"), ("EN-us", "Negroni")]);
+        cocktail.composition_elements.as_mut().unwrap()[1].display_names =
+            translations(&[("de", " ")]);
+        assert_eq!(
+            fields(&cocktail),
+            vec![
+                "name_translations.EN-us",
+                "name_translations.en",
+                "composition_elements[1].display_names.de",
+            ]
+        );
+    }
+
+    #[test]
+    fn tag_tool_and_step_errors_point_to_index() {
+        let mut cocktail = cocktail();
+        cocktail.tags.as_mut().unwrap().push(Tag {
+            name: "new;tag".to_string(),
+        });
+        cocktail.tools = Some(vec![
+            CocktailTool {
+                name: "Стрейнер".to_string(),
+            },
+            CocktailTool {
+                name: String::new(),
+            },
+        ]);
+        let recipe = cocktail.recipe.as_mut().unwrap();
+        recipe.steps.push("s".repeat(MAX_STEP_LENGTH + 1));
+        recipe.step_translations = vec![LocalizedText::default(), translations(&[("ru", "Шаг")])];
+        assert_eq!(
+            fields(&cocktail),
+            vec![
+                "tags[1].name",
+                "tools[1].name",
+                "recipe.steps[1]",
+                "recipe.step_translations[1].ru",
+            ]
+        );
+    }
+
+    #[test]
+    fn list_errors_point_to_list() {
+        let mut cocktail = cocktail();
+        cocktail.composition_elements = Some(vec![]);
+        cocktail.url = Some("ftp://example.com".to_string());
+        assert_eq!(fields(&cocktail), vec!["url", "composition_elements"]);
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const MAX_COLLECTION_NAME_LENGTH: usize = 64;
+
+/// Named list of cocktails (menu) owned by user.
+/// Cocktails keep the order in which they were added.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Collection {
+    pub(crate) id: Uuid,
+    pub(crate) owner_id: Uuid,
+    pub(crate) name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) cocktail_ids: Vec<Uuid>,
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl Collection {
+    pub fn new(owner_id: Uuid, name: String, description: Option<String>) -> Self {
+        Collection {
+            id: uuid::Uuid::new_v4(),
+            owner_id,
+            name,
+            description,
+            cocktail_ids: vec![],
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn contains(&self, cocktail_id: &Uuid) -> bool {
+        self.cocktail_ids.contains(cocktail_id)
+    }
+
+    /// Adds cocktail to the end of collection. Does nothing if cocktail is already added.
+    pub fn add_cocktail(&mut self, cocktail_id: &Uuid) {
+        if !self.contains(cocktail_id) {
+            self.cocktail_ids.push(*cocktail_id);
+        }
+    }
+
+    pub fn remove_cocktail(&mut self, cocktail_id: &Uuid) {
+        self.cocktail_ids.retain(|id| id != cocktail_id);
+    }
+}
+
+#[async_trait]
+pub trait CollectionRepo {
+    /// .
+    async fn create(&self, entity: &Collection) -> Result<()>;
+    /// .
+    async fn delete(&self, entity: &Collection) -> Result<()>;
+    /// .
+    async fn update(&self, entity: &Collection) -> Result<()>;
+    /// .
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<Collection>>;
+    /// .
+    async fn get_by_filter(&self, filter: &CollectionFilter) -> Result<CollectionsPaged>;
+    /// Removes cocktails from collections of all users. Returns number of modified collections.
+    async fn remove_cocktails(&self, cocktail_ids: &[Uuid]) -> Result<u64>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CollectionsPaged {
+    pub items: Vec<Collection>,
+    pub total_count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct CollectionFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub owner_id: Option<Uuid>,
+    pub pagination: crate::domain::Pagination,
+}
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Glassware {
+    pub(crate) id: Uuid,
+    pub(crate) name: Option<String>,
+    pub(crate) russian_name: String,
+    pub(crate) volume_ml: Option<i32>,
+    pub(crate) description: Option<String>,
+}
+
+impl Glassware {
+    pub fn new(
+        name: Option<String>,
+        russian_name: String,
+        volume_ml: Option<i32>,
+        description: Option<String>,
+    ) -> Self {
+        Glassware {
+            id: uuid::Uuid::new_v4(),
+            name,
+            russian_name,
+            volume_ml,
+            description,
+        }
+    }
+}
+
+#[async_trait]
+pub trait GlasswareRepo {
+    /// .
+    async fn create(&self, entity: &Glassware) -> Result<()>;
+    /// .
+    async fn delete(&self, entity: &Glassware) -> Result<()>;
+    /// .
+    async fn update(&self, entity: &Glassware) -> Result<()>;
+    /// .
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<Glassware>>;
+    /// .
+    async fn get_by_filter(&self, filter: &GlasswareFilter) -> Result<GlasswarePaged>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GlasswarePaged {
+    pub items: Vec<Glassware>,
+    pub total_count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct GlasswareFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub pagination: crate::domain::Pagination,
+}
@@ -1,2 +1,7 @@
 pub mod cocktail;
+pub mod collection;
+pub mod glassware;
+pub mod rating;
+pub mod revision;
+pub mod tool;
 pub mod user;
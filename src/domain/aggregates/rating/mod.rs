@@ -0,0 +1,87 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const MIN_STARS: u8 = 1;
+pub const MAX_STARS: u8 = 5;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Rating {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) cocktail_id: Uuid,
+    pub(crate) stars: u8,
+    pub(crate) review: Option<String>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl Rating {
+    pub fn new(user_id: Uuid, cocktail_id: Uuid, stars: u8, review: Option<String>) -> Self {
+        let now = Utc::now();
+        Rating {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            cocktail_id,
+            stars,
+            review,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn change(&mut self, stars: u8, review: Option<String>) {
+        self.stars = stars;
+        self.review = review;
+        self.updated_at = Utc::now();
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RatingError {
+    #[error("stars must be between 1 and 5, got {0}")]
+    InvalidStars(u8),
+    #[error("cocktail with id {0} not found")]
+    CocktailNotFound(Uuid),
+}
+
+#[async_trait]
+pub trait RatingRepo {
+    /// Creates rating or replaces existing rating of the same user for the same cocktail.
+    async fn upsert(&self, entity: &Rating) -> Result<()>;
+    /// .
+    async fn get_by_user_and_cocktail(
+        &self,
+        user_id: &Uuid,
+        cocktail_id: &Uuid,
+    ) -> Result<Option<Rating>>;
+    /// .
+    async fn get_by_filter(&self, filter: &RatingFilter) -> Result<RatingsPaged>;
+    /// Returns all ratings left by user.
+    async fn get_by_user(&self, user_id: &Uuid) -> Result<Vec<Rating>>;
+    /// Calculates average stars and ratings count for cocktail.
+    async fn get_summary(&self, cocktail_id: &Uuid) -> Result<RatingSummary>;
+    /// Removes ratings of the cocktails. Returns number of removed ratings.
+    async fn delete_by_cocktails(&self, cocktail_ids: &[Uuid]) -> Result<u64>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RatingSummary {
+    pub average: f64,
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RatingsPaged {
+    pub items: Vec<Rating>,
+    pub total_count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct RatingFilter {
+    pub cocktail_id: Uuid,
+    pub with_review_only: bool,
+    pub pagination: crate::domain::Pagination,
+}
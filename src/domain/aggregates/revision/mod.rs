@@ -0,0 +1,65 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::cocktail::Cocktail;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum RevisionAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+}
+
+/// Immutable record of one change of a cocktail.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct CocktailRevision {
+    pub(crate) id: Uuid,
+    pub(crate) cocktail_id: Uuid,
+    /// Sequence number of the revision, starts from 1 for every cocktail.
+    pub(crate) number: u64,
+    pub(crate) action: RevisionAction,
+    pub(crate) author: Option<String>,
+    pub(crate) created_at: DateTime<Utc>,
+    /// Cocktail after the change, for deletions the cocktail as it was deleted.
+    pub(crate) snapshot: Cocktail,
+    /// JSON Patch (RFC 6902) from the previous revision to the snapshot.
+    pub(crate) diff: json_patch::Patch,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RevisionError {
+    #[error("revision {number} of cocktail {cocktail_id} not found")]
+    NotFound { cocktail_id: Uuid, number: u64 },
+    #[error("revision {number} of cocktail {cocktail_id} is already recorded")]
+    NumberTaken { cocktail_id: Uuid, number: u64 },
+    #[error("revision must have cocktail state before or after the change")]
+    NoState,
+}
+
+#[async_trait]
+pub trait RevisionRepo {
+    /// Saves revision, revisions are never changed after that. Fails with
+    /// `RevisionError::NumberTaken` if the cocktail already has a revision with the same number.
+    async fn create(&self, entity: &CocktailRevision) -> Result<()>;
+    /// Returns revisions of cocktail, the latest first.
+    async fn get_by_cocktail(
+        &self,
+        cocktail_id: &Uuid,
+        pagination: &crate::domain::Pagination,
+    ) -> Result<RevisionsPaged>;
+    /// .
+    async fn get(&self, cocktail_id: &Uuid, number: u64) -> Result<Option<CocktailRevision>>;
+    /// Returns the latest revision of cocktail, if any was recorded.
+    async fn get_latest(&self, cocktail_id: &Uuid) -> Result<Option<CocktailRevision>>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RevisionsPaged {
+    pub items: Vec<CocktailRevision>,
+    pub total_count: u64,
+}
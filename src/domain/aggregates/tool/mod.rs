@@ -0,0 +1,49 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Tool {
+    pub(crate) id: Uuid,
+    pub(crate) name: Option<String>,
+    pub(crate) russian_name: String,
+    pub(crate) description: Option<String>,
+}
+
+impl Tool {
+    pub fn new(name: Option<String>, russian_name: String, description: Option<String>) -> Self {
+        Tool {
+            id: uuid::Uuid::new_v4(),
+            name,
+            russian_name,
+            description,
+        }
+    }
+}
+
+#[async_trait]
+pub trait ToolRepo {
+    /// .
+    async fn create(&self, entity: &Tool) -> Result<()>;
+    /// .
+    async fn delete(&self, entity: &Tool) -> Result<()>;
+    /// .
+    async fn update(&self, entity: &Tool) -> Result<()>;
+    /// .
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<Tool>>;
+    /// .
+    async fn get_by_filter(&self, filter: &ToolFilter) -> Result<ToolsPaged>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ToolsPaged {
+    pub items: Vec<Tool>,
+    pub total_count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct ToolFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub pagination: crate::domain::Pagination,
+}
@@ -1,14 +1,166 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::cocktail::RandomCocktailFilter;
+
+pub const MAX_FAVORITE_NOTE_LENGTH: usize = 500;
+/// Number of the most recently opened cocktail cards kept for user.
+pub const MAX_VIEWED_COCKTAILS: usize = 30;
+/// How long after the chosen time a missed daily cocktail is still sent, e.g. after restart.
+pub const DAILY_SUBSCRIPTION_GRACE_MINUTES: i64 = 180;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct User {
     pub id: Uuid,
     pub telegram_id: u64,
-    pub favorite_cocktails: Vec<Uuid>,
+    pub favorite_cocktails: Vec<FavoriteCocktail>,
+    /// Bottles and ingredients user has at home, named as in cocktail composition.
+    pub bar_ingredients: Vec<String>,
+    pub daily_subscription: Option<DailySubscription>,
+    /// Local date of the last sent daily cocktail. Changed only by `UserRepo::claim_daily_delivery`.
+    pub daily_last_sent_on: Option<NaiveDate>,
+    /// Recently opened cocktail cards, the oldest first. Changed only by `UserRepo::record_cocktail_view`.
+    pub viewed_cocktails: Vec<ViewedCocktail>,
+    /// Language of the bot interface chosen by user, e.g. `en`.
+    /// Language of Telegram client is used while not chosen.
+    pub language: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ViewedCocktail {
+    pub cocktail_id: Uuid,
+    pub viewed_at: DateTime<Utc>,
+}
+
+/// Daily cocktail sent to user at the chosen local time.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DailySubscription {
+    /// IANA time zone name, e.g. `Europe/Moscow`.
+    pub timezone: String,
+    pub send_at: NaiveTime,
+    pub filter: RandomCocktailFilter,
+}
+
+impl DailySubscription {
+    pub const DEFAULT_TIMEZONE: &'static str = "Europe/Moscow";
+
+    pub fn new(timezone: &str, send_at: NaiveTime) -> Self {
+        DailySubscription {
+            timezone: timezone.to_string(),
+            send_at,
+            filter: RandomCocktailFilter::default(),
+        }
+    }
+
+    /// Returns local date of the delivery which should be sent at the moment, if any.
+    pub fn get_due_delivery_date(&self, now: &DateTime<Utc>) -> Option<NaiveDate> {
+        let timezone: Tz = self.timezone.parse().ok()?;
+        let local_now = now.with_timezone(&timezone).naive_local();
+        let scheduled_today = local_now.date().and_time(self.send_at);
+        let scheduled = if scheduled_today <= local_now {
+            scheduled_today
+        } else {
+            scheduled_today - TimeDelta::days(1)
+        };
+        if local_now - scheduled < TimeDelta::minutes(DAILY_SUBSCRIPTION_GRACE_MINUTES) {
+            Some(scheduled.date())
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FavoriteCocktail {
+    pub cocktail_id: Uuid,
+    pub added_at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+impl FavoriteCocktail {
+    pub fn new(cocktail_id: Uuid) -> Self {
+        FavoriteCocktail {
+            cocktail_id,
+            added_at: Utc::now(),
+            note: None,
+        }
+    }
+}
+
+impl User {
+    pub fn get_favorite(&self, cocktail_id: &Uuid) -> Option<&FavoriteCocktail> {
+        self.favorite_cocktails
+            .iter()
+            .find(|favorite| favorite.cocktail_id == *cocktail_id)
+    }
+
+    pub fn is_favorite(&self, cocktail_id: &Uuid) -> bool {
+        self.get_favorite(cocktail_id).is_some()
+    }
+
+    /// Adds cocktail to favorites. Does nothing if cocktail is already in favorites.
+    pub fn add_favorite(&mut self, cocktail_id: &Uuid) {
+        if !self.is_favorite(cocktail_id) {
+            self.favorite_cocktails
+                .push(FavoriteCocktail::new(*cocktail_id));
+        }
+    }
+
+    pub fn remove_favorite(&mut self, cocktail_id: &Uuid) {
+        self.favorite_cocktails
+            .retain(|favorite| favorite.cocktail_id != *cocktail_id);
+    }
+
+    /// Sets or clears the personal note of favorite cocktail.
+    /// Returns `false` if cocktail is not in favorites.
+    pub fn set_favorite_note(&mut self, cocktail_id: &Uuid, note: Option<String>) -> bool {
+        match self
+            .favorite_cocktails
+            .iter_mut()
+            .find(|favorite| favorite.cocktail_id == *cocktail_id)
+        {
+            Some(favorite) => {
+                favorite.note = note.filter(|note| !note.trim().is_empty());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Favorite cocktails ids ordered from the most recently added.
+    pub fn get_favorite_ids_by_recency(&self) -> Vec<Uuid> {
+        let mut favorites = self.favorite_cocktails.clone();
+        favorites.sort_by_key(|favorite| std::cmp::Reverse(favorite.added_at));
+        favorites
+            .iter()
+            .map(|favorite| favorite.cocktail_id)
+            .collect()
+    }
+}
+
+impl User {
+    pub fn has_bar_ingredient(&self, ingredient: &str) -> bool {
+        self.bar_ingredients
+            .iter()
+            .any(|x| x.to_lowercase() == ingredient.to_lowercase())
+    }
+
+    /// Adds ingredient to home bar. Does nothing if ingredient is already there.
+    pub fn add_bar_ingredient(&mut self, ingredient: &str) {
+        if !self.has_bar_ingredient(ingredient) {
+            self.bar_ingredients.push(ingredient.to_string());
+        }
+    }
+
+    pub fn remove_bar_ingredient(&mut self, ingredient: &str) {
+        self.bar_ingredients
+            .retain(|x| x.to_lowercase() != ingredient.to_lowercase());
+    }
 }
 
 #[async_trait]
@@ -20,13 +172,25 @@ pub trait UserRepo {
     /// .
     async fn update(&self, user_entity: &User) -> Result<()>;
     /// .
-    async fn get_by_telegram_id(
-        &self,
-        telegram_user_id: &u64,
-    ) -> Result<Option<User>>;
+    async fn get_by_telegram_id(&self, telegram_user_id: &u64) -> Result<Option<User>>;
     /// .
-    async fn is_exist_by_telegram_id(
+    async fn is_exist_by_telegram_id(&self, telegram_user_id: &u64) -> Result<bool>;
+    /// Returns all users subscribed to the daily cocktail.
+    async fn get_with_daily_subscription(&self) -> Result<Vec<User>>;
+    /// Atomically marks daily cocktail for the date as sent.
+    /// Returns `false` if it was already marked, so the cocktail must not be sent again.
+    async fn claim_daily_delivery(&self, user_id: &Uuid, date: &NaiveDate) -> Result<bool>;
+    /// Takes back the claim for the date when the cocktail was not sent, restoring the previous date,
+    /// so the delivery is retried on the next check.
+    async fn release_daily_delivery(
         &self,
-        telegram_user_id: &u64,
-    ) -> Result<bool>;
+        user_id: &Uuid,
+        date: &NaiveDate,
+        previous_date: &Option<NaiveDate>,
+    ) -> Result<()>;
+    /// Moves cocktail to the end of recently viewed cocktails, keeping at most `MAX_VIEWED_COCKTAILS`.
+    async fn record_cocktail_view(&self, user_id: &Uuid, cocktail_id: &Uuid) -> Result<()>;
+    /// Removes cocktails from favourites and recently viewed cocktails of all users.
+    /// Returns number of modified users.
+    async fn remove_cocktails(&self, cocktail_ids: &[Uuid]) -> Result<u64>;
 }
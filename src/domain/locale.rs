@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Language of the main texts of the catalogue: `russian_name`, `history`, recipe steps
+/// and ingredient names.
+pub const DEFAULT_LOCALE: &str = "ru";
+/// Language of `name` of cocktail.
+pub const ENGLISH_LOCALE: &str = "en";
+/// Tried after the requested locales, the default locale always ends the chain.
+const FALLBACK_LOCALES: &[&str] = &[ENGLISH_LOCALE, DEFAULT_LOCALE];
+/// Used when nothing is requested, keeps the catalogue in its own language.
+const DEFAULT_LOCALES: &[&str] = &[DEFAULT_LOCALE, ENGLISH_LOCALE];
+
+/// Locales to look texts up in, in order of preference. A regional locale like `pt-br`
+/// is followed by its language `pt`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocaleChain(Vec<String>);
+
+impl LocaleChain {
+    /// Builds chain from the value of `Accept-Language` header, languages are ordered by
+    /// their quality, `*` and languages with zero quality are skipped.
+    pub fn from_accept_language(header: Option<&str>) -> Self {
+        let mut languages: Vec<(&str, f32)> = header
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|language| {
+                let mut parts = language.split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|parameter| parameter.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+                (tag != "*" && quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+        // stable sort keeps the header order for equal qualities
+        languages.sort_by(|left, right| right.1.total_cmp(&left.1));
+
+        Self::new(languages.into_iter().map(|(tag, _)| tag))
+    }
+
+    /// Builds chain from `language_code` of Telegram user.
+    pub fn from_language_code(language_code: Option<&str>) -> Self {
+        Self::new(language_code)
+    }
+
+    fn new<'a>(requested: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut locales: Vec<String> = vec![];
+        for locale in requested.into_iter().filter_map(normalize_locale) {
+            let language = locale.split('-').next().unwrap_or_default().to_string();
+            for locale in [locale, language] {
+                if !locales.contains(&locale) {
+                    locales.push(locale);
+                }
+            }
+        }
+        let fallbacks = if locales.is_empty() {
+            DEFAULT_LOCALES
+        } else {
+            FALLBACK_LOCALES
+        };
+        for fallback in fallbacks {
+            if !locales.iter().any(|locale| locale == fallback) {
+                locales.push(fallback.to_string());
+            }
+        }
+
+        LocaleChain(locales)
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+impl Default for LocaleChain {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+/// Lowercase language with optional region or script, like `en`, `pt-br` or `sr-latn`.
+/// Returns `None` for anything else.
+pub fn normalize_locale(locale: &str) -> Option<String> {
+    let locale = locale.trim().replace('_', "-").to_lowercase();
+    let mut parts = locale.split('-');
+    let language = parts.next()?;
+    let valid_language =
+        (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_lowercase());
+    let valid_subtags = parts.all(|subtag| {
+        (2..=4).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+    });
+
+    (valid_language && valid_subtags).then_some(locale)
+}
+
+/// Translations of a text keyed by locale. The text in `DEFAULT_LOCALE` is kept in the field
+/// the translations belong to.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub(crate) struct LocalizedText(BTreeMap<String, String>);
+
+impl LocalizedText {
+    /// Text in the first locale of the chain having one, `default` is the text in
+    /// `DEFAULT_LOCALE`.
+    pub fn resolve<'a>(&'a self, chain: &LocaleChain, default: Option<&'a str>) -> Option<&'a str> {
+        for locale in chain.locales() {
+            let text = match locale {
+                DEFAULT_LOCALE => default,
+                _ => self.get(locale),
+            };
+            if text.is_some() {
+                return text;
+            }
+        }
+
+        default
+    }
+
+    /// Text in exactly this locale, blank texts are treated as missing.
+    pub fn get(&self, locale: &str) -> Option<&str> {
+        self.0
+            .get(locale)
+            .map(String::as_str)
+            .filter(|text| !text.trim().is_empty())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(locale, text)| (locale.as_str(), text.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl From<BTreeMap<String, String>> for LocalizedText {
+    fn from(value: BTreeMap<String, String>) -> Self {
+        LocalizedText(value)
+    }
+}
+
+impl From<LocalizedText> for BTreeMap<String, String> {
+    fn from(value: LocalizedText) -> Self {
+        value.0
+    }
+}
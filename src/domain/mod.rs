@@ -1,4 +1,8 @@
 pub mod aggregates;
+pub mod locale;
+pub mod services;
+pub mod transliteration;
+pub mod units;
 
 #[derive(Clone, Debug)]
 pub struct Pagination {
@@ -0,0 +1,45 @@
+use anyhow::Result;
+
+use crate::domain::aggregates::{cocktail::CocktailRepo, user::User};
+
+/// Finds ingredients from cocktails catalogue matching the typed part of name.
+/// Ingredients starting with the query go first, ingredients user already has are skipped.
+pub async fn suggest_ingredients<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    user: &User,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<String>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut suggestions: Vec<(bool, String)> = cocktail_repo
+        .get_ingredients()
+        .await?
+        .into_iter()
+        .filter(|ingredient| !user.has_bar_ingredient(ingredient))
+        .filter_map(|ingredient| {
+            let lowercase = ingredient.to_lowercase();
+            if lowercase.starts_with(&query) {
+                Some((true, ingredient))
+            } else if lowercase.contains(&query) {
+                Some((false, ingredient))
+            } else {
+                None
+            }
+        })
+        .collect();
+    // stable sort keeps alphabetical order inside groups
+    suggestions.sort_by_key(|(starts_with, _)| !starts_with);
+
+    Ok(suggestions
+        .into_iter()
+        .take(limit)
+        .map(|(_, ingredient)| ingredient)
+        .collect())
+}
@@ -0,0 +1,191 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, ImageFormat};
+use uuid::Uuid;
+
+use crate::domain::aggregates::cocktail::{
+    Cocktail, CocktailImage, CocktailRepo, ImageSize, ImageVariant,
+};
+
+/// Largest accepted upload.
+pub const MAX_IMAGE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Resized copies with the largest side of the image, smaller images are not enlarged.
+const RESIZED_VARIANTS: &[(ImageSize, u32)] = &[
+    (ImageSize::Large, 1280),
+    (ImageSize::Medium, 640),
+    (ImageSize::Thumbnail, 160),
+];
+
+const RESIZED_JPEG_QUALITY: u8 = 85;
+
+/// Storage of image files, implementations keep them on local disk or in S3-compatible storage.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    async fn put(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<()>;
+    /// Returns `None` if there is no file with the key.
+    async fn get(&self, key: &str) -> Result<Option<Blob>>;
+    /// Removing a missing file is not an error.
+    async fn delete(&self, key: &str) -> Result<()>;
+    /// URL the file is available at for API clients.
+    fn public_url(&self, key: &str) -> String;
+}
+
+pub struct Blob {
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ImageError {
+    #[error("image format is not supported, upload JPEG, PNG or WebP")]
+    UnsupportedFormat,
+    #[error("image can not be read: {0}")]
+    Corrupted(String),
+}
+
+/// Stores uploaded image with its resized copies and replaces the previous image of the cocktail.
+/// Fails with `ImageError` if the data is not a supported image
+/// and with `CocktailError::VersionConflict` if the cocktail was changed since it was read.
+pub async fn upload<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    blob_store: &dyn BlobStore,
+    cocktail: &Cocktail,
+    data: Vec<u8>,
+) -> Result<CocktailImage>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let format = match image::guess_format(&data) {
+        Ok(format @ (ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP)) => format,
+        _ => return Err(ImageError::UnsupportedFormat.into()),
+    };
+    let image_id = Uuid::new_v4();
+    let key_prefix = format!("cocktails/{}/{}", cocktail.id, image_id);
+
+    // decoding and resizing take a while, so they are kept off the async workers
+    let original = data.clone();
+    let (decoded, resized) = tokio::task::spawn_blocking(move || resize(&original, format))
+        .await
+        .context("image resizing task failed")??;
+
+    let mut variants = vec![ImageVariant {
+        size: ImageSize::Original,
+        width: decoded.0,
+        height: decoded.1,
+        content_type: format.to_mime_type().to_string(),
+        key: format!(
+            "{}/original.{}",
+            key_prefix,
+            format.extensions_str().first().unwrap_or(&"bin")
+        ),
+        url: String::new(),
+    }];
+    let mut files = vec![data];
+    for copy in resized {
+        variants.push(ImageVariant {
+            size: copy.size,
+            width: copy.width,
+            height: copy.height,
+            content_type: ImageFormat::Jpeg.to_mime_type().to_string(),
+            key: format!("{}/{}.jpg", key_prefix, size_name(copy.size)),
+            url: String::new(),
+        });
+        files.push(copy.jpeg);
+    }
+    for (variant, file) in variants.iter_mut().zip(files) {
+        blob_store
+            .put(&variant.key, &variant.content_type, file)
+            .await?;
+        variant.url = blob_store.public_url(&variant.key);
+    }
+
+    let image = CocktailImage {
+        id: image_id,
+        uploaded_at: chrono::Utc::now(),
+        variants,
+    };
+    if let Err(err) = cocktail_repo.set_image(cocktail, Some(&image)).await {
+        delete_files(blob_store, &image).await;
+        return Err(err);
+    }
+    if let Some(previous) = &cocktail.image {
+        delete_files(blob_store, previous).await;
+    }
+
+    Ok(image)
+}
+
+/// Removes image of the cocktail with its files. Returns `false` if the cocktail has no image.
+/// Fails with `CocktailError::VersionConflict` if the cocktail was changed since it was read.
+pub async fn remove<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    blob_store: &dyn BlobStore,
+    cocktail: &Cocktail,
+) -> Result<bool>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let Some(image) = &cocktail.image else {
+        return Ok(false);
+    };
+    cocktail_repo.set_image(cocktail, None).await?;
+    delete_files(blob_store, image).await;
+
+    Ok(true)
+}
+
+/// Removes files of all variants. Failures are only logged, the image is not referenced
+/// by the cocktail anymore, so leftover files are harmless.
+pub async fn delete_files(blob_store: &dyn BlobStore, image: &CocktailImage) {
+    for variant in &image.variants {
+        if let Err(err) = blob_store.delete(&variant.key).await {
+            log::warn!("Failed to delete image file {}: {:?}", variant.key, err);
+        }
+    }
+}
+
+struct ResizedCopy {
+    size: ImageSize,
+    width: u32,
+    height: u32,
+    jpeg: Vec<u8>,
+}
+
+/// Size of the decoded image and JPEG encoded copies for `RESIZED_VARIANTS`.
+fn resize(data: &[u8], format: ImageFormat) -> Result<((u32, u32), Vec<ResizedCopy>)> {
+    let decoded = image::load_from_memory_with_format(data, format)
+        .map_err(|err| ImageError::Corrupted(err.to_string()))?;
+    // JPEG has no transparency
+    let decoded = DynamicImage::ImageRgb8(decoded.to_rgb8());
+
+    let mut resized = vec![];
+    for (size, max_side) in RESIZED_VARIANTS {
+        let copy = if decoded.width().max(decoded.height()) > *max_side {
+            decoded.resize(*max_side, *max_side, FilterType::Lanczos3)
+        } else {
+            decoded.clone()
+        };
+        let mut jpeg = vec![];
+        JpegEncoder::new_with_quality(&mut jpeg, RESIZED_JPEG_QUALITY)
+            .encode_image(&copy)
+            .context("failed to encode resized image")?;
+        resized.push(ResizedCopy {
+            size: *size,
+            width: copy.width(),
+            height: copy.height(),
+            jpeg,
+        });
+    }
+
+    Ok(((decoded.width(), decoded.height()), resized))
+}
+
+fn size_name(size: ImageSize) -> &'static str {
+    match size {
+        ImageSize::Original => "original",
+        ImageSize::Large => "large",
+        ImageSize::Medium => "medium",
+        ImageSize::Thumbnail => "thumbnail",
+    }
+}
@@ -0,0 +1,13 @@
+pub mod bar;
+pub mod images;
+pub mod random;
+pub mod ratings;
+pub mod recommendations;
+pub mod revisions;
+pub mod search;
+pub mod shopping_list;
+pub mod similarity;
+pub mod slugs;
+pub mod tags;
+pub mod trash;
+pub mod validation;
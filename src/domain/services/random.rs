@@ -0,0 +1,32 @@
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+
+use crate::domain::aggregates::cocktail::{Cocktail, CocktailRepo, RandomCocktailFilter};
+
+/// Mixes day number into well distributed seed, so neighbour days get unrelated cocktails.
+fn day_seed(date: &NaiveDate) -> u64 {
+    // splitmix64 finalizer
+    let mut seed = (date.num_days_from_ce() as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    seed = (seed ^ (seed >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    seed = (seed ^ (seed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    seed ^ (seed >> 31)
+}
+
+/// Returns the same cocktail for the whole day while catalogue and constraints are unchanged.
+pub async fn get_cocktail_of_the_day<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    filter: &RandomCocktailFilter,
+    date: &NaiveDate,
+) -> Result<Option<Cocktail>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let count = cocktail_repo.count_by_random_filter(filter).await?;
+    if count == 0 {
+        return Ok(None);
+    }
+
+    cocktail_repo
+        .get_nth_by_random_filter(filter, day_seed(date) % count)
+        .await
+}
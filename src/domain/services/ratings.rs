@@ -0,0 +1,46 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::aggregates::{
+    cocktail::CocktailRepo,
+    rating::{Rating, RatingError, RatingRepo, RatingSummary, MAX_STARS, MIN_STARS},
+};
+
+/// Saves user rating for cocktail and refreshes rating summary stored in cocktail.
+pub async fn rate_cocktail<TRatingRepo, TCocktailRepo>(
+    rating_repo: &TRatingRepo,
+    cocktail_repo: &TCocktailRepo,
+    user_id: &Uuid,
+    cocktail_id: &Uuid,
+    stars: u8,
+    review: Option<String>,
+) -> Result<RatingSummary>
+where
+    TRatingRepo: RatingRepo + Sync,
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    if !(MIN_STARS..=MAX_STARS).contains(&stars) {
+        return Err(RatingError::InvalidStars(stars).into());
+    }
+    if cocktail_repo.get_by_id(cocktail_id).await?.is_none() {
+        return Err(RatingError::CocktailNotFound(*cocktail_id).into());
+    }
+    let review = review.filter(|review| !review.trim().is_empty());
+
+    let rating = match rating_repo
+        .get_by_user_and_cocktail(user_id, cocktail_id)
+        .await?
+    {
+        Some(mut rating) => {
+            rating.change(stars, review);
+            rating
+        }
+        None => Rating::new(*user_id, *cocktail_id, stars, review),
+    };
+    rating_repo.upsert(&rating).await?;
+
+    let summary = rating_repo.get_summary(cocktail_id).await?;
+    cocktail_repo.update_rating(cocktail_id, &summary).await?;
+
+    Ok(summary)
+}
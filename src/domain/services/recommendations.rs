@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::{
+        cocktail::{CocktailFilter, CocktailRepo},
+        rating::RatingRepo,
+        user::User,
+    },
+    services::similarity::{self, MAX_SIMILAR_COCKTAILS},
+    Pagination,
+};
+
+/// Only the most recent favorites are used, similar lists are computed for each of them.
+const MAX_FAVORITE_SEEDS: usize = 20;
+const FAVORITE_WEIGHT: f64 = 1.0;
+const VIEWED_WEIGHT: f64 = 0.3;
+/// Only ratings from this number of stars are treated as liking the cocktail.
+const LIKED_STARS: u8 = 4;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RecommendationReason {
+    Favorite,
+    Rated,
+    Viewed,
+}
+
+#[derive(Clone, Debug)]
+pub struct Recommendation {
+    pub id: Uuid,
+    pub russian_name: String,
+    pub score: f64,
+    pub reason: RecommendationReason,
+    /// Cocktail user likes which contributed to the recommendation the most.
+    pub because_of_id: Uuid,
+    pub because_of_name: String,
+}
+
+impl Recommendation {
+    pub fn explanation(&self) -> String {
+        match self.reason {
+            RecommendationReason::Favorite => {
+                format!("Потому что вам нравится {}", self.because_of_name)
+            }
+            RecommendationReason::Rated => {
+                format!("Потому что вы высоко оценили {}", self.because_of_name)
+            }
+            RecommendationReason::Viewed => {
+                format!("Потому что вы смотрели {}", self.because_of_name)
+            }
+        }
+    }
+}
+
+struct Seed {
+    cocktail_id: Uuid,
+    weight: f64,
+    reason: RecommendationReason,
+}
+
+/// Recommends cocktails similar to favorite, highly rated and recently viewed ones.
+/// Favorite and rated cocktails are never recommended.
+pub async fn get_recommendations<TCocktailRepo, TRatingRepo>(
+    cocktail_repo: &TCocktailRepo,
+    rating_repo: &TRatingRepo,
+    user: &User,
+    limit: usize,
+) -> Result<Vec<Recommendation>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+{
+    let ratings = rating_repo.get_by_user(&user.id).await?;
+    let mut excluded: HashSet<Uuid> = user
+        .favorite_cocktails
+        .iter()
+        .map(|favorite| favorite.cocktail_id)
+        .collect();
+    excluded.extend(ratings.iter().map(|rating| rating.cocktail_id));
+
+    let mut seeds: Vec<Seed> = user
+        .get_favorite_ids_by_recency()
+        .into_iter()
+        .take(MAX_FAVORITE_SEEDS)
+        .map(|cocktail_id| Seed {
+            cocktail_id,
+            weight: FAVORITE_WEIGHT,
+            reason: RecommendationReason::Favorite,
+        })
+        .collect();
+    seeds.extend(
+        ratings
+            .iter()
+            .filter(|rating| rating.stars >= LIKED_STARS && !user.is_favorite(&rating.cocktail_id))
+            .map(|rating| Seed {
+                cocktail_id: rating.cocktail_id,
+                weight: FAVORITE_WEIGHT * rating.stars as f64 / 5.0,
+                reason: RecommendationReason::Rated,
+            }),
+    );
+    seeds.extend(
+        user.viewed_cocktails
+            .iter()
+            .filter(|viewed| !excluded.contains(&viewed.cocktail_id))
+            .map(|viewed| Seed {
+                cocktail_id: viewed.cocktail_id,
+                weight: VIEWED_WEIGHT,
+                reason: RecommendationReason::Viewed,
+            }),
+    );
+
+    // candidate id -> (name, total score, best contribution, index of the best seed)
+    let mut candidates: HashMap<Uuid, (String, f64, f64, usize)> = HashMap::new();
+    for (seed_index, seed) in seeds.iter().enumerate() {
+        let Some(similar) = similarity::get_similar_cocktails(
+            cocktail_repo,
+            &seed.cocktail_id,
+            MAX_SIMILAR_COCKTAILS,
+        )
+        .await?
+        else {
+            continue;
+        };
+        for similar in similar
+            .into_iter()
+            .filter(|similar| !excluded.contains(&similar.id))
+        {
+            let contribution = seed.weight * similar.score;
+            let candidate = candidates
+                .entry(similar.id)
+                .or_insert_with(|| (similar.russian_name.clone(), 0.0, 0.0, seed_index));
+            candidate.1 += contribution;
+            if contribution > candidate.2 {
+                candidate.2 = contribution;
+                candidate.3 = seed_index;
+            }
+        }
+    }
+
+    let mut ranked: Vec<(Uuid, String, f64, usize)> = candidates
+        .into_iter()
+        .map(|(id, (name, score, _, seed_index))| (id, name, score, seed_index))
+        .collect();
+    ranked.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+    ranked.truncate(limit);
+
+    let seed_ids: Vec<Uuid> = ranked
+        .iter()
+        .map(|(_, _, _, seed_index)| seeds[*seed_index].cocktail_id)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let seed_names: HashMap<Uuid, String> = if seed_ids.is_empty() {
+        HashMap::new()
+    } else {
+        cocktail_repo
+            .get_names(&CocktailFilter {
+                ids: Some(seed_ids.clone()),
+                names: None,
+                russian_names: None,
+                tags: None,
+                countries: None,
+                sort: None,
+                pagination: Pagination {
+                    page: 0,
+                    items_per_page: seed_ids.len() as u64,
+                },
+            })
+            .await?
+            .items
+            .into_iter()
+            .map(|cocktail| (cocktail.id, cocktail.russian_name))
+            .collect()
+    };
+
+    Ok(ranked
+        .into_iter()
+        .map(|(id, russian_name, score, seed_index)| {
+            let seed = &seeds[seed_index];
+            Recommendation {
+                id,
+                russian_name,
+                score,
+                reason: seed.reason.clone(),
+                because_of_id: seed.cocktail_id,
+                because_of_name: seed_names
+                    .get(&seed.cocktail_id)
+                    .cloned()
+                    .unwrap_or_default(),
+            }
+        })
+        .collect())
+}
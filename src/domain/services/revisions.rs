@@ -0,0 +1,192 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::{
+        cocktail::{Cocktail, CocktailRepo},
+        revision::{CocktailRevision, RevisionAction, RevisionError, RevisionRepo},
+    },
+    services::{search, similarity, slugs, trash},
+};
+
+/// Fields changed by the storage itself, by other aggregates or derived from names,
+/// they are not part of the diff.
+const BOOKKEEPING_FIELDS: &[&str] = &[
+    "version",
+    "updated_at",
+    "deleted_at",
+    "rating",
+    "slug",
+    "previous_slugs",
+    "image",
+];
+
+/// How many times a revision number is taken again when a concurrent change took it first.
+const MAX_RECORD_ATTEMPTS: usize = 5;
+
+/// Saves revision of a change, `before` is `None` for creation and `after` is `None` for deletion.
+/// Fails with `RevisionError::NoState` if both are `None`.
+pub async fn record<TRevisionRepo>(
+    revision_repo: &TRevisionRepo,
+    action: RevisionAction,
+    before: Option<&Cocktail>,
+    after: Option<&Cocktail>,
+    author: Option<String>,
+) -> Result<CocktailRevision>
+where
+    TRevisionRepo: RevisionRepo + Sync,
+{
+    let snapshot = after.or(before).ok_or(RevisionError::NoState)?.clone();
+    let mut revision = CocktailRevision {
+        id: Uuid::new_v4(),
+        cocktail_id: snapshot.id,
+        number: 0,
+        action,
+        author: author.filter(|author| !author.trim().is_empty()),
+        created_at: chrono::Utc::now(),
+        diff: diff(before, after),
+        snapshot,
+    };
+    let mut attempt = 1;
+    loop {
+        revision.number = revision_repo
+            .get_latest(&revision.cocktail_id)
+            .await?
+            .map_or(1, |latest| latest.number + 1);
+        match revision_repo.create(&revision).await {
+            Ok(()) => return Ok(revision),
+            Err(err)
+                if attempt < MAX_RECORD_ATTEMPTS
+                    && matches!(
+                        err.downcast_ref::<RevisionError>(),
+                        Some(RevisionError::NumberTaken { .. })
+                    ) =>
+            {
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// JSON Patch turning the first cocktail state into the second one, `None` is a missing cocktail.
+pub fn diff(from: Option<&Cocktail>, to: Option<&Cocktail>) -> json_patch::Patch {
+    json_patch::diff(&content(from), &content(to))
+}
+
+/// JSON Patch between two recorded revisions of the same cocktail.
+pub async fn diff_revisions<TRevisionRepo>(
+    revision_repo: &TRevisionRepo,
+    cocktail_id: &Uuid,
+    from: u64,
+    to: u64,
+) -> Result<json_patch::Patch>
+where
+    TRevisionRepo: RevisionRepo + Sync,
+{
+    let from = get_revision(revision_repo, cocktail_id, from).await?;
+    let to = get_revision(revision_repo, cocktail_id, to).await?;
+
+    Ok(diff(Some(&from.snapshot), Some(&to.snapshot)))
+}
+
+/// Brings cocktail back to the state of the revision. Cocktail in trash is taken out of it
+/// with its own revision first, purged cocktail is created again.
+/// `current` is the stored cocktail, in trash or not, its version is checked on update.
+/// Every stored change is recorded before success is returned.
+pub async fn restore<TCocktailRepo, TRevisionRepo>(
+    cocktail_repo: &TCocktailRepo,
+    revision_repo: &TRevisionRepo,
+    cocktail_id: &Uuid,
+    number: u64,
+    current: Option<&Cocktail>,
+    author: Option<String>,
+) -> Result<Cocktail>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+    TRevisionRepo: RevisionRepo + Sync,
+{
+    let revision = get_revision(revision_repo, cocktail_id, number).await?;
+    let mut restored = revision.snapshot;
+    restored.updated_at = Some(chrono::Utc::now());
+    restored.deleted_at = None;
+    // slug is chosen before anything is stored, taking out of trash keeps it as is
+    if current.is_some() {
+        slugs::assign(cocktail_repo, &mut restored, current).await?;
+    }
+    // cocktail in trash is taken out first and then changed as a stored one,
+    // so a failed change still leaves the taking out recorded
+    let trashed = match current {
+        Some(current) if current.deleted_at.is_some() => {
+            Some(trash::restore(cocktail_repo, revision_repo, current, author.clone()).await?)
+        }
+        _ => None,
+    };
+    let before = trashed.as_ref().or(current);
+    match before {
+        Some(current) => {
+            restored.rating = current.rating.clone();
+            restored.image = current.image.clone();
+            restored.version = current.version;
+            cocktail_repo.update(&restored).await?;
+            restored.version += 1;
+        }
+        None => {
+            // image files are removed together with the purged cocktail
+            restored.image = None;
+            // versions keep growing, so tags issued before deletion never match again
+            let latest_version = revision_repo
+                .get_latest(cocktail_id)
+                .await?
+                .map_or(0, |latest| latest.snapshot.version);
+            restored.version = latest_version + 1;
+            slugs::create(cocktail_repo, &mut restored).await?;
+        }
+    }
+    similarity::invalidate_cache();
+    search::invalidate_cache();
+    record(
+        revision_repo,
+        RevisionAction::Restore,
+        before,
+        Some(&restored),
+        author,
+    )
+    .await?;
+
+    Ok(restored)
+}
+
+async fn get_revision<TRevisionRepo>(
+    revision_repo: &TRevisionRepo,
+    cocktail_id: &Uuid,
+    number: u64,
+) -> Result<CocktailRevision>
+where
+    TRevisionRepo: RevisionRepo + Sync,
+{
+    revision_repo
+        .get(cocktail_id, number)
+        .await?
+        .ok_or_else(|| {
+            RevisionError::NotFound {
+                cocktail_id: *cocktail_id,
+                number,
+            }
+            .into()
+        })
+}
+
+fn content(cocktail: Option<&Cocktail>) -> serde_json::Value {
+    let Some(cocktail) = cocktail else {
+        return serde_json::Value::Null;
+    };
+    let mut value = serde_json::to_value(cocktail).unwrap();
+    if let Some(fields) = value.as_object_mut() {
+        for field in BOOKKEEPING_FIELDS {
+            fields.remove(*field);
+        }
+    }
+
+    value
+}
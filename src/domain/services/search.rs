@@ -0,0 +1,634 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::cocktail::{Cocktail, CocktailFilter, CocktailRepo, CocktailsPaged},
+    locale::ENGLISH_LOCALE,
+    transliteration, Pagination,
+};
+
+/// Characters of context kept before the first match in a snippet.
+const SNIPPET_CONTEXT_BEFORE: usize = 40;
+/// Maximum length of a snippet in characters.
+const SNIPPET_LENGTH: usize = 120;
+/// Maximum number of snippets returned for one cocktail.
+const MAX_SNIPPETS: usize = 3;
+
+const EXACT_SCORE: f64 = 1.0;
+const PREFIX_SCORE: f64 = 0.9;
+const WORD_PREFIX_SCORE: f64 = 0.8;
+const SUBSTRING_SCORE: f64 = 0.7;
+/// Score of a fuzzy match without typos, every edit lowers it by `EDIT_PENALTY`.
+const FUZZY_SCORE: f64 = 0.6;
+const EDIT_PENALTY: f64 = 0.15;
+/// Bonus for matches in the original alphabet, so "негрони" ranks "Негрони" above "Негрини".
+const SAME_ALPHABET_BONUS: f64 = 0.05;
+
+/// Lowercases text, replaces "ё" with "е" and collapses punctuation and spaces into single spaces.
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .replace('ё', "е")
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Latin letters and combinations which sound the same are reduced to one spelling,
+/// so both "whisky" and "виски" end up as "viski".
+const LATIN_SPELLINGS: &[(&str, &str)] = &[
+    ("shch", "sch"),
+    ("sch", "sch"),
+    ("ck", "k"),
+    ("ph", "f"),
+    ("kh", "h"),
+    ("qu", "k"),
+    ("wh", "v"),
+    ("w", "v"),
+    ("j", "h"),
+    ("q", "k"),
+    ("x", "ks"),
+    ("c", "k"),
+    ("y", "i"),
+];
+
+/// Transliterates normalised text into a phonetic Latin form used for comparison.
+pub fn transliterate(text: &str) -> String {
+    let mut latin = String::with_capacity(text.len());
+    for c in text.chars() {
+        match transliteration::to_latin(c) {
+            Some(replacement) => latin.push_str(replacement),
+            None => latin.push(c),
+        }
+    }
+
+    let mut result = String::with_capacity(latin.len());
+    let mut rest = latin.as_str();
+    'outer: while let Some(c) = rest.chars().next() {
+        for (from, to) in LATIN_SPELLINGS.iter() {
+            if let Some(tail) = rest.strip_prefix(from) {
+                // keep "ch", "sh", "zh", "ts" and "ya"/"yu" produced by transliteration
+                let keep_digraph = match *from {
+                    "c" => tail.starts_with('h'),
+                    "y" => tail.starts_with('a') || tail.starts_with('u'),
+                    _ => false,
+                };
+                if keep_digraph {
+                    break;
+                }
+                result.push_str(to);
+                rest = tail;
+                continue 'outer;
+            }
+        }
+        result.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    result
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions
+/// and transpositions of adjacent characters cost one edit each.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_previous = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(previous_previous[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut previous_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Number of typos tolerated for a query of the given length.
+fn max_edits(query_len: usize) -> usize {
+    match query_len {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    }
+}
+
+/// Relevance of a name to a query from 0 to 1, `None` if the name does not match.
+/// Both arguments must be in the same form (normalised or transliterated).
+fn match_score(query: &str, name: &str) -> Option<f64> {
+    if query.is_empty() || name.is_empty() {
+        return None;
+    }
+    if name == query {
+        return Some(EXACT_SCORE);
+    }
+    if name.starts_with(query) {
+        return Some(PREFIX_SCORE);
+    }
+    if name.split(' ').any(|word| word.starts_with(query)) {
+        return Some(WORD_PREFIX_SCORE);
+    }
+    if name.contains(query) {
+        return Some(SUBSTRING_SCORE);
+    }
+
+    let query_len = query.chars().count();
+    let allowed = max_edits(query_len);
+    if allowed == 0 {
+        return None;
+    }
+    // compare with the whole name, each word and each beginning of a word as long as the query,
+    // the latter lets an unfinished word with a typo match
+    let words: Vec<&str> = name.split(' ').collect();
+    let mut candidates = vec![name.to_string()];
+    for (index, word) in words.iter().enumerate() {
+        candidates.push(word.to_string());
+        candidates.push(word.chars().take(query_len).collect());
+        candidates.push(words[index..].join(" ").chars().take(query_len).collect());
+    }
+    candidates
+        .iter()
+        .map(|candidate| edit_distance(query, candidate))
+        .filter(|distance| *distance <= allowed)
+        .min()
+        .map(|distance| FUZZY_SCORE - EDIT_PENALTY * distance as f64)
+}
+
+/// Russian and english names of a cocktail, normalised and transliterated.
+fn search_names(cocktail: &Cocktail) -> Vec<(String, String)> {
+    [
+        Some(cocktail.russian_name.as_str()),
+        cocktail.name.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|name| {
+        let name = normalize(name);
+        let latin = transliterate(&name);
+        (name, latin)
+    })
+    .collect()
+}
+
+/// Relevance of names from `search_names` to a normalised query and its transliteration.
+fn names_relevance(query: &str, query_latin: &str, names: &[(String, String)]) -> Option<f64> {
+    names
+        .iter()
+        .filter_map(|(name, latin)| {
+            let same_alphabet = match_score(query, name).map(|score| score + SAME_ALPHABET_BONUS);
+            let transliterated = match_score(query_latin, latin);
+            match (same_alphabet, transliterated) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                (a, b) => a.or(b),
+            }
+        })
+        .max_by(|a, b| a.total_cmp(b))
+}
+
+/// Cocktail of the name search with its names prepared for comparison.
+#[derive(Debug)]
+struct SearchEntry {
+    cocktail: Cocktail,
+    names: Vec<(String, String)>,
+}
+
+impl SearchEntry {
+    fn new(cocktail: Cocktail) -> Self {
+        let names = search_names(&cocktail);
+        SearchEntry { cocktail, names }
+    }
+
+    /// Applies ids, tags and countries of the filter the same way `CocktailRepo::get_by_filter`
+    /// does.
+    fn matches(&self, filter: &CocktailFilter) -> bool {
+        let cocktail = &self.cocktail;
+        filter
+            .ids
+            .as_ref()
+            .is_none_or(|ids| ids.contains(&cocktail.id))
+            && filter.tags.as_ref().is_none_or(|tags| {
+                cocktail
+                    .tags
+                    .iter()
+                    .flatten()
+                    .any(|tag| tags.contains(&tag.name))
+            })
+            && filter.countries.as_ref().is_none_or(|countries| {
+                cocktail
+                    .country_of_origin
+                    .as_ref()
+                    .is_some_and(|country| countries.contains(country))
+            })
+    }
+}
+
+#[derive(Default)]
+struct SearchCache {
+    /// Incremented by every invalidation, names loaded before it are not cached.
+    generation: u64,
+    entries: Option<Arc<Vec<SearchEntry>>>,
+}
+
+fn cache() -> &'static Mutex<SearchCache> {
+    static CACHE: OnceLock<Mutex<SearchCache>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// Drops cached names. Must be called when any cocktail is created, changed or deleted,
+/// the same as `similarity::invalidate_cache`.
+pub fn invalidate_cache() {
+    let mut cache = cache().lock().unwrap();
+    cache.generation += 1;
+    cache.entries = None;
+}
+
+/// Names of all cocktails not in trash, loaded once and kept until the cache is invalidated.
+async fn search_entries<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+) -> Result<Arc<Vec<SearchEntry>>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let generation = {
+        let cache = cache().lock().unwrap();
+        if let Some(entries) = &cache.entries {
+            return Ok(entries.clone());
+        }
+        cache.generation
+    };
+
+    let entries: Arc<Vec<SearchEntry>> = Arc::new(
+        cocktail_repo
+            .get_names_for_search()
+            .await?
+            .into_iter()
+            .map(SearchEntry::new)
+            .collect(),
+    );
+    let mut cache = cache().lock().unwrap();
+    if cache.generation == generation {
+        cache.entries = Some(entries.clone());
+    }
+
+    Ok(entries)
+}
+
+/// Ids of cocktails matching the query by name, the most relevant first.
+/// Ids, tags and countries of the filter are applied, other fields are ignored.
+pub async fn search_cocktail_ids<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    query: &str,
+    filter: &CocktailFilter,
+) -> Result<Vec<Uuid>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let query = normalize(query);
+    let query_latin = transliterate(&query);
+    let entries = search_entries(cocktail_repo).await?;
+    let mut ranked: Vec<(f64, &Cocktail)> = entries
+        .iter()
+        .filter(|entry| entry.matches(filter))
+        .filter_map(|entry| {
+            names_relevance(&query, &query_latin, &entry.names)
+                .map(|score| (score, &entry.cocktail))
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.0.total_cmp(&a.0)
+            .then_with(|| a.1.russian_name.cmp(&b.1.russian_name))
+    });
+
+    Ok(ranked
+        .into_iter()
+        .map(|(_, cocktail)| cocktail.id)
+        .collect())
+}
+
+/// Searches cocktails by russian and english names, tolerating typos, "ё" and names typed
+/// in the other alphabet. Results are ordered by relevance, then by name.
+/// Ids, tags, countries and pagination of the filter are applied, names and sort are ignored.
+pub async fn search_cocktails<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    query: &str,
+    filter: &CocktailFilter,
+) -> Result<CocktailsPaged>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let ranked = search_cocktail_ids(cocktail_repo, query, filter).await?;
+
+    let total_count = ranked.len() as u64;
+    let pagination = &filter.pagination;
+    let page_ids: Vec<Uuid> = ranked
+        .iter()
+        .skip((pagination.page * pagination.items_per_page) as usize)
+        .take(pagination.items_per_page as usize)
+        .cloned()
+        .collect();
+    if page_ids.is_empty() {
+        return Ok(CocktailsPaged {
+            items: vec![],
+            total_count,
+        });
+    }
+
+    let mut cocktails: HashMap<Uuid, Cocktail> = cocktail_repo
+        .get_by_filter(&CocktailFilter {
+            ids: Some(page_ids.clone()),
+            names: None,
+            russian_names: None,
+            tags: None,
+            countries: None,
+            sort: None,
+            pagination: Pagination {
+                page: 0,
+                items_per_page: page_ids.len() as u64,
+            },
+        })
+        .await?
+        .items
+        .into_iter()
+        .map(|cocktail| (cocktail.id, cocktail))
+        .collect();
+
+    Ok(CocktailsPaged {
+        items: page_ids
+            .iter()
+            .filter_map(|id| cocktails.remove(id))
+            .collect(),
+        total_count,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnippetField {
+    Name,
+    Ingredients,
+    Tags,
+    History,
+    Recipe,
+}
+
+/// Fragment of a cocktail field containing query words.
+#[derive(Clone, Debug)]
+pub struct SearchSnippet {
+    pub field: SnippetField,
+    pub text: String,
+    /// Matched words as ranges of character (not byte) offsets in `text`.
+    pub highlights: Vec<(usize, usize)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TextSearchHit {
+    pub cocktail: Cocktail,
+    pub score: f64,
+    pub snippets: Vec<SearchSnippet>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TextSearchPaged {
+    pub items: Vec<TextSearchHit>,
+    pub total_count: u64,
+}
+
+/// Word beginnings used to find matches in text. Mongo matches words by stem,
+/// so the ending of each query word is cut off to catch other forms of the word.
+fn query_stems(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .filter(|word| !word.starts_with('-'))
+        .flat_map(|word| {
+            normalize(word)
+                .split(' ')
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .filter(|word| word.chars().count() > 1)
+        .map(|word| {
+            let len = word.chars().count();
+            let stem_len = if len > 5 { len - 2 } else { len };
+            word.chars().take(stem_len).collect()
+        })
+        .collect()
+}
+
+/// Builds snippet around the first matched word, `None` if nothing in the text matches.
+fn make_snippet(field: SnippetField, text: &str, stems: &[String]) -> Option<SearchSnippet> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = vec![];
+    let mut word_start = None;
+    for (index, c) in chars.iter().chain([' '].iter()).enumerate() {
+        match (c.is_alphanumeric(), word_start) {
+            (true, None) => word_start = Some(index),
+            (false, Some(start)) => {
+                words.push((start, index));
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+    let matched: Vec<(usize, usize)> = words
+        .into_iter()
+        .filter(|(start, end)| {
+            let word = normalize(&chars[*start..*end].iter().collect::<String>());
+            stems.iter().any(|stem| word.starts_with(stem.as_str()))
+        })
+        .collect();
+    let (first_start, first_end) = *matched.first()?;
+
+    let mut start = first_start.saturating_sub(SNIPPET_CONTEXT_BEFORE);
+    while start > 0 && start < first_start && !chars[start - 1].is_whitespace() {
+        start += 1;
+    }
+    let mut end = (start + SNIPPET_LENGTH).max(first_end).min(chars.len());
+    while end < chars.len() && end > first_end && !chars[end].is_whitespace() {
+        end -= 1;
+    }
+
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < chars.len() { "…" } else { "" };
+    let offset = prefix.chars().count();
+    let highlights = matched
+        .into_iter()
+        .filter(|(word_start, word_end)| *word_start >= start && *word_end <= end)
+        .map(|(word_start, word_end)| (word_start - start + offset, word_end - start + offset))
+        .collect();
+    Some(SearchSnippet {
+        field,
+        text: format!(
+            "{}{}{}",
+            prefix,
+            chars[start..end].iter().collect::<String>(),
+            suffix
+        ),
+        highlights,
+    })
+}
+
+fn make_snippets(cocktail: &Cocktail, stems: &[String]) -> Vec<SearchSnippet> {
+    let names = [
+        Some(cocktail.russian_name.as_str()),
+        cocktail.name.as_deref(),
+        cocktail.name_translations.get(ENGLISH_LOCALE),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" / ");
+    let ingredients = cocktail
+        .composition_elements
+        .iter()
+        .flatten()
+        .flat_map(|element| {
+            [
+                Some(element.name.as_str()),
+                element.display_names.get(ENGLISH_LOCALE),
+            ]
+        })
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+    let tags = cocktail
+        .tags
+        .iter()
+        .flatten()
+        .map(|tag| tag.name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut fields = vec![
+        (SnippetField::Name, names),
+        (SnippetField::Ingredients, ingredients),
+        (SnippetField::Tags, tags),
+        (
+            SnippetField::History,
+            cocktail.history.clone().unwrap_or_default(),
+        ),
+    ];
+    // english texts are searched by their own index, see `CocktailRepo::text_search`
+    fields.extend(
+        cocktail
+            .history_translations
+            .get(ENGLISH_LOCALE)
+            .map(|history| (SnippetField::History, history.to_string())),
+    );
+    fields.extend(cocktail.recipe.iter().flat_map(|recipe| {
+        let translated = recipe
+            .step_translations
+            .iter()
+            .filter_map(|translations| translations.get(ENGLISH_LOCALE));
+        recipe
+            .steps
+            .iter()
+            .map(String::as_str)
+            .chain(translated)
+            .map(|step| (SnippetField::Recipe, step.to_string()))
+    }));
+
+    fields
+        .iter()
+        .filter_map(|(field, text)| make_snippet(*field, text, stems))
+        .take(MAX_SNIPPETS)
+        .collect()
+}
+
+/// Searches cocktails by any words of their names, history, recipe and ingredients
+/// and returns fragments with matched words.
+pub async fn text_search<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    query: &str,
+    pagination: &Pagination,
+) -> Result<TextSearchPaged>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    if query.trim().is_empty() {
+        return Ok(TextSearchPaged {
+            items: vec![],
+            total_count: 0,
+        });
+    }
+    let found = cocktail_repo.text_search(query, pagination).await?;
+    let stems = query_stems(query);
+
+    Ok(TextSearchPaged {
+        items: found
+            .items
+            .into_iter()
+            .map(|item| {
+                let snippets = make_snippets(&item.cocktail, &stems);
+                TextSearchHit {
+                    cocktail: item.cocktail,
+                    score: item.score,
+                    snippets,
+                }
+            })
+            .collect(),
+        total_count: found.total_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_lowercases_and_collapses_punctuation() {
+        assert_eq!(normalize("  Old-Fashioned,  Sour! "), "old fashioned sour");
+    }
+
+    #[test]
+    fn normalize_replaces_yo() {
+        assert_eq!(normalize("Ёрш"), "ерш");
+    }
+
+    #[test]
+    fn transliterate_brings_both_alphabets_to_one_spelling() {
+        assert_eq!(transliterate("виски"), "viski");
+        assert_eq!(transliterate("whisky"), "viski");
+        assert_eq!(transliterate("негрони"), transliterate("negroni"));
+        assert_eq!(transliterate("дайкири"), transliterate("daiquiri"));
+    }
+
+    #[test]
+    fn transliterate_keeps_digraphs_of_transliteration() {
+        assert_eq!(transliterate("чача"), "chacha");
+        assert_eq!(transliterate("шот"), "shot");
+        assert_eq!(transliterate("борщ"), transliterate("borsch"));
+    }
+
+    #[test]
+    fn transliterate_keeps_digits_and_spaces() {
+        assert_eq!(transliterate("b 52"), "b 52");
+    }
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("negroni", "negroni"), 0);
+        assert_eq!(edit_distance("", "rum"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn edit_distance_counts_transposition_as_one_edit() {
+        assert_eq!(edit_distance("negorni", "negroni"), 1);
+    }
+
+    #[test]
+    fn edit_distance_compares_characters_not_bytes() {
+        assert_eq!(edit_distance("негрони", "нигрони"), 1);
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::cocktail::{CocktailFilter, CocktailRepo},
+    units::normalize_amount,
+    Pagination,
+};
+
+/// Cocktail and the number of servings to buy ingredients for.
+#[derive(Clone, Debug)]
+pub struct CocktailServings {
+    pub cocktail_id: Uuid,
+    pub servings: u32,
+}
+
+/// Ingredient user already has. Without amount the ingredient is removed from the list completely.
+#[derive(Clone, Debug)]
+pub struct OwnedIngredient {
+    pub name: String,
+    pub amount: Option<f64>,
+    pub unit: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ShoppingListItem {
+    pub name: String,
+    pub amount: f64,
+    pub unit: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct ShoppingList {
+    pub items: Vec<ShoppingListItem>,
+    /// Ingredients removed from the list because user already has them.
+    pub owned: Vec<String>,
+    pub not_found_cocktails: Vec<Uuid>,
+}
+
+/// Amounts of ingredients keyed by lowercase name and normalised unit, the value keeps
+/// the first seen spelling of the name.
+type Totals = HashMap<(String, String), (String, f64)>;
+
+fn add_amount(totals: &mut Totals, name: &str, amount: f64, unit: &str) {
+    let (amount, unit) = normalize_amount(amount, unit);
+    let entry = totals
+        .entry((name.trim().to_lowercase(), unit))
+        .or_insert_with(|| (name.trim().to_string(), 0.0));
+    entry.1 += amount;
+}
+
+/// Subtracts what user already has and returns names of ingredients not needed anymore.
+/// Amount is subtracted only from the same ingredient measured in a compatible unit.
+fn subtract_owned(totals: &mut Totals, owned: &[OwnedIngredient]) -> Vec<String> {
+    let mut owned_names = vec![];
+    for owned_ingredient in owned.iter() {
+        let owned_name = owned_ingredient.name.trim().to_lowercase();
+        match owned_ingredient.amount {
+            Some(owned_amount) => {
+                let (owned_amount, owned_unit) = normalize_amount(
+                    owned_amount,
+                    owned_ingredient.unit.as_deref().unwrap_or_default(),
+                );
+                if let Some(entry) = totals.get_mut(&(owned_name.clone(), owned_unit)) {
+                    entry.1 -= owned_amount;
+                    if entry.1 <= 0.0 {
+                        owned_names.push(entry.0.clone());
+                    }
+                }
+            }
+            None => totals
+                .iter_mut()
+                .filter(|((name, _), _)| name == &owned_name)
+                .for_each(|(_, entry)| {
+                    owned_names.push(entry.0.clone());
+                    entry.1 = 0.0;
+                }),
+        }
+    }
+    owned_names.sort();
+    owned_names.dedup();
+    owned_names
+}
+
+/// Items left to buy, ordered by name.
+fn shopping_items(totals: Totals) -> Vec<ShoppingListItem> {
+    let mut items: Vec<ShoppingListItem> = totals
+        .into_iter()
+        .filter(|(_, (_, amount))| *amount > 0.0)
+        .map(|((_, unit), (name, amount))| ShoppingListItem { name, amount, unit })
+        .collect();
+    items.sort_by(|a, b| {
+        a.name
+            .to_lowercase()
+            .cmp(&b.name.to_lowercase())
+            .then_with(|| a.unit.cmp(&b.unit))
+    });
+    items
+}
+
+/// Collects ingredients of cocktails into one list summing amounts with compatible units.
+pub async fn build_shopping_list<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    servings: &[CocktailServings],
+    owned: &[OwnedIngredient],
+) -> Result<ShoppingList>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let mut servings_by_id: HashMap<Uuid, u32> = HashMap::new();
+    for item in servings.iter().filter(|item| item.servings > 0) {
+        *servings_by_id.entry(item.cocktail_id).or_default() += item.servings;
+    }
+    if servings_by_id.is_empty() {
+        return Ok(ShoppingList {
+            items: vec![],
+            owned: vec![],
+            not_found_cocktails: vec![],
+        });
+    }
+
+    let cocktails = cocktail_repo
+        .get_by_filter(&CocktailFilter {
+            ids: Some(servings_by_id.keys().cloned().collect()),
+            names: None,
+            russian_names: None,
+            tags: None,
+            countries: None,
+            sort: None,
+            pagination: Pagination {
+                page: 0,
+                items_per_page: servings_by_id.len() as u64,
+            },
+        })
+        .await?
+        .items;
+
+    let mut not_found_cocktails: Vec<Uuid> = servings_by_id
+        .keys()
+        .filter(|id| !cocktails.iter().any(|cocktail| &cocktail.id == *id))
+        .cloned()
+        .collect();
+    not_found_cocktails.sort();
+
+    let mut totals = Totals::new();
+    for cocktail in cocktails.iter() {
+        let cocktail_servings = servings_by_id[&cocktail.id] as f64;
+        for element in cocktail.composition_elements.iter().flatten() {
+            add_amount(
+                &mut totals,
+                &element.name,
+                element.count as f64 * cocktail_servings,
+                &element.unit,
+            );
+        }
+    }
+    let owned_names = subtract_owned(&mut totals, owned);
+
+    Ok(ShoppingList {
+        items: shopping_items(totals),
+        owned: owned_names,
+        not_found_cocktails,
+    })
+}
+
+/// Maximum number of cocktails taken from a tag into one shopping list.
+pub const MAX_SHOPPING_LIST_COCKTAILS: u64 = 100;
+
+/// Returns ids of cocktails marked with the tag, limited by `MAX_SHOPPING_LIST_COCKTAILS`.
+pub async fn get_cocktail_ids_by_tag<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    tag: &str,
+) -> Result<Vec<Uuid>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let cocktails = cocktail_repo
+        .get_names(&CocktailFilter {
+            ids: None,
+            names: None,
+            russian_names: None,
+            tags: Some(vec![tag.to_string()]),
+            countries: None,
+            sort: None,
+            pagination: Pagination {
+                page: 0,
+                items_per_page: MAX_SHOPPING_LIST_COCKTAILS,
+            },
+        })
+        .await?;
+
+    Ok(cocktails.items.iter().map(|cocktail| cocktail.id).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(name: &str, amount: Option<f64>, unit: Option<&str>) -> OwnedIngredient {
+        OwnedIngredient {
+            name: name.to_string(),
+            amount,
+            unit: unit.map(String::from),
+        }
+    }
+
+    fn amounts(totals: Totals) -> Vec<(String, f64, String)> {
+        shopping_items(totals)
+            .into_iter()
+            .map(|item| (item.name, item.amount, item.unit))
+            .collect()
+    }
+
+    #[test]
+    fn add_amount_sums_compatible_units_and_ignores_case() {
+        let mut totals = Totals::new();
+        add_amount(&mut totals, "Джин", 50.0, "мл");
+        add_amount(&mut totals, "джин ", 2.0, "cl");
+        add_amount(&mut totals, "Лайм", 1.0, "шт");
+        assert_eq!(
+            amounts(totals),
+            vec![
+                ("Джин".to_string(), 70.0, "мл".to_string()),
+                ("Лайм".to_string(), 1.0, "шт".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn subtract_owned_reduces_amount_in_compatible_units() {
+        let mut totals = Totals::new();
+        add_amount(&mut totals, "Джин", 100.0, "мл");
+        let owned_names = subtract_owned(&mut totals, &[owned("джин", Some(4.0), Some("cl"))]);
+        assert!(owned_names.is_empty());
+        assert_eq!(
+            amounts(totals),
+            vec![("Джин".to_string(), 60.0, "мл".to_string())]
+        );
+    }
+
+    #[test]
+    fn subtract_owned_removes_ingredient_when_enough_is_owned() {
+        let mut totals = Totals::new();
+        add_amount(&mut totals, "Джин", 100.0, "мл");
+        let owned_names = subtract_owned(&mut totals, &[owned("Джин", Some(1.0), Some("л"))]);
+        assert_eq!(owned_names, vec!["Джин".to_string()]);
+        assert!(amounts(totals).is_empty());
+    }
+
+    #[test]
+    fn subtract_owned_skips_incompatible_units() {
+        let mut totals = Totals::new();
+        add_amount(&mut totals, "Сахар", 20.0, "г");
+        let owned_names = subtract_owned(&mut totals, &[owned("сахар", Some(50.0), Some("мл"))]);
+        assert!(owned_names.is_empty());
+        assert_eq!(
+            amounts(totals),
+            vec![("Сахар".to_string(), 20.0, "г".to_string())]
+        );
+    }
+
+    #[test]
+    fn subtract_owned_without_amount_removes_ingredient_in_all_units() {
+        let mut totals = Totals::new();
+        add_amount(&mut totals, "Мята", 10.0, "г");
+        add_amount(&mut totals, "Мята", 2.0, "веточки");
+        add_amount(&mut totals, "Ром", 50.0, "мл");
+        let owned_names = subtract_owned(&mut totals, &[owned("мята", None, None)]);
+        assert_eq!(owned_names, vec!["Мята".to_string()]);
+        assert_eq!(
+            amounts(totals),
+            vec![("Ром".to_string(), 50.0, "мл".to_string())]
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Mutex, OnceLock},
+};
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::cocktail::{localization::localized_cocktail_name, Cocktail, CocktailRepo},
+    locale::{LocaleChain, LocalizedText},
+};
+
+/// Number of similar cocktails kept per cocktail.
+pub const MAX_SIMILAR_COCKTAILS: usize = 20;
+
+const INGREDIENTS_WEIGHT: f64 = 0.5;
+const TAGS_WEIGHT: f64 = 0.15;
+const METHOD_WEIGHT: f64 = 0.1;
+const BASE_SPIRIT_WEIGHT: f64 = 0.25;
+
+#[derive(Clone, Debug)]
+pub struct SimilarCocktail {
+    pub id: Uuid,
+    pub russian_name: String,
+    pub name: Option<String>,
+    pub(crate) name_translations: LocalizedText,
+    /// Similarity from 0 to 1.
+    pub score: f64,
+    pub shared_ingredients: Vec<String>,
+}
+
+impl SimilarCocktail {
+    /// Name in the first locale of the chain having one, the same as `Cocktail::localized_name`.
+    pub fn localized_name(&self, chain: &LocaleChain) -> &str {
+        localized_cocktail_name(
+            chain,
+            &self.russian_name,
+            self.name.as_deref(),
+            &self.name_translations,
+        )
+    }
+}
+
+#[derive(Default)]
+struct SimilarityCache {
+    /// Incremented by every invalidation, lists computed before it are not cached.
+    generation: u64,
+    entries: HashMap<Uuid, Vec<SimilarCocktail>>,
+}
+
+fn cache() -> &'static Mutex<SimilarityCache> {
+    static CACHE: OnceLock<Mutex<SimilarityCache>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// Drops all cached results. Must be called when any cocktail is created, changed or deleted,
+/// because the change affects similarity lists of other cocktails too.
+pub fn invalidate_cache() {
+    let mut cache = cache().lock().unwrap();
+    cache.generation += 1;
+    cache.entries.clear();
+}
+
+fn ingredient_names(cocktail: &Cocktail) -> HashSet<String> {
+    cocktail
+        .composition_elements
+        .iter()
+        .flatten()
+        .map(|element| element.name.trim().to_lowercase())
+        .collect()
+}
+
+fn tag_names(cocktail: &Cocktail) -> HashSet<String> {
+    cocktail
+        .tags
+        .iter()
+        .flatten()
+        .map(|tag| tag.name.trim().to_lowercase())
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        0.0
+    } else {
+        a.intersection(b).count() as f64 / union as f64
+    }
+}
+
+fn score(source: &Cocktail, candidate: &Cocktail) -> (f64, Vec<String>) {
+    let source_ingredients = ingredient_names(source);
+    let candidate_ingredients = ingredient_names(candidate);
+    let mut seen = HashSet::new();
+    let shared_ingredients: Vec<String> = candidate
+        .composition_elements
+        .iter()
+        .flatten()
+        .filter(|element| {
+            let name = element.name.trim().to_lowercase();
+            source_ingredients.contains(&name) && seen.insert(name)
+        })
+        .map(|element| element.name.clone())
+        .collect();
+
+    let mut score = INGREDIENTS_WEIGHT * jaccard(&source_ingredients, &candidate_ingredients)
+        + TAGS_WEIGHT * jaccard(&tag_names(source), &tag_names(candidate));
+    if source.method.is_some() && source.method == candidate.method {
+        score += METHOD_WEIGHT;
+    }
+    if let (Some(source_spirit), Some(candidate_spirit)) =
+        (source.base_spirit(), candidate.base_spirit())
+    {
+        if source_spirit == candidate_spirit {
+            score += BASE_SPIRIT_WEIGHT;
+        }
+    }
+
+    (score, shared_ingredients)
+}
+
+/// Returns cocktails most similar to the given one, best first.
+/// Returns `None` if cocktail does not exist.
+pub async fn get_similar_cocktails<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    cocktail_id: &Uuid,
+    limit: usize,
+) -> Result<Option<Vec<SimilarCocktail>>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let generation = {
+        let cache = cache().lock().unwrap();
+        if let Some(similar) = cache.entries.get(cocktail_id) {
+            return Ok(Some(similar.iter().take(limit).cloned().collect()));
+        }
+        cache.generation
+    };
+
+    let cocktails = cocktail_repo.get_all().await?;
+    let Some(source) = cocktails
+        .iter()
+        .find(|cocktail| cocktail.id == *cocktail_id)
+    else {
+        return Ok(None);
+    };
+
+    let mut similar: Vec<SimilarCocktail> = cocktails
+        .iter()
+        .filter(|cocktail| cocktail.id != source.id)
+        .map(|cocktail| {
+            let (score, shared_ingredients) = score(source, cocktail);
+            SimilarCocktail {
+                id: cocktail.id,
+                russian_name: cocktail.russian_name.clone(),
+                name: cocktail.name.clone(),
+                name_translations: cocktail.name_translations.clone(),
+                score,
+                shared_ingredients,
+            }
+        })
+        .filter(|similar| similar.score > 0.0)
+        .collect();
+    similar.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.russian_name.cmp(&b.russian_name))
+    });
+    similar.truncate(MAX_SIMILAR_COCKTAILS);
+
+    let result = similar.iter().take(limit).cloned().collect();
+    let mut cache = cache().lock().unwrap();
+    if cache.generation == generation {
+        cache.entries.insert(*cocktail_id, similar);
+    }
+
+    Ok(Some(result))
+}
@@ -0,0 +1,254 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::cocktail::{Cocktail, CocktailError, CocktailRepo},
+    transliteration,
+};
+
+/// Leaves room for a numeric suffix and the `c_` prefix of bot deep links,
+/// Telegram limits the whole start payload to 64 characters.
+pub const MAX_SLUG_LENGTH: usize = 50;
+/// Numbered variants tried before falling back to a part of the cocktail id.
+const MAX_SLUG_SUFFIX: u32 = 100;
+/// How many times a new cocktail gets the next free slug when its slug was taken
+/// by a concurrent request.
+const MAX_CREATE_ATTEMPTS: usize = 3;
+/// Used when neither name has letters or digits.
+const DEFAULT_SLUG: &str = "cocktail";
+
+/// Lowercase latin letters and digits separated by single hyphens.
+/// Cyrillic is transliterated, latin letters with diacritics lose them.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.to_lowercase().chars() {
+        let latin = match c {
+            'a'..='z' | '0'..='9' => Some(c.to_string()),
+            _ => transliteration::to_latin(c).map(str::to_string),
+        };
+        match latin {
+            Some(latin) => {
+                if slug.chars().count() + latin.chars().count() > MAX_SLUG_LENGTH {
+                    break;
+                }
+                slug.push_str(&latin);
+            }
+            None if !slug.is_empty() && !slug.ends_with('-') => slug.push('-'),
+            None => {}
+        }
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Slug derived from english name, or from russian name if english one is missing.
+pub fn base_slug(cocktail: &Cocktail) -> String {
+    [
+        cocktail.name.as_deref(),
+        Some(cocktail.russian_name.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    .map(slugify)
+    .find(|slug| !slug.is_empty())
+    .unwrap_or_else(|| DEFAULT_SLUG.to_string())
+}
+
+/// Gives the cocktail a unique slug. `current` is the stored cocktail, its slug is kept while
+/// the names still lead to it. Replaced slug goes to `previous_slugs`.
+pub async fn assign<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    cocktail: &mut Cocktail,
+    current: Option<&Cocktail>,
+) -> Result<()>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    match current {
+        Some(current) => {
+            cocktail.slug = current.slug.clone();
+            cocktail.previous_slugs = current.previous_slugs.clone();
+        }
+        None => {
+            // cocktail brought back from a snapshot may have lost its old slugs meanwhile
+            let mut previous_slugs = vec![];
+            for slug in cocktail.previous_slugs.drain(..) {
+                if !cocktail_repo.is_slug_taken(&slug, &cocktail.id).await? {
+                    previous_slugs.push(slug);
+                }
+            }
+            cocktail.previous_slugs = previous_slugs;
+        }
+    }
+
+    let base = base_slug(cocktail);
+    if let Some(slug) = &cocktail.slug {
+        if is_variant_of(slug, &base) && !cocktail_repo.is_slug_taken(slug, &cocktail.id).await? {
+            return Ok(());
+        }
+    }
+
+    let slug = free_slug(cocktail_repo, &base, &cocktail.id).await?;
+    if let Some(replaced) = cocktail.slug.replace(slug.clone()) {
+        if !cocktail.previous_slugs.contains(&replaced) {
+            cocktail.previous_slugs.push(replaced);
+        }
+    }
+    cocktail.previous_slugs.retain(|previous| *previous != slug);
+
+    Ok(())
+}
+
+/// Stores new cocktail with a slug given by `assign`. The slug index rejects a slug
+/// taken by another request after the check, then the next free slug is tried.
+pub async fn create<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    cocktail: &mut Cocktail,
+) -> Result<()>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let mut attempt = 1;
+    loop {
+        assign(cocktail_repo, cocktail, None).await?;
+        match cocktail_repo.create(cocktail).await {
+            Ok(()) => return Ok(()),
+            Err(err)
+                if attempt < MAX_CREATE_ATTEMPTS
+                    && matches!(
+                        err.downcast_ref::<CocktailError>(),
+                        Some(CocktailError::SlugTaken(_))
+                    ) =>
+            {
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Assigns slugs to cocktails stored before slugs were introduced. Returns number of cocktails
+/// which got a slug.
+pub async fn backfill<TCocktailRepo>(cocktail_repo: &TCocktailRepo) -> Result<u64>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let mut count = 0;
+    for mut cocktail in cocktail_repo.get_without_slug().await? {
+        assign(cocktail_repo, &mut cocktail, None).await?;
+        if let Some(slug) = &cocktail.slug {
+            cocktail_repo.set_slug(&cocktail.id, slug).await?;
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// `base` itself or `base` with a numeric suffix, like `negroni-2`.
+fn is_variant_of(slug: &str, base: &str) -> bool {
+    match slug.strip_prefix(base) {
+        Some("") => true,
+        Some(suffix) => suffix
+            .strip_prefix('-')
+            .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())),
+        None => false,
+    }
+}
+
+async fn free_slug<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    base: &str,
+    id: &Uuid,
+) -> Result<String>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    for slug in numbered_slugs(base) {
+        if !cocktail_repo.is_slug_taken(&slug, id).await? {
+            return Ok(slug);
+        }
+    }
+
+    Ok(id_slug(base, id))
+}
+
+/// `base`, then `base-2` and so on up to `MAX_SLUG_SUFFIX`.
+fn numbered_slugs(base: &str) -> impl Iterator<Item = String> + '_ {
+    (1..=MAX_SLUG_SUFFIX).map(move |suffix| match suffix {
+        1 => base.to_string(),
+        _ => format!("{}-{}", base, suffix),
+    })
+}
+
+/// Used when all numbered slugs are taken, a part of the id keeps it unique.
+fn id_slug(base: &str, id: &Uuid) -> String {
+    format!("{}-{}", base, &id.simple().to_string()[..8])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Telegram limit of the start payload, which is `c_` followed by the slug.
+    const MAX_START_PAYLOAD_LENGTH: usize = 64;
+
+    #[test]
+    fn slugify_joins_words_with_single_hyphens() {
+        assert_eq!(slugify("Old Fashioned"), "old-fashioned");
+        assert_eq!(slugify("  B-52 (shot)!  "), "b-52-shot");
+    }
+
+    #[test]
+    fn slugify_transliterates_cyrillic() {
+        assert_eq!(slugify("Негрони"), "negroni");
+        assert_eq!(slugify("Белый русский"), "belyy-russkiy");
+    }
+
+    #[test]
+    fn slugify_drops_diacritics() {
+        assert_eq!(slugify("Crème de Menthe"), "creme-de-menthe");
+    }
+
+    #[test]
+    fn slugify_without_letters_is_empty() {
+        assert_eq!(slugify("?!"), "");
+    }
+
+    #[test]
+    fn slugify_cuts_long_text_to_max_length() {
+        let slug = slugify(&"very long name ".repeat(10));
+        assert!(slug.len() <= MAX_SLUG_LENGTH);
+        assert!(!slug.ends_with('-'));
+        assert!(slug.starts_with("very-long-name-very"));
+    }
+
+    #[test]
+    fn numbered_slugs_start_with_base() {
+        let slugs: Vec<String> = numbered_slugs("negroni").take(3).collect();
+        assert_eq!(slugs, vec!["negroni", "negroni-2", "negroni-3"]);
+        assert_eq!(
+            numbered_slugs("negroni").last().as_deref(),
+            Some("negroni-100")
+        );
+    }
+
+    #[test]
+    fn suffixed_slugs_of_longest_base_fit_start_payload() {
+        let base = "a".repeat(MAX_SLUG_LENGTH);
+        let id = Uuid::new_v4();
+        let longest = numbered_slugs(&base)
+            .chain([id_slug(&base, &id)])
+            .map(|slug| slug.len())
+            .max()
+            .unwrap();
+        assert!("c_".len() + longest <= MAX_START_PAYLOAD_LENGTH);
+    }
+
+    #[test]
+    fn suffixed_slugs_are_variants_of_base() {
+        assert!(numbered_slugs("negroni").all(|slug| is_variant_of(&slug, "negroni")));
+        assert!(!is_variant_of("negroni-sbagliato", "negroni"));
+        assert!(!is_variant_of("negroni-", "negroni"));
+    }
+}
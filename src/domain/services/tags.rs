@@ -0,0 +1,98 @@
+use anyhow::Result;
+
+use crate::domain::{
+    aggregates::{
+        cocktail::{CocktailError, CocktailRepo},
+        revision::{RevisionAction, RevisionRepo},
+    },
+    services::revisions,
+};
+
+/// How many times cocktails changed by other requests during the merge are merged again.
+const MAX_MERGE_ATTEMPTS: usize = 3;
+
+/// Renames tag in all cocktails. Returns number of modified cocktails.
+pub async fn rename<TCocktailRepo, TRevisionRepo>(
+    cocktail_repo: &TCocktailRepo,
+    revision_repo: &TRevisionRepo,
+    from: &str,
+    to: &str,
+    author: Option<String>,
+) -> Result<u64>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+    TRevisionRepo: RevisionRepo + Sync,
+{
+    merge(
+        cocktail_repo,
+        revision_repo,
+        &[from.to_string()],
+        to,
+        author,
+    )
+    .await
+}
+
+/// Replaces all source tags with target tag in all cocktails and records the changes
+/// in revision history. Cocktails in trash are changed too, otherwise restoring them
+/// brings the old tags back. Returns number of modified cocktails.
+pub async fn merge<TCocktailRepo, TRevisionRepo>(
+    cocktail_repo: &TCocktailRepo,
+    revision_repo: &TRevisionRepo,
+    sources: &[String],
+    target: &str,
+    author: Option<String>,
+) -> Result<u64>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+    TRevisionRepo: RevisionRepo + Sync,
+{
+    let sources: Vec<String> = sources
+        .iter()
+        .filter(|source| *source != target)
+        .cloned()
+        .collect();
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let mut modified_count = 0;
+    for _ in 0..MAX_MERGE_ATTEMPTS {
+        let cocktails = cocktail_repo.get_by_tags(&sources).await?;
+        if cocktails.is_empty() {
+            break;
+        }
+        for before in cocktails {
+            let mut after = before.clone();
+            if !after.merge_tags(&sources, target) {
+                continue;
+            }
+            match cocktail_repo.merge_tags(&before, &sources, target).await {
+                Ok(()) => {}
+                // changed meanwhile, it is read again on the next attempt
+                Err(err)
+                    if matches!(
+                        err.downcast_ref::<CocktailError>(),
+                        Some(CocktailError::VersionConflict { .. })
+                    ) =>
+                {
+                    continue;
+                }
+                Err(err) => return Err(err),
+            }
+            after.version += 1;
+            after.updated_at = Some(chrono::Utc::now());
+            revisions::record(
+                revision_repo,
+                RevisionAction::Update,
+                Some(&before),
+                Some(&after),
+                author.clone(),
+            )
+            .await?;
+            modified_count += 1;
+        }
+    }
+
+    Ok(modified_count)
+}
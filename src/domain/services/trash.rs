@@ -0,0 +1,82 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::{
+        cocktail::{Cocktail, CocktailRepo},
+        collection::CollectionRepo,
+        rating::RatingRepo,
+        revision::{RevisionAction, RevisionRepo},
+        user::UserRepo,
+    },
+    services::{
+        images::{self, BlobStore},
+        revisions, search, similarity,
+    },
+};
+
+/// Takes cocktail out of trash and records it in revision history.
+/// Fails with `CocktailError::VersionConflict` if the cocktail was changed since `deleted` was read.
+pub async fn restore<TCocktailRepo, TRevisionRepo>(
+    cocktail_repo: &TCocktailRepo,
+    revision_repo: &TRevisionRepo,
+    deleted: &Cocktail,
+    author: Option<String>,
+) -> Result<Cocktail>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+    TRevisionRepo: RevisionRepo + Sync,
+{
+    let restored = cocktail_repo.restore_deleted(deleted).await?;
+    similarity::invalidate_cache();
+    search::invalidate_cache();
+    // content is not changed, so the revision has empty diff
+    revisions::record(
+        revision_repo,
+        RevisionAction::Restore,
+        Some(&restored),
+        Some(&restored),
+        author,
+    )
+    .await?;
+
+    Ok(restored)
+}
+
+/// Removes cocktails kept in trash longer than `retention` for good with their images.
+/// They are removed from favourites, recently viewed cocktails and collections of users
+/// and their ratings are removed before, so a failed cleanup leaves the cocktails in trash
+/// and is repeated on the next run. Returns ids of removed cocktails.
+pub async fn purge_expired<TCocktailRepo, TUserRepo, TCollectionRepo, TRatingRepo>(
+    cocktail_repo: &TCocktailRepo,
+    user_repo: &TUserRepo,
+    collection_repo: &TCollectionRepo,
+    rating_repo: &TRatingRepo,
+    blob_store: &dyn BlobStore,
+    retention: chrono::Duration,
+) -> Result<Vec<Uuid>>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+    TUserRepo: UserRepo + Sync,
+    TCollectionRepo: CollectionRepo + Sync,
+    TRatingRepo: RatingRepo + Sync,
+{
+    let deleted_before = chrono::Utc::now() - retention;
+    let expired = cocktail_repo.get_expired_deleted(&deleted_before).await?;
+    if expired.is_empty() {
+        return Ok(vec![]);
+    }
+    let expired_ids: Vec<Uuid> = expired.iter().map(|cocktail| cocktail.id).collect();
+    user_repo.remove_cocktails(&expired_ids).await?;
+    collection_repo.remove_cocktails(&expired_ids).await?;
+    rating_repo.delete_by_cocktails(&expired_ids).await?;
+
+    let purged = cocktail_repo
+        .purge_deleted(&expired, &deleted_before)
+        .await?;
+    for image in purged.iter().filter_map(|cocktail| cocktail.image.as_ref()) {
+        images::delete_files(blob_store, image).await;
+    }
+
+    Ok(purged.iter().map(|cocktail| cocktail.id).collect())
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+
+use crate::domain::aggregates::cocktail::{Cocktail, CocktailError, CocktailRepo, FieldError};
+
+/// Checks cocktail fields and uniqueness of its names among other cocktails.
+/// Fails with `CocktailError::Invalid` listing all violated constraints.
+pub async fn validate_cocktail<TCocktailRepo>(
+    cocktail_repo: &TCocktailRepo,
+    cocktail: &Cocktail,
+) -> Result<()>
+where
+    TCocktailRepo: CocktailRepo + Sync,
+{
+    let mut errors = cocktail.validate();
+    let name = cocktail
+        .name
+        .as_deref()
+        .filter(|name| !name.trim().is_empty());
+    if !cocktail.russian_name.trim().is_empty() || name.is_some() {
+        let same_names = cocktail_repo
+            .get_by_exact_names(name, &cocktail.russian_name)
+            .await?;
+        for other in same_names.iter().filter(|other| other.id != cocktail.id) {
+            if !cocktail.russian_name.trim().is_empty()
+                && same_name(&other.russian_name, &cocktail.russian_name)
+            {
+                errors.push(FieldError::new(
+                    "russian_name",
+                    format!("cocktail {} already has this name", other.id),
+                ));
+            }
+            if let (Some(other_name), Some(name)) = (&other.name, name) {
+                if same_name(other_name, name) {
+                    errors.push(FieldError::new(
+                        "name",
+                        format!("cocktail {} already has this name", other.id),
+                    ));
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(CocktailError::Invalid(errors).into())
+    }
+}
+
+fn same_name(left: &str, right: &str) -> bool {
+    left.trim().to_lowercase() == right.trim().to_lowercase()
+}
@@ -0,0 +1,47 @@
+/// Base unit of volume, amounts of liquids are compared in it.
+pub const MILLILITRES: &str = "мл";
+/// Base unit of weight.
+pub const GRAMS: &str = "г";
+
+/// Units with known conversion: their spellings, multiplier and the base unit of the dimension.
+pub const UNIT_CONVERSIONS: &[(&[&str], f64, &str)] = &[
+    (&["мл", "ml"], 1.0, MILLILITRES),
+    (&["cl", "сл"], 10.0, MILLILITRES),
+    (&["л", "l"], 1000.0, MILLILITRES),
+    (&["oz", "унц", "унция", "унции"], 30.0, MILLILITRES),
+    (&["г", "гр", "g"], 1.0, GRAMS),
+    (&["кг", "kg"], 1000.0, GRAMS),
+];
+
+/// Converts amount to the base unit of its dimension (millilitres or grams).
+/// Units without known conversion are kept as is.
+pub fn normalize_amount(amount: f64, unit: &str) -> (f64, String) {
+    let unit = unit.trim().to_lowercase();
+    let unit = unit.trim_end_matches('.');
+    match UNIT_CONVERSIONS
+        .iter()
+        .find(|(spellings, _, _)| spellings.contains(&unit))
+    {
+        Some((_, multiplier, base_unit)) => (amount * multiplier, base_unit.to_string()),
+        None => (amount, unit.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_amount_converts_to_base_units() {
+        assert_eq!(normalize_amount(5.0, "cl"), (50.0, "мл".to_string()));
+        assert_eq!(normalize_amount(2.0, "OZ"), (60.0, "мл".to_string()));
+        assert_eq!(normalize_amount(0.5, "л"), (500.0, "мл".to_string()));
+        assert_eq!(normalize_amount(1.5, "кг"), (1500.0, "г".to_string()));
+        assert_eq!(normalize_amount(20.0, " гр. "), (20.0, "г".to_string()));
+    }
+
+    #[test]
+    fn normalize_amount_keeps_unknown_units() {
+        assert_eq!(normalize_amount(2.0, "Шт."), (2.0, "шт".to_string()));
+    }
+}
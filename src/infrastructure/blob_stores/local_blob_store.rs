@@ -0,0 +1,85 @@
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+
+use crate::{
+    domain::services::images::{Blob, BlobStore},
+    infrastructure::configurations::BlobStoreConfiguration,
+};
+
+/// Keeps files in a directory, keys are relative paths in it.
+#[derive(Debug, Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+    public_url: String,
+}
+
+impl LocalBlobStore {
+    pub fn new(config: &BlobStoreConfiguration) -> Self {
+        LocalBlobStore {
+            root: PathBuf::from(&config.local_path),
+            public_url: config.public_url.clone(),
+        }
+    }
+
+    /// Keys come from request paths too, so only plain relative paths are allowed.
+    fn path(&self, key: &str) -> Result<PathBuf> {
+        let relative = Path::new(key);
+        let plain = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if key.is_empty() || !plain {
+            bail!("invalid blob key {}", key);
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, _content_type: &str, data: Vec<u8>) -> Result<()> {
+        let path = self.path(key)?;
+        if let Some(directory) = path.parent() {
+            tokio::fs::create_dir_all(directory)
+                .await
+                .with_context(|| format!("failed to create directory for blob {}", key))?;
+        }
+        tokio::fs::write(&path, data)
+            .await
+            .with_context(|| format!("failed to write blob {}", key))
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Blob>> {
+        let Ok(path) = self.path(key) else {
+            return Ok(None);
+        };
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err).with_context(|| format!("failed to read blob {}", key)),
+        };
+        // content type is not stored, files are named with the extension of their format
+        let content_type = image::ImageFormat::from_path(&path)
+            .map(|format| format.to_mime_type())
+            .unwrap_or("application/octet-stream");
+
+        Ok(Some(Blob {
+            content_type: content_type.to_string(),
+            data,
+        }))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let path = self.path(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err).with_context(|| format!("failed to delete blob {}", key)),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        super::public_url(&self.public_url, key)
+    }
+}
@@ -0,0 +1,25 @@
+pub mod local_blob_store;
+#[cfg(feature = "s3")]
+pub mod s3_blob_store;
+
+use anyhow::{bail, Result};
+
+use crate::domain::services::images::BlobStore;
+
+use super::configurations::BlobStoreConfiguration;
+
+pub fn create_blob_store(config: &BlobStoreConfiguration) -> Result<Box<dyn BlobStore>> {
+    match config.blob_store.as_str() {
+        "local" => Ok(Box::new(local_blob_store::LocalBlobStore::new(config))),
+        #[cfg(feature = "s3")]
+        "s3" => Ok(Box::new(s3_blob_store::S3BlobStore::new(config)?)),
+        #[cfg(not(feature = "s3"))]
+        "s3" => bail!("s3 blob store is not available, build with `s3` feature"),
+        other => bail!("unknown blob store {}, use `local` or `s3`", other),
+    }
+}
+
+/// URL of the file under the configured public base URL.
+fn public_url(base_url: &str, key: &str) -> String {
+    format!("{}/{}", base_url.trim_end_matches('/'), key)
+}
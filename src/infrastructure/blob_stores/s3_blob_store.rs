@@ -0,0 +1,102 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use object_store::{
+    aws::{AmazonS3, AmazonS3Builder},
+    path::Path,
+    Attribute, Attributes, ObjectStore, PutOptions, PutPayload,
+};
+
+use crate::{
+    domain::services::images::{Blob, BlobStore},
+    infrastructure::configurations::BlobStoreConfiguration,
+};
+
+/// Keeps files in a bucket of AWS S3 or S3-compatible storage like MinIO.
+#[derive(Debug)]
+pub struct S3BlobStore {
+    store: AmazonS3,
+    public_url: String,
+}
+
+impl S3BlobStore {
+    /// Settings missing in the configuration are taken from `AWS_*` environment variables.
+    pub fn new(config: &BlobStoreConfiguration) -> Result<Self> {
+        let bucket = config
+            .s3_bucket
+            .as_ref()
+            .context("S3_BUCKET is required for s3 blob store")?;
+        let mut builder = AmazonS3Builder::from_env()
+            .with_bucket_name(bucket)
+            .with_region(&config.s3_region);
+        if let Some(endpoint) = &config.s3_endpoint {
+            builder = builder
+                .with_endpoint(endpoint)
+                .with_allow_http(endpoint.starts_with("http://"));
+        }
+        if let Some(access_key_id) = &config.s3_access_key_id {
+            builder = builder.with_access_key_id(access_key_id);
+        }
+        if let Some(secret_access_key) = &config.s3_secret_access_key {
+            builder = builder.with_secret_access_key(secret_access_key);
+        }
+
+        Ok(S3BlobStore {
+            store: builder.build().context("failed to create s3 client")?,
+            public_url: config.public_url.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    async fn put(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<()> {
+        let location = Path::parse(key).with_context(|| format!("invalid blob key {}", key))?;
+        let options = PutOptions {
+            attributes: Attributes::from_iter([(Attribute::ContentType, content_type.to_string())]),
+            ..Default::default()
+        };
+        self.store
+            .put_opts(&location, PutPayload::from(data), options)
+            .await
+            .with_context(|| format!("failed to put blob {}", key))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Blob>> {
+        let Ok(location) = Path::parse(key) else {
+            return Ok(None);
+        };
+        let result = match self.store.get(&location).await {
+            Ok(result) => result,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(err) => return Err(err).with_context(|| format!("failed to get blob {}", key)),
+        };
+        let content_type = result
+            .attributes
+            .get(&Attribute::ContentType)
+            .map(|content_type| content_type.to_string())
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+        let data = result
+            .bytes()
+            .await
+            .with_context(|| format!("failed to read blob {}", key))?;
+
+        Ok(Some(Blob {
+            content_type,
+            data: data.to_vec(),
+        }))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let location = Path::parse(key).with_context(|| format!("invalid blob key {}", key))?;
+        match self.store.delete(&location).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(err) => Err(err).with_context(|| format!("failed to delete blob {}", key)),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        super::public_url(&self.public_url, key)
+    }
+}
@@ -13,3 +13,30 @@ pub struct DbConfiguration {
     #[envconfig{ from = "MONGO_PORT" }]
     pub mongo_port: String,
 }
+
+#[derive(Envconfig, Debug, Clone)]
+// S3 settings are read only in builds with `s3` feature
+#[cfg_attr(not(feature = "s3"), allow(dead_code))]
+pub struct BlobStoreConfiguration {
+    /// `local` or `s3`, the latter is available in builds with `s3` feature.
+    #[envconfig(from = "BLOB_STORE", default = "local")]
+    pub blob_store: String,
+    /// Directory of `local` store.
+    #[envconfig(from = "BLOB_STORE_LOCAL_PATH", default = "data/blobs")]
+    pub local_path: String,
+    /// Base of file URLs in API responses. Files of any store are served
+    /// by the API at `/api/images/v1/{key}`, a CDN or public bucket URL can be set instead.
+    #[envconfig(from = "BLOB_STORE_PUBLIC_URL", default = "/api/images/v1")]
+    pub public_url: String,
+    #[envconfig(from = "S3_BUCKET")]
+    pub s3_bucket: Option<String>,
+    /// Endpoint of S3-compatible storage, AWS endpoint of the region is used when not set.
+    #[envconfig(from = "S3_ENDPOINT")]
+    pub s3_endpoint: Option<String>,
+    #[envconfig(from = "S3_REGION", default = "us-east-1")]
+    pub s3_region: String,
+    #[envconfig(from = "S3_ACCESS_KEY_ID")]
+    pub s3_access_key_id: Option<String>,
+    #[envconfig(from = "S3_SECRET_ACCESS_KEY")]
+    pub s3_secret_access_key: Option<String>,
+}
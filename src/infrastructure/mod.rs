@@ -2,27 +2,41 @@
 pub mod configurations;
 
 // private modules
+mod blob_stores;
 mod mongo;
 mod repositories;
 
 use anyhow::Result;
-use configurations::DbConfiguration;
-use repositories::{cocktail_repository::CocktailRepository, user_repository::UserRepository};
+use configurations::{BlobStoreConfiguration, DbConfiguration};
+use repositories::{
+    cocktail_repository::CocktailRepository, collection_repository::CollectionRepository,
+    glassware_repository::GlasswareRepository, rating_repository::RatingRepository,
+    revision_repository::RevisionRepository, tool_repository::ToolRepository,
+    user_repository::UserRepository,
+};
 use std::sync::OnceLock;
 
-use crate::domain::aggregates::{cocktail::CocktailRepo, user::UserRepo};
+use crate::domain::{
+    aggregates::{
+        cocktail::CocktailRepo, collection::CollectionRepo, glassware::GlasswareRepo,
+        rating::RatingRepo, revision::RevisionRepo, tool::ToolRepo, user::UserRepo,
+    },
+    services::images::BlobStore,
+};
 
 pub static REPOFACTORYINSTANCE: OnceLock<RepositoryFactory> = OnceLock::new();
 
 #[derive(Debug, Clone)]
 pub struct RepositoryFactory {
     db_configuration: DbConfiguration,
+    blob_store_configuration: BlobStoreConfiguration,
 }
 
 impl RepositoryFactory {
-    pub fn new(cfg: &DbConfiguration) -> Self {
+    pub fn new(cfg: &DbConfiguration, blob_store_cfg: &BlobStoreConfiguration) -> Self {
         RepositoryFactory {
             db_configuration: cfg.clone(),
+            blob_store_configuration: blob_store_cfg.clone(),
         }
     }
 
@@ -41,4 +55,29 @@ impl RepositoryFactory {
     pub async fn get_cocktails_repository(&self) -> Result<impl CocktailRepo> {
         CocktailRepository::new(self.db_configuration.clone()).await
     }
+
+    pub async fn get_tools_repository(&self) -> Result<impl ToolRepo> {
+        ToolRepository::new(self.db_configuration.clone()).await
+    }
+
+    pub async fn get_ratings_repository(&self) -> Result<impl RatingRepo> {
+        RatingRepository::new(self.db_configuration.clone()).await
+    }
+
+    pub async fn get_revisions_repository(&self) -> Result<impl RevisionRepo> {
+        RevisionRepository::new(self.db_configuration.clone()).await
+    }
+
+    pub async fn get_glassware_repository(&self) -> Result<impl GlasswareRepo> {
+        GlasswareRepository::new(self.db_configuration.clone()).await
+    }
+
+    pub async fn get_collections_repository(&self) -> Result<impl CollectionRepo> {
+        CollectionRepository::new(self.db_configuration.clone()).await
+    }
+
+    /// Store of image files chosen by `BLOB_STORE` setting.
+    pub fn get_blob_store(&self) -> Result<Box<dyn BlobStore>> {
+        blob_stores::create_blob_store(&self.blob_store_configuration)
+    }
 }
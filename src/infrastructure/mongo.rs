@@ -1,17 +1,43 @@
 #![allow(clippy::from_over_into)]
 
+use std::collections::BTreeMap;
+
 use anyhow::{Context, Result};
 use mongodb::{bson::doc, options::UpdateModifications, Client, Collection};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::aggregates::{
-    cocktail::{Cocktail, CocktailItem, Recipe, Tag},
-    user::User,
+    cocktail::{
+        Cocktail, CocktailImage, CocktailItem, CocktailPatch, CocktailTool,
+        CocktailWithMissingIngredients, ImageSize, ImageVariant, PreparationMethod,
+        RandomCocktailFilter, Recipe, Tag,
+    },
+    collection::Collection as CocktailCollection,
+    glassware::Glassware,
+    rating::{Rating, RatingSummary},
+    revision::{CocktailRevision, RevisionAction},
+    tool::Tool,
+    user::{DailySubscription, FavoriteCocktail, User, ViewedCocktail},
 };
 
 use super::configurations::DbConfiguration;
 
+/// Code of the server error on a write violating a unique index.
+const DUPLICATE_KEY_ERROR_CODE: i32 = 11000;
+
+/// Whether the write failed because a document with the same unique key already exists.
+pub fn is_duplicate_key_error(err: &mongodb::error::Error) -> bool {
+    matches!(
+        err.kind.as_ref(),
+        mongodb::error::ErrorKind::Write(mongodb::error::WriteFailure::WriteError(write_error))
+            if write_error.code == DUPLICATE_KEY_ERROR_CODE
+    )
+}
+
+pub const COCKTAILS_COLLECTION: &str = "cocktails";
+pub const COCKTAIL_ENGLISH_TEXTS_COLLECTION: &str = "cocktail_english_texts";
+
 #[derive(Clone, Debug)]
 pub struct MongoDbClient {
     config: DbConfiguration,
@@ -47,7 +73,44 @@ impl MongoDbClient {
     pub fn get_cocktails_collection(&self) -> Collection<CocktailDbModel> {
         self.client
             .database(&self.config.mongo_database_name)
-            .collection::<CocktailDbModel>("cocktails")
+            .collection::<CocktailDbModel>(COCKTAILS_COLLECTION)
+    }
+
+    /// English texts of cocktails, kept apart to have a text index with english stemming.
+    pub fn get_cocktail_english_texts_collection(&self) -> Collection<mongodb::bson::Document> {
+        self.client
+            .database(&self.config.mongo_database_name)
+            .collection::<mongodb::bson::Document>(COCKTAIL_ENGLISH_TEXTS_COLLECTION)
+    }
+
+    pub fn get_tools_collection(&self) -> Collection<ToolDbModel> {
+        self.client
+            .database(&self.config.mongo_database_name)
+            .collection::<ToolDbModel>("tools")
+    }
+
+    pub fn get_glassware_collection(&self) -> Collection<GlasswareDbModel> {
+        self.client
+            .database(&self.config.mongo_database_name)
+            .collection::<GlasswareDbModel>("glassware")
+    }
+
+    pub fn get_collections_collection(&self) -> Collection<CollectionDbModel> {
+        self.client
+            .database(&self.config.mongo_database_name)
+            .collection::<CollectionDbModel>("collections")
+    }
+
+    pub fn get_ratings_collection(&self) -> Collection<RatingDbModel> {
+        self.client
+            .database(&self.config.mongo_database_name)
+            .collection::<RatingDbModel>("ratings")
+    }
+
+    pub fn get_revisions_collection(&self) -> Collection<CocktailRevisionDbModel> {
+        self.client
+            .database(&self.config.mongo_database_name)
+            .collection::<CocktailRevisionDbModel>("cocktail_revisions")
     }
 }
 
@@ -61,7 +124,90 @@ pub struct Insert<T> {
 pub struct UserDbModel {
     pub id: mongodb::bson::uuid::Uuid,
     pub telegram_id: String,
-    pub favorite_cocktails: Vec<mongodb::bson::uuid::Uuid>,
+    pub favorite_cocktails: Vec<FavoriteCocktailDbEntry>,
+    #[serde(default)]
+    pub bar_ingredients: Vec<String>,
+    #[serde(default)]
+    pub daily_subscription: Option<DailySubscriptionDbModel>,
+    /// Local date in format `YYYY-MM-DD`, written only when daily delivery is claimed.
+    #[serde(default)]
+    pub daily_last_sent_on: Option<String>,
+    #[serde(default)]
+    pub viewed_cocktails: Vec<ViewedCocktailDbModel>,
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ViewedCocktailDbModel {
+    pub cocktail_id: mongodb::bson::uuid::Uuid,
+    pub viewed_at: mongodb::bson::DateTime,
+}
+
+pub const DAILY_SEND_AT_FORMAT: &str = "%H:%M";
+pub const DAILY_LAST_SENT_ON_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DailySubscriptionDbModel {
+    pub timezone: String,
+    /// Local time in format `HH:MM`.
+    pub send_at: String,
+    pub tag: Option<String>,
+    pub non_alcoholic: bool,
+    pub max_ingredients: Option<u32>,
+}
+
+impl From<DailySubscription> for DailySubscriptionDbModel {
+    fn from(value: DailySubscription) -> Self {
+        DailySubscriptionDbModel {
+            timezone: value.timezone,
+            send_at: value.send_at.format(DAILY_SEND_AT_FORMAT).to_string(),
+            tag: value.filter.tag,
+            non_alcoholic: value.filter.non_alcoholic,
+            max_ingredients: value.filter.max_ingredients,
+        }
+    }
+}
+
+impl Into<DailySubscription> for DailySubscriptionDbModel {
+    fn into(self) -> DailySubscription {
+        DailySubscription {
+            timezone: self.timezone,
+            send_at: chrono::NaiveTime::parse_from_str(&self.send_at, DAILY_SEND_AT_FORMAT)
+                .unwrap_or_default(),
+            filter: RandomCocktailFilter {
+                tag: self.tag,
+                non_alcoholic: self.non_alcoholic,
+                max_ingredients: self.max_ingredients,
+            },
+        }
+    }
+}
+
+/// Favorite cocktails were stored as plain cocktail ids before notes were introduced.
+/// Both formats are read, only records are written.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum FavoriteCocktailDbEntry {
+    Legacy(mongodb::bson::uuid::Uuid),
+    Record(FavoriteCocktailDbModel),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FavoriteCocktailDbModel {
+    pub cocktail_id: mongodb::bson::uuid::Uuid,
+    pub added_at: mongodb::bson::DateTime,
+    pub note: Option<String>,
+}
+
+impl From<FavoriteCocktail> for FavoriteCocktailDbModel {
+    fn from(value: FavoriteCocktail) -> Self {
+        FavoriteCocktailDbModel {
+            cocktail_id: mongodb::bson::Uuid::parse_str(value.cocktail_id.to_string()).unwrap(),
+            added_at: mongodb::bson::DateTime::from_millis(value.added_at.timestamp_millis()),
+            note: value.note,
+        }
+    }
 }
 
 impl From<User> for UserDbModel {
@@ -72,8 +218,27 @@ impl From<User> for UserDbModel {
             favorite_cocktails: value
                 .favorite_cocktails
                 .iter()
-                .map(|i| mongodb::bson::Uuid::parse_str(i.to_string()).unwrap())
+                .map(|fc| {
+                    FavoriteCocktailDbEntry::Record(FavoriteCocktailDbModel::from(fc.clone()))
+                })
                 .collect(),
+            bar_ingredients: value.bar_ingredients,
+            daily_subscription: value.daily_subscription.map(DailySubscriptionDbModel::from),
+            daily_last_sent_on: value
+                .daily_last_sent_on
+                .map(|date| date.format(DAILY_LAST_SENT_ON_FORMAT).to_string()),
+            viewed_cocktails: value
+                .viewed_cocktails
+                .iter()
+                .map(|viewed| ViewedCocktailDbModel {
+                    cocktail_id: mongodb::bson::Uuid::parse_str(viewed.cocktail_id.to_string())
+                        .unwrap(),
+                    viewed_at: mongodb::bson::DateTime::from_millis(
+                        viewed.viewed_at.timestamp_millis(),
+                    ),
+                })
+                .collect(),
+            language: value.language,
         }
     }
 }
@@ -86,15 +251,56 @@ impl Into<User> for UserDbModel {
             favorite_cocktails: self
                 .favorite_cocktails
                 .iter()
-                .map(|fc| Uuid::parse_str(&fc.to_string()).unwrap())
+                .enumerate()
+                .map(|(index, fc)| match fc {
+                    // legacy ids have no timestamp, keep their original order instead
+                    FavoriteCocktailDbEntry::Legacy(cocktail_id) => FavoriteCocktail {
+                        cocktail_id: Uuid::parse_str(&cocktail_id.to_string()).unwrap(),
+                        added_at: chrono::DateTime::from_timestamp_millis(index as i64)
+                            .unwrap_or_default(),
+                        note: None,
+                    },
+                    FavoriteCocktailDbEntry::Record(record) => FavoriteCocktail {
+                        cocktail_id: Uuid::parse_str(&record.cocktail_id.to_string()).unwrap(),
+                        added_at: chrono::DateTime::from_timestamp_millis(
+                            record.added_at.timestamp_millis(),
+                        )
+                        .unwrap_or_default(),
+                        note: record.note.clone(),
+                    },
+                })
+                .collect(),
+            bar_ingredients: self.bar_ingredients,
+            daily_subscription: self
+                .daily_subscription
+                .map(|subscription| subscription.into()),
+            daily_last_sent_on: self.daily_last_sent_on.and_then(|date| {
+                chrono::NaiveDate::parse_from_str(&date, DAILY_LAST_SENT_ON_FORMAT).ok()
+            }),
+            viewed_cocktails: self
+                .viewed_cocktails
+                .iter()
+                .map(|viewed| ViewedCocktail {
+                    cocktail_id: Uuid::parse_str(&viewed.cocktail_id.to_string()).unwrap(),
+                    viewed_at: chrono::DateTime::from_timestamp_millis(
+                        viewed.viewed_at.timestamp_millis(),
+                    )
+                    .unwrap_or_default(),
+                })
                 .collect(),
+            language: self.language,
         }
     }
 }
 
 impl Into<UpdateModifications> for UserDbModel {
     fn into(self) -> UpdateModifications {
-        UpdateModifications::Document(doc! {"$set":{"favorite_cocktails": self.favorite_cocktails}})
+        UpdateModifications::Document(doc! {"$set":{
+            "favorite_cocktails": mongodb::bson::to_bson(&self.favorite_cocktails).unwrap(),
+            "bar_ingredients": self.bar_ingredients,
+            "daily_subscription": mongodb::bson::to_bson(&self.daily_subscription).unwrap(),
+            "language": self.language,
+        }})
     }
 }
 
@@ -104,12 +310,32 @@ pub struct CocktailDbModel {
     pub url: Option<String>,
     pub name: Option<String>,
     pub russian_name: String,
+    #[serde(default)]
+    pub name_translations: BTreeMap<String, String>,
     pub country_of_origin: Option<String>,
     pub history: Option<String>,
+    #[serde(default)]
+    pub history_translations: BTreeMap<String, String>,
     pub tags: Option<Vec<TagDbModel>>,
-    pub tools: Option<Vec<CocktailItemDbModel>>,
+    pub glass: Option<String>,
+    pub method: Option<PreparationMethodDbModel>,
+    pub tools: Option<Vec<CocktailToolDbModel>>,
     pub composition_elements: Option<Vec<CocktailItemDbModel>>,
     pub recipe: Option<RecipeDbModel>,
+    pub rating: Option<RatingSummaryDbModel>,
+    #[serde(default)]
+    pub updated_at: Option<mongodb::bson::DateTime>,
+    #[serde(default)]
+    pub version: i64,
+    #[serde(default)]
+    pub deleted_at: Option<mongodb::bson::DateTime>,
+    /// Missing in cocktails stored before slugs were introduced, unique index skips them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub previous_slugs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<CocktailImageDbModel>,
 }
 
 impl From<Cocktail> for CocktailDbModel {
@@ -119,15 +345,19 @@ impl From<Cocktail> for CocktailDbModel {
             url: value.url,
             name: value.name,
             russian_name: value.russian_name,
+            name_translations: value.name_translations.into(),
             country_of_origin: value.country_of_origin,
             history: value.history,
+            history_translations: value.history_translations.into(),
             tags: value
                 .tags
                 .map(|tags| tags.iter().map(|x| TagDbModel::from(x.clone())).collect()),
+            glass: value.glass,
+            method: value.method.map(PreparationMethodDbModel::from),
             tools: value.tools.map(|tools| {
                 tools
                     .iter()
-                    .map(|x| CocktailItemDbModel::from(x.clone()))
+                    .map(|x| CocktailToolDbModel::from(x.clone()))
                     .collect()
             }),
             composition_elements: value.composition_elements.map(|composition_elements| {
@@ -137,6 +367,17 @@ impl From<Cocktail> for CocktailDbModel {
                     .collect()
             }),
             recipe: value.recipe.map(RecipeDbModel::from),
+            rating: value.rating.map(RatingSummaryDbModel::from),
+            updated_at: value.updated_at.map(|updated_at| {
+                mongodb::bson::DateTime::from_millis(updated_at.timestamp_millis())
+            }),
+            version: value.version as i64,
+            deleted_at: value.deleted_at.map(|deleted_at| {
+                mongodb::bson::DateTime::from_millis(deleted_at.timestamp_millis())
+            }),
+            slug: value.slug,
+            previous_slugs: value.previous_slugs,
+            image: value.image.map(CocktailImageDbModel::from),
         }
     }
 }
@@ -148,11 +389,15 @@ impl Into<Cocktail> for CocktailDbModel {
             url: self.url,
             name: self.name,
             russian_name: self.russian_name,
+            name_translations: self.name_translations.into(),
             country_of_origin: self.country_of_origin,
             history: self.history,
+            history_translations: self.history_translations.into(),
             tags: self
                 .tags
                 .map(|tags| tags.iter().map(|x| Into::into(x.clone())).collect()),
+            glass: self.glass,
+            method: self.method.map(|method| method.into()),
             tools: self
                 .tools
                 .map(|tags| tags.iter().map(|x| Into::into(x.clone())).collect()),
@@ -163,6 +408,17 @@ impl Into<Cocktail> for CocktailDbModel {
                     .collect()
             }),
             recipe: self.recipe.map(|recipe| recipe.into()),
+            rating: self.rating.map(|rating| rating.into()),
+            updated_at: self.updated_at.and_then(|updated_at| {
+                chrono::DateTime::from_timestamp_millis(updated_at.timestamp_millis())
+            }),
+            version: self.version as u64,
+            deleted_at: self.deleted_at.and_then(|deleted_at| {
+                chrono::DateTime::from_timestamp_millis(deleted_at.timestamp_millis())
+            }),
+            slug: self.slug,
+            previous_slugs: self.previous_slugs,
+            image: self.image.map(|image| image.into()),
         }
     }
 }
@@ -172,23 +428,128 @@ impl Into<UpdateModifications> for CocktailDbModel {
         let bson_tags = mongodb::bson::to_bson(&self.tags).unwrap();
         let bson_composition_elements = mongodb::bson::to_bson(&self.composition_elements).unwrap();
         let bson_tools = mongodb::bson::to_bson(&self.tools).unwrap();
+        let bson_method = mongodb::bson::to_bson(&self.method).unwrap();
         let bson_recipe = mongodb::bson::to_bson(&self.recipe).unwrap();
 
         UpdateModifications::Document(doc! {"$set":{
             "name": self.name,
             "russian_name": self.russian_name,
+            "name_translations": mongodb::bson::to_bson(&self.name_translations).unwrap(),
             "country_of_origin": self.country_of_origin,
             "history": self.history,
+            "history_translations": mongodb::bson::to_bson(&self.history_translations).unwrap(),
             "url": self.url,
             "tags": bson_tags,
+            "glass": self.glass,
+            "method": bson_method,
             "composition_elements": bson_composition_elements,
             "tools": bson_tools,
-            "recipe": bson_recipe
+            "recipe": bson_recipe,
+            "updated_at": self.updated_at,
+            "slug": self.slug,
+            "previous_slugs": self.previous_slugs
         },
+        "$inc": {"version": 1},
         })
     }
 }
 
+impl Into<UpdateModifications> for &CocktailPatch {
+    fn into(self) -> UpdateModifications {
+        fn to_bson<T: Serialize>(value: &T) -> mongodb::bson::Bson {
+            mongodb::bson::to_bson(value).unwrap()
+        }
+
+        // removed fields are serialized as null and go to $unset
+        let changes = vec![
+            ("url", self.url.as_ref().map(to_bson)),
+            ("name", self.name.as_ref().map(to_bson)),
+            ("russian_name", self.russian_name.as_ref().map(to_bson)),
+            (
+                "name_translations",
+                self.name_translations.as_ref().map(to_bson),
+            ),
+            (
+                "country_of_origin",
+                self.country_of_origin.as_ref().map(to_bson),
+            ),
+            ("history", self.history.as_ref().map(to_bson)),
+            (
+                "history_translations",
+                self.history_translations.as_ref().map(to_bson),
+            ),
+            (
+                "tags",
+                self.tags.as_ref().map(|tags| {
+                    to_bson(&tags.as_ref().map(|tags| {
+                        tags.iter()
+                            .map(|tag| TagDbModel::from(tag.clone()))
+                            .collect::<Vec<_>>()
+                    }))
+                }),
+            ),
+            ("glass", self.glass.as_ref().map(to_bson)),
+            (
+                "method",
+                self.method
+                    .map(|method| to_bson(&method.map(PreparationMethodDbModel::from))),
+            ),
+            (
+                "tools",
+                self.tools.as_ref().map(|tools| {
+                    to_bson(&tools.as_ref().map(|tools| {
+                        tools
+                            .iter()
+                            .map(|tool| CocktailToolDbModel::from(tool.clone()))
+                            .collect::<Vec<_>>()
+                    }))
+                }),
+            ),
+            (
+                "composition_elements",
+                self.composition_elements
+                    .as_ref()
+                    .map(|composition_elements| {
+                        to_bson(&composition_elements.as_ref().map(|composition_elements| {
+                            composition_elements
+                                .iter()
+                                .map(|element| CocktailItemDbModel::from(element.clone()))
+                                .collect::<Vec<_>>()
+                        }))
+                    }),
+            ),
+            (
+                "recipe",
+                self.recipe
+                    .as_ref()
+                    .map(|recipe| to_bson(&recipe.clone().map(RecipeDbModel::from))),
+            ),
+            ("slug", self.slug.as_ref().map(to_bson)),
+            ("previous_slugs", self.previous_slugs.as_ref().map(to_bson)),
+        ];
+
+        let mut set = doc! {"updated_at": mongodb::bson::DateTime::now()};
+        let mut unset = doc! {};
+        for (field, value) in changes {
+            match value {
+                Some(mongodb::bson::Bson::Null) => {
+                    unset.insert(field, "");
+                }
+                Some(value) => {
+                    set.insert(field, value);
+                }
+                None => {}
+            }
+        }
+
+        let mut update = doc! {"$set": set, "$inc": {"version": 1}};
+        if !unset.is_empty() {
+            update.insert("$unset", unset);
+        }
+        UpdateModifications::Document(update)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TagDbModel {
     pub name: String,
@@ -213,11 +574,65 @@ impl Into<UpdateModifications> for TagDbModel {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PreparationMethodDbModel {
+    Shake,
+    Stir,
+    Build,
+    Blend,
+    Throw,
+}
+
+impl From<PreparationMethod> for PreparationMethodDbModel {
+    fn from(value: PreparationMethod) -> Self {
+        match value {
+            PreparationMethod::Shake => PreparationMethodDbModel::Shake,
+            PreparationMethod::Stir => PreparationMethodDbModel::Stir,
+            PreparationMethod::Build => PreparationMethodDbModel::Build,
+            PreparationMethod::Blend => PreparationMethodDbModel::Blend,
+            PreparationMethod::Throw => PreparationMethodDbModel::Throw,
+        }
+    }
+}
+
+impl Into<PreparationMethod> for PreparationMethodDbModel {
+    fn into(self) -> PreparationMethod {
+        match self {
+            PreparationMethodDbModel::Shake => PreparationMethod::Shake,
+            PreparationMethodDbModel::Stir => PreparationMethod::Stir,
+            PreparationMethodDbModel::Build => PreparationMethod::Build,
+            PreparationMethodDbModel::Blend => PreparationMethod::Blend,
+            PreparationMethodDbModel::Throw => PreparationMethod::Throw,
+        }
+    }
+}
+
+/// Legacy documents store tools with `count` and `unit`, those fields are ignored on read.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CocktailToolDbModel {
+    pub name: String,
+}
+
+impl From<CocktailTool> for CocktailToolDbModel {
+    fn from(value: CocktailTool) -> Self {
+        CocktailToolDbModel { name: value.name }
+    }
+}
+
+impl Into<CocktailTool> for CocktailToolDbModel {
+    fn into(self) -> CocktailTool {
+        CocktailTool { name: self.name }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CocktailItemDbModel {
     pub name: String,
     pub count: i32,
     pub unit: String,
+    #[serde(default)]
+    pub display_names: BTreeMap<String, String>,
 }
 
 impl From<CocktailItem> for CocktailItemDbModel {
@@ -226,6 +641,7 @@ impl From<CocktailItem> for CocktailItemDbModel {
             name: value.name,
             count: value.count,
             unit: value.unit,
+            display_names: value.display_names.into(),
         }
     }
 }
@@ -236,6 +652,7 @@ impl Into<CocktailItem> for CocktailItemDbModel {
             name: self.name,
             count: self.count,
             unit: self.unit,
+            display_names: self.display_names.into(),
         }
     }
 }
@@ -243,18 +660,439 @@ impl Into<CocktailItem> for CocktailItemDbModel {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RecipeDbModel {
     pub steps: Vec<String>,
+    #[serde(default)]
+    pub step_translations: Vec<BTreeMap<String, String>>,
 }
 
 impl From<Recipe> for RecipeDbModel {
     fn from(value: Recipe) -> Self {
         RecipeDbModel {
             steps: value.steps.iter().map(|x| x.to_string()).collect(),
+            step_translations: value
+                .step_translations
+                .into_iter()
+                .map(|translations| translations.into())
+                .collect(),
         }
     }
 }
 
 impl Into<Recipe> for RecipeDbModel {
     fn into(self) -> Recipe {
-        Recipe { steps: self.steps }
+        Recipe {
+            steps: self.steps,
+            step_translations: self
+                .step_translations
+                .into_iter()
+                .map(|translations| translations.into())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CocktailImageDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub uploaded_at: mongodb::bson::DateTime,
+    pub variants: Vec<ImageVariantDbModel>,
+}
+
+impl From<CocktailImage> for CocktailImageDbModel {
+    fn from(value: CocktailImage) -> Self {
+        CocktailImageDbModel {
+            id: mongodb::bson::uuid::Uuid::parse_str(value.id.to_string()).unwrap(),
+            uploaded_at: mongodb::bson::DateTime::from_millis(value.uploaded_at.timestamp_millis()),
+            variants: value
+                .variants
+                .into_iter()
+                .map(ImageVariantDbModel::from)
+                .collect(),
+        }
+    }
+}
+
+impl Into<CocktailImage> for CocktailImageDbModel {
+    fn into(self) -> CocktailImage {
+        CocktailImage {
+            id: Uuid::parse_str(&self.id.to_string()).unwrap(),
+            uploaded_at: chrono::DateTime::from_timestamp_millis(
+                self.uploaded_at.timestamp_millis(),
+            )
+            .unwrap_or_default(),
+            variants: self
+                .variants
+                .into_iter()
+                .map(|variant| variant.into())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImageVariantDbModel {
+    pub size: ImageSizeDbModel,
+    pub width: u32,
+    pub height: u32,
+    pub content_type: String,
+    pub key: String,
+    pub url: String,
+}
+
+impl From<ImageVariant> for ImageVariantDbModel {
+    fn from(value: ImageVariant) -> Self {
+        ImageVariantDbModel {
+            size: ImageSizeDbModel::from(value.size),
+            width: value.width,
+            height: value.height,
+            content_type: value.content_type,
+            key: value.key,
+            url: value.url,
+        }
+    }
+}
+
+impl Into<ImageVariant> for ImageVariantDbModel {
+    fn into(self) -> ImageVariant {
+        ImageVariant {
+            size: self.size.into(),
+            width: self.width,
+            height: self.height,
+            content_type: self.content_type,
+            key: self.key,
+            url: self.url,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageSizeDbModel {
+    Original,
+    Large,
+    Medium,
+    Thumbnail,
+}
+
+impl From<ImageSize> for ImageSizeDbModel {
+    fn from(value: ImageSize) -> Self {
+        match value {
+            ImageSize::Original => ImageSizeDbModel::Original,
+            ImageSize::Large => ImageSizeDbModel::Large,
+            ImageSize::Medium => ImageSizeDbModel::Medium,
+            ImageSize::Thumbnail => ImageSizeDbModel::Thumbnail,
+        }
+    }
+}
+
+impl Into<ImageSize> for ImageSizeDbModel {
+    fn into(self) -> ImageSize {
+        match self {
+            ImageSizeDbModel::Original => ImageSize::Original,
+            ImageSizeDbModel::Large => ImageSize::Large,
+            ImageSizeDbModel::Medium => ImageSize::Medium,
+            ImageSizeDbModel::Thumbnail => ImageSize::Thumbnail,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ToolDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub description: Option<String>,
+}
+
+impl From<Tool> for ToolDbModel {
+    fn from(value: Tool) -> Self {
+        ToolDbModel {
+            id: mongodb::bson::uuid::Uuid::parse_str(value.id.to_string()).unwrap(),
+            name: value.name,
+            russian_name: value.russian_name,
+            description: value.description,
+        }
+    }
+}
+
+impl Into<Tool> for ToolDbModel {
+    fn into(self) -> Tool {
+        Tool {
+            id: Uuid::parse_str(&self.id.to_string()).unwrap(),
+            name: self.name,
+            russian_name: self.russian_name,
+            description: self.description,
+        }
+    }
+}
+
+impl Into<UpdateModifications> for ToolDbModel {
+    fn into(self) -> UpdateModifications {
+        UpdateModifications::Document(doc! {"$set":{
+            "name": self.name,
+            "russian_name": self.russian_name,
+            "description": self.description
+        },
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GlasswareDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub name: Option<String>,
+    pub russian_name: String,
+    pub volume_ml: Option<i32>,
+    pub description: Option<String>,
+}
+
+impl From<Glassware> for GlasswareDbModel {
+    fn from(value: Glassware) -> Self {
+        GlasswareDbModel {
+            id: mongodb::bson::uuid::Uuid::parse_str(value.id.to_string()).unwrap(),
+            name: value.name,
+            russian_name: value.russian_name,
+            volume_ml: value.volume_ml,
+            description: value.description,
+        }
+    }
+}
+
+impl Into<Glassware> for GlasswareDbModel {
+    fn into(self) -> Glassware {
+        Glassware {
+            id: Uuid::parse_str(&self.id.to_string()).unwrap(),
+            name: self.name,
+            russian_name: self.russian_name,
+            volume_ml: self.volume_ml,
+            description: self.description,
+        }
+    }
+}
+
+impl Into<UpdateModifications> for GlasswareDbModel {
+    fn into(self) -> UpdateModifications {
+        UpdateModifications::Document(doc! {"$set":{
+            "name": self.name,
+            "russian_name": self.russian_name,
+            "volume_ml": self.volume_ml,
+            "description": self.description
+        },
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RatingSummaryDbModel {
+    pub average: f64,
+    pub count: i64,
+}
+
+impl From<RatingSummary> for RatingSummaryDbModel {
+    fn from(value: RatingSummary) -> Self {
+        RatingSummaryDbModel {
+            average: value.average,
+            count: value.count as i64,
+        }
+    }
+}
+
+impl Into<RatingSummary> for RatingSummaryDbModel {
+    fn into(self) -> RatingSummary {
+        RatingSummary {
+            average: self.average,
+            count: self.count as u64,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RatingDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub user_id: mongodb::bson::uuid::Uuid,
+    pub cocktail_id: mongodb::bson::uuid::Uuid,
+    pub stars: i32,
+    pub review: Option<String>,
+    pub created_at: mongodb::bson::DateTime,
+    pub updated_at: mongodb::bson::DateTime,
+}
+
+impl From<Rating> for RatingDbModel {
+    fn from(value: Rating) -> Self {
+        RatingDbModel {
+            id: mongodb::bson::uuid::Uuid::parse_str(value.id.to_string()).unwrap(),
+            user_id: mongodb::bson::uuid::Uuid::parse_str(value.user_id.to_string()).unwrap(),
+            cocktail_id: mongodb::bson::uuid::Uuid::parse_str(value.cocktail_id.to_string())
+                .unwrap(),
+            stars: value.stars as i32,
+            review: value.review,
+            created_at: mongodb::bson::DateTime::from_millis(value.created_at.timestamp_millis()),
+            updated_at: mongodb::bson::DateTime::from_millis(value.updated_at.timestamp_millis()),
+        }
+    }
+}
+
+impl Into<Rating> for RatingDbModel {
+    fn into(self) -> Rating {
+        Rating {
+            id: Uuid::parse_str(&self.id.to_string()).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id.to_string()).unwrap(),
+            cocktail_id: Uuid::parse_str(&self.cocktail_id.to_string()).unwrap(),
+            stars: self.stars as u8,
+            review: self.review,
+            created_at: chrono::DateTime::from_timestamp_millis(self.created_at.timestamp_millis())
+                .unwrap_or_default(),
+            updated_at: chrono::DateTime::from_timestamp_millis(self.updated_at.timestamp_millis())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CocktailRevisionDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub cocktail_id: mongodb::bson::uuid::Uuid,
+    pub number: i64,
+    pub action: RevisionAction,
+    pub author: Option<String>,
+    pub created_at: mongodb::bson::DateTime,
+    pub snapshot: CocktailDbModel,
+    pub diff: json_patch::Patch,
+}
+
+impl From<CocktailRevision> for CocktailRevisionDbModel {
+    fn from(value: CocktailRevision) -> Self {
+        CocktailRevisionDbModel {
+            id: mongodb::bson::Uuid::parse_str(value.id.to_string()).unwrap(),
+            cocktail_id: mongodb::bson::Uuid::parse_str(value.cocktail_id.to_string()).unwrap(),
+            number: value.number as i64,
+            action: value.action,
+            author: value.author,
+            created_at: mongodb::bson::DateTime::from_millis(value.created_at.timestamp_millis()),
+            snapshot: CocktailDbModel::from(value.snapshot),
+            diff: value.diff,
+        }
+    }
+}
+
+impl Into<CocktailRevision> for CocktailRevisionDbModel {
+    fn into(self) -> CocktailRevision {
+        CocktailRevision {
+            id: Uuid::parse_str(&self.id.to_string()).unwrap(),
+            cocktail_id: Uuid::parse_str(&self.cocktail_id.to_string()).unwrap(),
+            number: self.number as u64,
+            action: self.action,
+            author: self.author,
+            created_at: chrono::DateTime::from_timestamp_millis(self.created_at.timestamp_millis())
+                .unwrap_or_default(),
+            snapshot: self.snapshot.into(),
+            diff: self.diff,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CocktailWithMissingIngredientsDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub russian_name: String,
+    pub missing_ingredients: Vec<String>,
+}
+
+impl Into<CocktailWithMissingIngredients> for CocktailWithMissingIngredientsDbModel {
+    fn into(self) -> CocktailWithMissingIngredients {
+        CocktailWithMissingIngredients {
+            id: Uuid::parse_str(&self.id.to_string()).unwrap(),
+            russian_name: self.russian_name,
+            missing_ingredients: self.missing_ingredients,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CocktailTextScoreDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub russian_name: String,
+    pub text_score: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CollectionDbModel {
+    pub id: mongodb::bson::uuid::Uuid,
+    pub owner_id: mongodb::bson::uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub cocktail_ids: Vec<mongodb::bson::uuid::Uuid>,
+    pub created_at: mongodb::bson::DateTime,
+}
+
+impl From<CocktailCollection> for CollectionDbModel {
+    fn from(value: CocktailCollection) -> Self {
+        CollectionDbModel {
+            id: mongodb::bson::Uuid::parse_str(value.id.to_string()).unwrap(),
+            owner_id: mongodb::bson::Uuid::parse_str(value.owner_id.to_string()).unwrap(),
+            name: value.name,
+            description: value.description,
+            cocktail_ids: value
+                .cocktail_ids
+                .iter()
+                .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+                .collect(),
+            created_at: mongodb::bson::DateTime::from_millis(value.created_at.timestamp_millis()),
+        }
+    }
+}
+
+impl Into<CocktailCollection> for CollectionDbModel {
+    fn into(self) -> CocktailCollection {
+        CocktailCollection {
+            id: Uuid::parse_str(&self.id.to_string()).unwrap(),
+            owner_id: Uuid::parse_str(&self.owner_id.to_string()).unwrap(),
+            name: self.name,
+            description: self.description,
+            cocktail_ids: self
+                .cocktail_ids
+                .iter()
+                .map(|id| Uuid::parse_str(&id.to_string()).unwrap())
+                .collect(),
+            created_at: chrono::DateTime::from_timestamp_millis(self.created_at.timestamp_millis())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Into<UpdateModifications> for CollectionDbModel {
+    fn into(self) -> UpdateModifications {
+        UpdateModifications::Document(doc! {"$set":{
+            "name": self.name,
+            "description": self.description,
+            "cocktail_ids": self.cocktail_ids
+        },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_update_saves_language() {
+        let user = User {
+            id: Uuid::new_v4(),
+            telegram_id: 42,
+            favorite_cocktails: vec![],
+            bar_ingredients: vec![],
+            daily_subscription: None,
+            daily_last_sent_on: None,
+            viewed_cocktails: vec![],
+            language: Some("en".to_string()),
+        };
+        let update: UpdateModifications = UserDbModel::from(user).into();
+        let UpdateModifications::Document(update) = update else {
+            panic!("user update must be a document");
+        };
+        assert_eq!(
+            update.get_document("$set").unwrap().get_str("language"),
+            Ok("en")
+        );
     }
 }
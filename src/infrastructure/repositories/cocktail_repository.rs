@@ -1,16 +1,52 @@
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use mongodb::bson::doc;
+use mongodb::{bson::doc, options::IndexOptions, IndexModel};
+use tokio::sync::OnceCell;
 use tokio_stream::StreamExt;
 
 use crate::{
-    domain::aggregates::cocktail::{Cocktail, CocktailFilter, CocktailRepo, CocktailsPaged},
+    domain::{
+        aggregates::{
+            cocktail::{
+                spirits::whole_word_regex, CatalogueStats, Cocktail, CocktailError, CocktailFacets,
+                CocktailFilter, CocktailImage, CocktailPatch, CocktailRepo, CocktailSort,
+                CocktailWithTextScore, CocktailsByIngredientsPaged, CocktailsPaged,
+                CocktailsTextSearchPaged, FacetValue, RandomCocktailFilter, TagWithCount,
+                BASE_SPIRITS, NON_ALCOHOLIC_TAGS,
+            },
+            rating::RatingSummary,
+        },
+        units::{MILLILITRES, UNIT_CONVERSIONS},
+        Pagination,
+    },
     infrastructure::{
         configurations::DbConfiguration,
-        mongo::{CocktailDbModel, MongoDbClient},
+        mongo::{
+            is_duplicate_key_error, CocktailDbModel, CocktailImageDbModel,
+            CocktailTextScoreDbModel, CocktailWithMissingIngredientsDbModel, MongoDbClient,
+            RatingSummaryDbModel, COCKTAILS_COLLECTION, COCKTAIL_ENGLISH_TEXTS_COLLECTION,
+        },
     },
 };
 
+const TEXT_INDEX_NAME: &str = "cocktails_text";
+
+/// Text index is created once per process, before the first text search.
+static TEXT_INDEX: OnceCell<()> = OnceCell::const_new();
+
+const ENGLISH_TEXT_INDEX_NAME: &str = "cocktail_english_texts_text";
+
+/// English text index is created and filled with texts of all cocktails once per process,
+/// before the first text search.
+static ENGLISH_TEXT_INDEX: OnceCell<()> = OnceCell::const_new();
+
+const SLUG_INDEX_NAME: &str = "cocktails_slug";
+
+/// Slug index is created once per process, before the first write of a slug.
+static SLUG_INDEX: OnceCell<()> = OnceCell::const_new();
+
 #[derive(Debug, Clone)]
 pub struct CocktailRepository {
     db_client: MongoDbClient,
@@ -23,84 +59,648 @@ impl CocktailRepository {
             .context("failed to create mongodb client from cocktail repository")?;
         Ok(Self { db_client: client })
     }
-}
 
-#[async_trait]
-impl CocktailRepo for CocktailRepository {
-    async fn create(&self, entity: &Cocktail) {
-        let _insert_result = self
-            .db_client
-            .get_cocktails_collection()
-            .insert_one(CocktailDbModel::from(entity.clone()))
+    fn sort_document(sort: &Option<CocktailSort>) -> mongodb::bson::Document {
+        match sort {
+            Some(CocktailSort::Name) => doc! {"russian_name": 1},
+            Some(CocktailSort::Rating) => {
+                doc! {"rating.average": -1, "rating.count": -1, "russian_name": 1}
+            }
+            None => doc! {},
+        }
+    }
+
+    /// Matches cocktails not moved to trash, every query except trash listing starts from it.
+    fn not_deleted() -> mongodb::bson::Document {
+        doc! {"deleted_at": null}
+    }
+
+    /// Matches the cocktail only while it has the expected version, in trash or not.
+    /// Version 0 also matches cocktails stored before versions were tracked.
+    fn stored_version_filter(id: &uuid::Uuid, version: u64) -> mongodb::bson::Document {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+        if version == 0 {
+            doc! {"id": &uuid_mongo, "version": {"$in": [0, null]}}
+        } else {
+            doc! {"id": &uuid_mongo, "version": version as i64}
+        }
+    }
+
+    /// Matches the cocktail only while it has the expected version and is not in trash.
+    fn version_filter(id: &uuid::Uuid, version: u64) -> mongodb::bson::Document {
+        let mut filter = Self::stored_version_filter(id, version);
+        filter.extend(Self::not_deleted());
+        filter
+    }
+
+    /// Same as `version_filter`, but matches the cocktail only while it is in trash.
+    fn deleted_version_filter(id: &uuid::Uuid, version: u64) -> mongodb::bson::Document {
+        let mut filter = Self::stored_version_filter(id, version);
+        filter.insert("deleted_at", doc! {"$ne": null});
+        filter
+    }
+
+    /// Collection can have only one text index, so it uses russian stemming.
+    /// English texts are searched with english stemming in a separate collection,
+    /// see `ensure_english_text_index`.
+    async fn ensure_text_index(&self) -> Result<()> {
+        TEXT_INDEX
+            .get_or_try_init(|| async {
+                let index = IndexModel::builder()
+                    .keys(doc! {
+                        "name": "text",
+                        "russian_name": "text",
+                        "history": "text",
+                        "recipe.steps": "text",
+                        "composition_elements.name": "text",
+                        "tags.name": "text",
+                    })
+                    .options(
+                        IndexOptions::builder()
+                            .name(TEXT_INDEX_NAME.to_string())
+                            .default_language("russian".to_string())
+                            .weights(doc! {
+                                "name": 10,
+                                "russian_name": 10,
+                                "composition_elements.name": 5,
+                                "tags.name": 3,
+                                "history": 1,
+                                "recipe.steps": 1,
+                            })
+                            .build(),
+                    )
+                    .build();
+                self.db_client
+                    .get_cocktails_collection()
+                    .create_index(index)
+                    .await
+                    .context("failed to create cocktails text index")
+                    .map(|_| ())
+            })
             .await
-            .expect("Error while insert user to database");
+            .map(|_| ())
     }
 
-    async fn delete(&self, entity: &Cocktail) {
-        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
-        let delete_filter = doc! {"id":  &uuid_mongo};
-        let _insert_result = self
-            .db_client
-            .get_cocktails_collection()
-            .find_one_and_delete(delete_filter)
+    /// English texts are copied by `refresh_english_texts` to a collection with its own
+    /// text index. Texts of all cocktails are copied when the index is created, so cocktails
+    /// stored before or missed by a failed copy are found too.
+    async fn ensure_english_text_index(&self) -> Result<()> {
+        ENGLISH_TEXT_INDEX
+            .get_or_try_init(|| async {
+                // weights are the same as in the russian index to keep the scores comparable
+                let index = IndexModel::builder()
+                    .keys(doc! {
+                        "name": "text",
+                        "translated_name": "text",
+                        "history": "text",
+                        "steps": "text",
+                        "ingredients": "text",
+                    })
+                    .options(
+                        IndexOptions::builder()
+                            .name(ENGLISH_TEXT_INDEX_NAME.to_string())
+                            .default_language("english".to_string())
+                            .weights(doc! {
+                                "name": 10,
+                                "translated_name": 10,
+                                "ingredients": 5,
+                                "history": 1,
+                                "steps": 1,
+                            })
+                            .build(),
+                    )
+                    .build();
+                self.db_client
+                    .get_cocktail_english_texts_collection()
+                    .create_index(index)
+                    .await
+                    .context("failed to create cocktail english texts index")?;
+                self.refresh_english_texts(doc! {}).await
+            })
             .await
-            .expect("Error while insert user to database");
+            .map(|_| ())
     }
 
-    async fn update(&self, entity: &Cocktail) {
-        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
-        let _insert_result = self
-            .db_client
+    /// Copies english texts of cocktails matching the filter to the collection with
+    /// the english text index.
+    async fn refresh_english_texts(&self, filter: mongodb::bson::Document) -> Result<()> {
+        let pipeline = vec![
+            doc! {"$match": filter},
+            doc! {"$project": {
+                "_id": "$id",
+                "name": "$name",
+                "translated_name": "$name_translations.en",
+                "history": "$history_translations.en",
+                "steps": "$recipe.step_translations.en",
+                "ingredients": "$composition_elements.display_names.en",
+            }},
+            doc! {"$merge": {
+                "into": COCKTAIL_ENGLISH_TEXTS_COLLECTION,
+                "on": "_id",
+                "whenMatched": "replace",
+                "whenNotMatched": "insert",
+            }},
+        ];
+        self.db_client
             .get_cocktails_collection()
-            .find_one_and_update(
-                doc! {"id": &uuid_mongo},
-                CocktailDbModel::from(entity.clone()),
-            )
+            .aggregate(pipeline)
             .await
-            .expect("Error while insert user to database");
+            .context("failed to copy english texts of cocktails")?;
+
+        Ok(())
     }
 
-    async fn get_names(&self, filter: &CocktailFilter) -> Result<CocktailsPaged> {
-        let filter_by_ids = if !filter.ids.is_some() {
-            let uuids_doc: Vec<mongodb::bson::Document> = filter
-                .ids
-                .as_ref()
-                .map(|ids| {
-                    ids
-                    .iter()
-                    .map(|id| doc! {"id": mongodb::bson::Uuid::parse_str(id.to_string()).unwrap()})
-                    .collect()
-                })
-                .unwrap();
-            doc! {"$or":uuids_doc}
+    /// English texts follow the stored cocktail, a failed copy is only logged as the write
+    /// itself succeeded. The texts are copied again on the next write or start.
+    async fn sync_english_texts(&self, id: &uuid::Uuid) {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+        if let Err(err) = self.refresh_english_texts(doc! {"id": uuid_mongo}).await {
+            log::error!("Failed to copy english texts of cocktail {}: {:?}", id, err);
+        }
+    }
+
+    async fn read_text_scores(
+        mut cursor: mongodb::Cursor<mongodb::bson::Document>,
+    ) -> Result<Vec<CocktailTextScoreDbModel>> {
+        let mut scores = vec![];
+        while let Some(document) = cursor.next().await {
+            let document = document.context("failed to read text search result from cursor")?;
+            scores.push(
+                mongodb::bson::from_document(document)
+                    .context("failed to read text score of cocktail")?,
+            );
+        }
+
+        Ok(scores)
+    }
+
+    /// Slug is the only unique field, so a duplicate key means the slug was taken
+    /// by another request after it was checked.
+    fn slug_error(err: mongodb::error::Error, slug: Option<&str>) -> anyhow::Error {
+        if is_duplicate_key_error(&err) {
+            CocktailError::SlugTaken(slug.unwrap_or_default().to_string()).into()
+        } else {
+            err.into()
+        }
+    }
+
+    /// Cocktails stored before slugs were introduced have no slug and are not indexed.
+    async fn ensure_slug_index(&self) -> Result<()> {
+        SLUG_INDEX
+            .get_or_try_init(|| async {
+                let index = IndexModel::builder()
+                    .keys(doc! {"slug": 1})
+                    .options(
+                        IndexOptions::builder()
+                            .name(SLUG_INDEX_NAME.to_string())
+                            .unique(true)
+                            .partial_filter_expression(doc! {"slug": {"$type": "string"}})
+                            .build(),
+                    )
+                    .build();
+                self.db_client
+                    .get_cocktails_collection()
+                    .create_index(index)
+                    .await
+                    .context("failed to create cocktails slug index")
+                    .map(|_| ())
+            })
+            .await
+            .map(|_| ())
+    }
+
+    fn filter_document(filter: &CocktailFilter) -> mongodb::bson::Document {
+        let filter_by_ids = if let Some(ids) = &filter.ids {
+            let uuids: Vec<mongodb::bson::Uuid> = ids
+                .iter()
+                .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+                .collect();
+            doc! {"id": {"$in": uuids}}
         } else {
             doc! {}
         };
 
         let filter_by_en_names = if let Some(names) = &filter.names {
-            let names_doc: Vec<mongodb::bson::Document> =
-                names.iter().map(|name| doc! {"name": doc!{"$regex": mongodb::bson::Regex{ pattern: format!("[a-zA-Zа-яА-Я0-9 ]*({})[a-zA-Zа-яА-Я0-9 ]*", name), options: "mi".to_string() }}}).collect();
+            let names_doc: Vec<mongodb::bson::Document> = names
+                .iter()
+                .map(|name| doc! {"name": doc!{"$regex": Self::name_regex(name)}})
+                .collect();
             doc! {"$or": names_doc}
         } else {
             doc! {}
         };
 
         let filter_by_russian_names = if let Some(rus_names) = &filter.russian_names {
-            let names_doc: Vec<mongodb::bson::Document> =
-                rus_names.iter().map(|name| doc! {"russian_name": doc!{"$regex": mongodb::bson::Regex{ pattern: format!("[a-zA-Zа-яА-Я0-9 ]*({})[a-zA-Zа-яА-Я0-9 ]*", name), options: "mi".to_string() }}}).collect();
+            let names_doc: Vec<mongodb::bson::Document> = rus_names
+                .iter()
+                .map(|name| doc! {"russian_name": doc!{"$regex": Self::name_regex(name)}})
+                .collect();
             doc! {"$or": names_doc}
         } else {
             doc! {}
         };
 
+        let filter_by_tags = if let Some(tags) = &filter.tags {
+            doc! {"tags.name": {"$in": tags}}
+        } else {
+            doc! {}
+        };
+
+        let filter_by_countries = if let Some(countries) = &filter.countries {
+            doc! {"country_of_origin": {"$in": countries}}
+        } else {
+            doc! {}
+        };
+
         let filter_by_names = doc! {"$or": vec![filter_by_en_names, filter_by_russian_names]};
-        let filter_document = doc! {"$and": vec![filter_by_names, filter_by_ids]};
+        doc! {"$and": vec![
+            Self::not_deleted(),
+            filter_by_names,
+            filter_by_ids,
+            filter_by_tags,
+            filter_by_countries,
+        ]}
+    }
+
+    /// Expression evaluating to the name of the spirit with the largest amount in composition,
+    /// the same as `Cocktail::base_spirit`: names are matched by whole words and amounts
+    /// are compared in millilitres.
+    fn base_spirit_expression() -> mongodb::bson::Document {
+        let spirits_regex = whole_word_regex(
+            &BASE_SPIRITS
+                .iter()
+                .map(|(word, _)| *word)
+                .collect::<Vec<_>>()
+                .join("|"),
+        );
+        let millilitres_branches: Vec<mongodb::bson::Document> = UNIT_CONVERSIONS
+            .iter()
+            .filter(|(_, _, base_unit)| *base_unit == MILLILITRES)
+            .map(|(spellings, multiplier, _)| {
+                let unit_regex = format!(r"^\s*({})\.*\s*$", spellings.join("|"));
+                doc! {
+                    "case": {"$regexMatch": {"input": "$$element.unit", "regex": unit_regex, "options": "i"}},
+                    "then": {"$multiply": ["$$element.count", *multiplier]},
+                }
+            })
+            .collect();
+        let spirit_branches: Vec<mongodb::bson::Document> = BASE_SPIRITS
+            .iter()
+            .map(|(word, spirit)| {
+                doc! {
+                    "case": {"$regexMatch": {"input": "$$base.name", "regex": whole_word_regex(word), "options": "i"}},
+                    "then": *spirit,
+                }
+            })
+            .collect();
+        doc! {"$let": {
+            "vars": {"base": {"$reduce": {
+                "input": {"$map": {
+                    "input": {"$filter": {
+                        "input": {"$ifNull": ["$composition_elements", []]},
+                        "as": "element",
+                        "cond": {"$regexMatch": {"input": "$$element.name", "regex": spirits_regex, "options": "i"}},
+                    }},
+                    "as": "element",
+                    "in": {
+                        "name": "$$element.name",
+                        "amount": {"$switch": {"branches": millilitres_branches, "default": 0}},
+                    },
+                }},
+                "initialValue": null,
+                "in": {"$cond": [
+                    {"$or": [{"$eq": ["$$value", null]}, {"$gt": ["$$this.amount", "$$value.amount"]}]},
+                    "$$this",
+                    "$$value",
+                ]},
+            }}},
+            "in": {"$switch": {"branches": spirit_branches, "default": null}},
+        }}
+    }
+
+    /// Facet stages counting cocktails per value of the field, empty values are skipped.
+    fn count_by_stages(field: &str) -> Vec<mongodb::bson::Document> {
+        let path = format!("${}", field);
+        vec![
+            doc! {"$match": {field: {"$nin": [null, ""]}}},
+            doc! {"$group": {"_id": path, "count": {"$sum": 1}}},
+            doc! {"$sort": {"count": -1, "_id": 1}},
+        ]
+    }
+
+    fn facets_stages() -> mongodb::bson::Document {
+        let mut tags_stages = vec![doc! {"$unwind": "$tags"}];
+        tags_stages.extend(Self::count_by_stages("tags.name"));
+        doc! {
+            "tags": tags_stages,
+            "countries": Self::count_by_stages("country_of_origin"),
+            "base_spirits": Self::count_by_stages("base_spirit"),
+            "glasses": Self::count_by_stages("glass"),
+            "methods": Self::count_by_stages("method"),
+        }
+    }
+
+    fn read_count(document: &mongodb::bson::Document, key: &str) -> u64 {
+        document
+            .get_array(key)
+            .ok()
+            .and_then(|total| total.first())
+            .and_then(|total| total.as_document())
+            .and_then(|total| total.get("count"))
+            .and_then(|count| count.as_i32().map(i64::from).or(count.as_i64()))
+            .unwrap_or_default() as u64
+    }
+
+    fn read_facet_values(document: &mongodb::bson::Document, key: &str) -> Vec<FacetValue> {
+        document
+            .get_array(key)
+            .into_iter()
+            .flatten()
+            .filter_map(|value| value.as_document())
+            .filter_map(|value| {
+                let count = value
+                    .get("count")
+                    .and_then(|count| count.as_i32().map(i64::from).or(count.as_i64()))?;
+                Some(FacetValue {
+                    value: value.get_str("_id").ok()?.to_string(),
+                    count: count as u64,
+                })
+            })
+            .collect()
+    }
+
+    fn read_facets(document: &mongodb::bson::Document) -> CocktailFacets {
+        CocktailFacets {
+            tags: Self::read_facet_values(document, "tags"),
+            countries: Self::read_facet_values(document, "countries"),
+            base_spirits: Self::read_facet_values(document, "base_spirits"),
+            glasses: Self::read_facet_values(document, "glasses"),
+            methods: Self::read_facet_values(document, "methods"),
+        }
+    }
+
+    /// Case insensitive substring match, user input is escaped so it is matched literally.
+    fn name_regex(name: &str) -> mongodb::bson::Regex {
+        let mut pattern = String::with_capacity(name.len());
+        for c in name.chars() {
+            if "\\^$.|?*+()[]{}/-".contains(c) {
+                pattern.push('\\');
+            }
+            pattern.push(c);
+        }
+        mongodb::bson::Regex {
+            pattern,
+            options: "i".to_string(),
+        }
+    }
+
+    /// Case insensitive match of the whole name.
+    fn exact_name_regex(name: &str) -> mongodb::bson::Regex {
+        let mut regex = Self::name_regex(name.trim());
+        regex.pattern = format!("^{}$", regex.pattern);
+        regex
+    }
+
+    fn random_filter_document(filter: &RandomCocktailFilter) -> mongodb::bson::Document {
+        let mut conditions = vec![Self::not_deleted()];
+        if let Some(tag) = &filter.tag {
+            conditions.push(doc! {"tags.name": tag});
+        }
+        if filter.non_alcoholic {
+            conditions.push(doc! {"tags.name": {"$in": NON_ALCOHOLIC_TAGS}});
+        }
+        if let Some(max_ingredients) = filter.max_ingredients {
+            conditions.push(doc! {"$expr": {"$lte": [
+                {"$size": {"$ifNull": ["$composition_elements", []]}},
+                max_ingredients as i64
+            ]}});
+        }
+        doc! {"$and": conditions}
+    }
+}
+
+#[async_trait]
+impl CocktailRepo for CocktailRepository {
+    async fn create(&self, entity: &Cocktail) -> Result<()> {
+        self.ensure_slug_index().await?;
+        self.db_client
+            .get_cocktails_collection()
+            .insert_one(CocktailDbModel::from(entity.clone()))
+            .await
+            .map_err(|err| Self::slug_error(err, entity.slug.as_deref()))
+            .context("failed to insert cocktail")?;
+        self.sync_english_texts(&entity.id).await;
+
+        Ok(())
+    }
+
+    async fn delete(&self, entity: &Cocktail) -> Result<()> {
+        let now = mongodb::bson::DateTime::now();
+        let delete_result = self
+            .db_client
+            .get_cocktails_collection()
+            .update_one(
+                Self::version_filter(&entity.id, entity.version),
+                doc! {
+                    "$set": {"deleted_at": now, "updated_at": now},
+                    "$inc": {"version": 1},
+                },
+            )
+            .await
+            .context("failed to move cocktail to trash")?;
+        if delete_result.matched_count == 0 {
+            return Err(CocktailError::VersionConflict {
+                id: entity.id,
+                version: entity.version,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn get_deleted(&self, pagination: &Pagination) -> Result<CocktailsPaged> {
+        let filter_document = doc! {"deleted_at": {"$ne": null}};
+
+        let result = self
+            .db_client
+            .get_cocktails_collection()
+            .find(filter_document.clone())
+            .sort(doc! {"deleted_at": -1})
+            .limit(pagination.items_per_page as i64)
+            .skip(pagination.page * pagination.items_per_page)
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect deleted cocktails in result")?;
+
+        let count_by_filter = self
+            .db_client
+            .get_cocktails_collection()
+            .count_documents(filter_document)
+            .await
+            .context("failed to count deleted cocktail documents")?;
+
+        Ok(CocktailsPaged {
+            items: result,
+            total_count: count_by_filter,
+        })
+    }
+
+    async fn get_deleted_by_id(&self, id: &uuid::Uuid) -> Result<Option<Cocktail>> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+        self.db_client
+            .get_cocktails_collection()
+            .find_one(doc! {"id": &uuid_mongo, "deleted_at": {"$ne": null}})
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context(format!("Deleted coctail with id {} not found", uuid_mongo))
+    }
+
+    async fn restore_deleted(&self, entity: &Cocktail) -> Result<Cocktail> {
+        let restored = self
+            .db_client
+            .get_cocktails_collection()
+            .find_one_and_update(
+                Self::deleted_version_filter(&entity.id, entity.version),
+                doc! {
+                    "$unset": {"deleted_at": ""},
+                    "$set": {"updated_at": mongodb::bson::DateTime::now()},
+                    "$inc": {"version": 1},
+                },
+            )
+            .return_document(mongodb::options::ReturnDocument::After)
+            .await
+            .context("failed to restore cocktail from trash")?;
+
+        match restored {
+            Some(restored) => Ok(restored.into()),
+            None => Err(CocktailError::VersionConflict {
+                id: entity.id,
+                version: entity.version,
+            }
+            .into()),
+        }
+    }
+
+    async fn get_expired_deleted(
+        &self,
+        deleted_before: &chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Cocktail>> {
+        let deleted_before =
+            mongodb::bson::DateTime::from_millis(deleted_before.timestamp_millis());
+        self.db_client
+            .get_cocktails_collection()
+            .find(doc! {"deleted_at": {"$lt": deleted_before}})
+            .projection(doc! {"id": 1, "russian_name": 1, "image": 1})
+            .await
+            .context("failed to find expired cocktails in trash")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect expired cocktails in trash")
+    }
+
+    async fn purge_deleted(
+        &self,
+        expired: &[Cocktail],
+        deleted_before: &chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Cocktail>> {
+        if expired.is_empty() {
+            return Ok(vec![]);
+        }
+        let deleted_before =
+            mongodb::bson::DateTime::from_millis(deleted_before.timestamp_millis());
+        let collection = self.db_client.get_cocktails_collection();
+        let ids: Vec<mongodb::bson::Uuid> = expired
+            .iter()
+            .map(|cocktail| mongodb::bson::Uuid::parse_str(cocktail.id.to_string()).unwrap())
+            .collect();
+        // deleted_at is checked again, cocktail restored in between is kept
+        collection
+            .delete_many(doc! {"id": {"$in": &ids}, "deleted_at": {"$lt": deleted_before}})
+            .await
+            .context("failed to purge cocktails from trash")?;
+        let kept: Vec<Cocktail> = collection
+            .find(doc! {"id": {"$in": &ids}})
+            .projection(doc! {"id": 1, "russian_name": 1})
+            .await
+            .context("failed to find cocktails kept in trash")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect cocktails kept in trash")?;
+
+        let purged: Vec<Cocktail> = expired
+            .iter()
+            .filter(|expired| !kept.iter().any(|cocktail| cocktail.id == expired.id))
+            .cloned()
+            .collect();
+        let purged_ids: Vec<mongodb::bson::Uuid> = purged
+            .iter()
+            .map(|cocktail| mongodb::bson::Uuid::parse_str(cocktail.id.to_string()).unwrap())
+            .collect();
+        self.db_client
+            .get_cocktail_english_texts_collection()
+            .delete_many(doc! {"_id": {"$in": &purged_ids}})
+            .await
+            .context("failed to delete english texts of purged cocktails")?;
+
+        Ok(purged)
+    }
+
+    async fn update(&self, entity: &Cocktail) -> Result<()> {
+        self.ensure_slug_index().await?;
+        let update_result = self
+            .db_client
+            .get_cocktails_collection()
+            .update_one(
+                Self::version_filter(&entity.id, entity.version),
+                CocktailDbModel::from(entity.clone()),
+            )
+            .await
+            .map_err(|err| Self::slug_error(err, entity.slug.as_deref()))
+            .context("failed to update cocktail")?;
+        if update_result.matched_count == 0 {
+            return Err(CocktailError::VersionConflict {
+                id: entity.id,
+                version: entity.version,
+            }
+            .into());
+        }
+        self.sync_english_texts(&entity.id).await;
+
+        Ok(())
+    }
+
+    async fn patch(&self, id: &uuid::Uuid, version: u64, patch: &CocktailPatch) -> Result<()> {
+        self.ensure_slug_index().await?;
+        let update_result = self
+            .db_client
+            .get_cocktails_collection()
+            .update_one(Self::version_filter(id, version), patch)
+            .await
+            .map_err(|err| Self::slug_error(err, patch.slug.as_deref()))
+            .context("failed to patch cocktail")?;
+        if update_result.matched_count == 0 {
+            return Err(CocktailError::VersionConflict { id: *id, version }.into());
+        }
+        self.sync_english_texts(id).await;
+
+        Ok(())
+    }
+
+    async fn get_names(&self, filter: &CocktailFilter) -> Result<CocktailsPaged> {
+        let filter_document = Self::filter_document(filter);
 
         let result = self
             .db_client
             .get_cocktails_collection()
             .find(filter_document.clone())
             .projection(doc! {"id":1, "russian_name":1})
+            .sort(Self::sort_document(&filter.sort))
             .limit(filter.pagination.items_per_page as i64)
             .skip(filter.pagination.page * filter.pagination.items_per_page)
             .await
@@ -130,46 +730,135 @@ impl CocktailRepo for CocktailRepository {
         let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
 
         cocktail_collection
-            .find_one(doc! {"id": &uuid_mongo})
+            .find_one(doc! {"id": &uuid_mongo, "deleted_at": null})
             .await
             .map(|x| x.map(|x| x.into()))
             .context(format!("Coctail with id {} not found", uuid_mongo))
     }
 
-    async fn get_by_filter(&self, filter: &CocktailFilter) -> Result<CocktailsPaged> {
-        let filter_by_ids = if let Some(ids) = &filter.ids {
-            let uuids_doc: Vec<mongodb::bson::Document> = ids
-                .iter()
-                .map(|id| doc! {"id": mongodb::bson::Uuid::parse_str(id.to_string()).unwrap()})
-                .collect();
-            doc! {"$or":uuids_doc}
-        } else {
-            doc! {}
-        };
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<Cocktail>> {
+        self.db_client
+            .get_cocktails_collection()
+            .find_one(doc! {
+                "$or": [{"slug": slug}, {"previous_slugs": slug}],
+                "deleted_at": null,
+            })
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context(format!("Coctail with slug {} not found", slug))
+    }
 
-        let filter_by_en_names = if let Some(names) = &filter.names {
-            let names_doc: Vec<mongodb::bson::Document> =
-                names.iter().map(|name| doc! {"name": doc!{"$regex": mongodb::bson::Regex{ pattern: format!("[a-zA-Zа-яА-Я0-9 ]*({})[a-zA-Zа-яА-Я0-9 ]*", name), options: "mi".to_string() }}}).collect();
-            doc! {"$or": names_doc}
-        } else {
-            doc! {}
-        };
+    async fn is_slug_taken(&self, slug: &str, except_id: &uuid::Uuid) -> Result<bool> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(except_id.to_string()).unwrap();
+        let count = self
+            .db_client
+            .get_cocktails_collection()
+            .count_documents(doc! {
+                "$or": [{"slug": slug}, {"previous_slugs": slug}],
+                "id": {"$ne": &uuid_mongo},
+            })
+            .await
+            .context("failed to count cocktails with slug")?;
 
-        let filter_by_russian_names = if let Some(rus_names) = &filter.russian_names {
-            let names_doc: Vec<mongodb::bson::Document> =
-                rus_names.iter().map(|name| doc! {"russian_name": doc!{"$regex": mongodb::bson::Regex{ pattern: format!("[a-zA-Zа-яА-Я0-9 ]*({})[a-zA-Zа-яА-Я0-9 ]*", name), options: "mi".to_string() }}}).collect();
-            doc! {"$or": names_doc}
-        } else {
-            doc! {}
+        Ok(count > 0)
+    }
+
+    async fn get_without_slug(&self) -> Result<Vec<Cocktail>> {
+        let result = self
+            .db_client
+            .get_cocktails_collection()
+            .find(doc! {"slug": {"$not": {"$type": "string"}}})
+            .projection(doc! {"id": 1, "name": 1, "russian_name": 1, "previous_slugs": 1})
+            .await
+            .context("failed to find cocktails without slug")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect cocktails without slug")?;
+
+        Ok(result)
+    }
+
+    async fn set_slug(&self, id: &uuid::Uuid, slug: &str) -> Result<()> {
+        self.ensure_slug_index().await?;
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+        self.db_client
+            .get_cocktails_collection()
+            .update_one(
+                doc! {"id": &uuid_mongo, "slug": {"$not": {"$type": "string"}}},
+                doc! {"$set": {"slug": slug}},
+            )
+            .await
+            .context("failed to set cocktail slug")?;
+
+        Ok(())
+    }
+
+    async fn set_image(&self, entity: &Cocktail, image: Option<&CocktailImage>) -> Result<()> {
+        let update = match image {
+            Some(image) => {
+                let bson_image =
+                    mongodb::bson::to_bson(&CocktailImageDbModel::from(image.clone())).unwrap();
+                doc! {
+                    "$set": {"image": bson_image, "updated_at": mongodb::bson::DateTime::now()},
+                    "$inc": {"version": 1},
+                }
+            }
+            None => doc! {
+                "$unset": {"image": ""},
+                "$set": {"updated_at": mongodb::bson::DateTime::now()},
+                "$inc": {"version": 1},
+            },
         };
+        let update_result = self
+            .db_client
+            .get_cocktails_collection()
+            .update_one(Self::version_filter(&entity.id, entity.version), update)
+            .await
+            .context("failed to set cocktail image")?;
+        if update_result.matched_count == 0 {
+            return Err(CocktailError::VersionConflict {
+                id: entity.id,
+                version: entity.version,
+            }
+            .into());
+        }
 
-        let filter_by_names = doc! {"$or": vec![filter_by_en_names, filter_by_russian_names]};
-        let filter_document = doc! {"$and": vec![filter_by_names, filter_by_ids]};
+        Ok(())
+    }
+
+    async fn get_by_exact_names(
+        &self,
+        name: Option<&str>,
+        russian_name: &str,
+    ) -> Result<Vec<Cocktail>> {
+        let mut names = vec![doc! {"russian_name": Self::exact_name_regex(russian_name)}];
+        if let Some(name) = name {
+            names.push(doc! {"name": Self::exact_name_regex(name)});
+        }
+        let result = self
+            .db_client
+            .get_cocktails_collection()
+            .find(doc! {"$and": [Self::not_deleted(), {"$or": names}]})
+            .projection(doc! {"id": 1, "name": 1, "russian_name": 1})
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect cocktails in result")?;
+
+        Ok(result)
+    }
+
+    async fn get_by_filter(&self, filter: &CocktailFilter) -> Result<CocktailsPaged> {
+        let filter_document = Self::filter_document(filter);
 
         let result = self
             .db_client
             .get_cocktails_collection()
             .find(filter_document.clone())
+            .sort(Self::sort_document(&filter.sort))
             .limit(filter.pagination.items_per_page as i64)
             .skip(filter.pagination.page * filter.pagination.items_per_page)
             .await
@@ -193,4 +882,463 @@ impl CocktailRepo for CocktailRepository {
 
         Ok(result)
     }
+
+    async fn get_names_for_search(&self) -> Result<Vec<Cocktail>> {
+        let result = self
+            .db_client
+            .get_cocktails_collection()
+            .find(Self::not_deleted())
+            .projection(doc! {
+                "id": 1,
+                "name": 1,
+                "russian_name": 1,
+                "tags": 1,
+                "country_of_origin": 1,
+            })
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect cocktails in result")?;
+
+        Ok(result)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Cocktail>> {
+        let result = self
+            .db_client
+            .get_cocktails_collection()
+            .find(Self::not_deleted())
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect cocktails in result")?;
+
+        Ok(result)
+    }
+
+    async fn update_rating(&self, id: &uuid::Uuid, summary: &RatingSummary) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+        let bson_rating =
+            mongodb::bson::to_bson(&RatingSummaryDbModel::from(summary.clone())).unwrap();
+        let mut filter_document = Self::not_deleted();
+        filter_document.insert("id", uuid_mongo);
+        self.db_client
+            .get_cocktails_collection()
+            .update_one(
+                filter_document,
+                doc! {"$set": {"rating": bson_rating, "updated_at": mongodb::bson::DateTime::now()}},
+            )
+            .await
+            .context("failed to update cocktail rating")?;
+
+        Ok(())
+    }
+
+    async fn get_tags(&self) -> Result<Vec<TagWithCount>> {
+        let pipeline = vec![
+            doc! {"$match": Self::not_deleted()},
+            doc! {"$unwind": "$tags"},
+            doc! {"$group": {"_id": "$tags.name", "cocktails_count": {"$sum": 1}}},
+            doc! {"$sort": {"_id": 1}},
+        ];
+
+        let mut cursor = self
+            .db_client
+            .get_cocktails_collection()
+            .aggregate(pipeline)
+            .await
+            .context("failed to aggregate cocktail tags")?;
+
+        let mut result = vec![];
+        while let Some(tag_doc) = cursor.next().await {
+            let tag_doc = tag_doc.context("failed to read cocktail tag from cursor")?;
+            result.push(TagWithCount {
+                name: tag_doc.get_str("_id").unwrap_or_default().to_string(),
+                cocktails_count: tag_doc
+                    .get("cocktails_count")
+                    .and_then(|count| count.as_i32().map(i64::from).or(count.as_i64()))
+                    .unwrap_or_default() as u64,
+            });
+        }
+
+        Ok(result)
+    }
+
+    async fn get_by_tags(&self, tags: &[String]) -> Result<Vec<Cocktail>> {
+        self.db_client
+            .get_cocktails_collection()
+            .find(doc! {"tags.name": {"$in": tags}})
+            .await
+            .context("failed to find cocktails by tags")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect cocktails by tags")
+    }
+
+    async fn merge_tags(&self, entity: &Cocktail, sources: &[String], target: &str) -> Result<()> {
+        let sources_to_remove: Vec<&String> = sources.iter().filter(|x| *x != target).collect();
+
+        // one pipeline update, so the cocktail is never left with both or none of the tags
+        let pipeline = vec![doc! {"$set": {
+            "tags": {"$let": {
+                "vars": {"kept": {"$filter": {
+                    "input": "$tags",
+                    "cond": {"$not": [{"$in": ["$$this.name", &sources_to_remove]}]},
+                }}},
+                "in": {"$cond": [
+                    {"$in": [target, "$$kept.name"]},
+                    "$$kept",
+                    {"$concatArrays": ["$$kept", [{"name": target}]]},
+                ]},
+            }},
+            "updated_at": "$$NOW",
+            "version": {"$add": [{"$ifNull": ["$version", 0]}, 1]},
+        }}];
+        let update_result = self
+            .db_client
+            .get_cocktails_collection()
+            .update_one(
+                Self::stored_version_filter(&entity.id, entity.version),
+                pipeline,
+            )
+            .await
+            .context("failed to merge cocktail tags")?;
+        if update_result.matched_count == 0 {
+            return Err(CocktailError::VersionConflict {
+                id: entity.id,
+                version: entity.version,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn get_ingredients(&self) -> Result<Vec<String>> {
+        let pipeline = vec![
+            doc! {"$match": Self::not_deleted()},
+            doc! {"$unwind": "$composition_elements"},
+            doc! {"$group": {"_id": "$composition_elements.name"}},
+            doc! {"$sort": {"_id": 1}},
+        ];
+
+        let mut cursor = self
+            .db_client
+            .get_cocktails_collection()
+            .aggregate(pipeline)
+            .await
+            .context("failed to aggregate cocktail ingredients")?;
+
+        let mut result = vec![];
+        while let Some(ingredient_doc) = cursor.next().await {
+            let ingredient_doc =
+                ingredient_doc.context("failed to read cocktail ingredient from cursor")?;
+            if let Ok(name) = ingredient_doc.get_str("_id") {
+                result.push(name.to_string());
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn get_by_available_ingredients(
+        &self,
+        ingredients: &[String],
+        pagination: &Pagination,
+    ) -> Result<CocktailsByIngredientsPaged> {
+        let pipeline = vec![
+            doc! {"$match": Self::not_deleted()},
+            doc! {"$project": {
+                "id": 1,
+                "russian_name": 1,
+                "missing_ingredients": {"$setDifference": [
+                    {"$ifNull": ["$composition_elements.name", []]},
+                    ingredients
+                ]},
+            }},
+            doc! {"$addFields": {"missing_count": {"$size": "$missing_ingredients"}}},
+            doc! {"$sort": {"missing_count": 1, "russian_name": 1}},
+            doc! {"$facet": {
+                "items": [
+                    {"$skip": (pagination.page * pagination.items_per_page) as i64},
+                    {"$limit": pagination.items_per_page as i64},
+                ],
+                "total": [{"$count": "count"}],
+            }},
+        ];
+
+        let mut cursor = self
+            .db_client
+            .get_cocktails_collection()
+            .aggregate(pipeline)
+            .await
+            .context("failed to aggregate cocktails by available ingredients")?;
+
+        let mut result = CocktailsByIngredientsPaged {
+            items: vec![],
+            total_count: 0,
+        };
+        if let Some(facet_doc) = cursor.next().await {
+            let facet_doc =
+                facet_doc.context("failed to read cocktails by ingredients from cursor")?;
+            result.total_count = Self::read_count(&facet_doc, "total");
+            for item in facet_doc.get_array("items").into_iter().flatten() {
+                if let Some(item) = item.as_document() {
+                    let item: CocktailWithMissingIngredientsDbModel =
+                        mongodb::bson::from_document(item.clone())
+                            .context("failed to read cocktail with missing ingredients")?;
+                    result.items.push(item.into());
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn text_search(
+        &self,
+        query: &str,
+        pagination: &Pagination,
+    ) -> Result<CocktailsTextSearchPaged> {
+        self.ensure_text_index().await?;
+        self.ensure_english_text_index().await?;
+        let russian_pipeline = vec![
+            doc! {"$match": {"$text": {"$search": query}, "deleted_at": null}},
+            doc! {"$project": {"id": 1, "russian_name": 1, "text_score": {"$meta": "textScore"}}},
+        ];
+        let russian = self
+            .db_client
+            .get_cocktails_collection()
+            .aggregate(russian_pipeline)
+            .await
+            .context("failed to search cocktails by text")?;
+        let english_pipeline = vec![
+            doc! {"$match": {"$text": {"$search": query}}},
+            doc! {"$addFields": {"text_score": {"$meta": "textScore"}}},
+            doc! {"$lookup": {
+                "from": COCKTAILS_COLLECTION,
+                "localField": "_id",
+                "foreignField": "id",
+                "as": "cocktail",
+            }},
+            doc! {"$unwind": "$cocktail"},
+            doc! {"$match": {"cocktail.deleted_at": null}},
+            doc! {"$project": {
+                "id": "$_id",
+                "russian_name": "$cocktail.russian_name",
+                "text_score": 1,
+            }},
+        ];
+        let english = self
+            .db_client
+            .get_cocktail_english_texts_collection()
+            .aggregate(english_pipeline)
+            .await
+            .context("failed to search cocktails by english text")?;
+
+        // cocktail found by both indexes keeps the better score
+        let mut best: HashMap<mongodb::bson::Uuid, CocktailTextScoreDbModel> = HashMap::new();
+        let scores = Self::read_text_scores(russian)
+            .await?
+            .into_iter()
+            .chain(Self::read_text_scores(english).await?);
+        for score in scores {
+            match best.get(&score.id) {
+                Some(found) if found.text_score >= score.text_score => {}
+                _ => {
+                    best.insert(score.id, score);
+                }
+            }
+        }
+        let mut ranked: Vec<CocktailTextScoreDbModel> = best.into_values().collect();
+        ranked.sort_by(|a, b| {
+            b.text_score
+                .total_cmp(&a.text_score)
+                .then_with(|| a.russian_name.cmp(&b.russian_name))
+        });
+
+        let total_count = ranked.len() as u64;
+        let page: Vec<CocktailTextScoreDbModel> = ranked
+            .into_iter()
+            .skip((pagination.page * pagination.items_per_page) as usize)
+            .take(pagination.items_per_page as usize)
+            .collect();
+        let page_ids: Vec<mongodb::bson::Uuid> = page.iter().map(|score| score.id).collect();
+        let found: Vec<CocktailDbModel> = self
+            .db_client
+            .get_cocktails_collection()
+            .find(doc! {"id": {"$in": &page_ids}})
+            .await
+            .context("failed to find cocktails found by text")?
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect cocktails found by text")?;
+        let mut cocktails: HashMap<mongodb::bson::Uuid, CocktailDbModel> = found
+            .into_iter()
+            .map(|cocktail| (cocktail.id, cocktail))
+            .collect();
+
+        Ok(CocktailsTextSearchPaged {
+            items: page
+                .into_iter()
+                .filter_map(|score| {
+                    cocktails
+                        .remove(&score.id)
+                        .map(|cocktail| CocktailWithTextScore {
+                            cocktail: cocktail.into(),
+                            score: score.text_score,
+                        })
+                })
+                .collect(),
+            total_count,
+        })
+    }
+
+    async fn get_facets(&self, filter: &CocktailFilter) -> Result<CocktailFacets> {
+        let pipeline = vec![
+            doc! {"$match": Self::filter_document(filter)},
+            doc! {"$addFields": {"base_spirit": Self::base_spirit_expression()}},
+            doc! {"$facet": Self::facets_stages()},
+        ];
+
+        let mut cursor = self
+            .db_client
+            .get_cocktails_collection()
+            .aggregate(pipeline)
+            .await
+            .context("failed to aggregate cocktail facets")?;
+
+        match cursor.next().await {
+            Some(facet_doc) => {
+                let facet_doc = facet_doc.context("failed to read cocktail facets from cursor")?;
+                Ok(Self::read_facets(&facet_doc))
+            }
+            None => Ok(CocktailFacets::default()),
+        }
+    }
+
+    async fn get_stats(&self) -> Result<CatalogueStats> {
+        let mut facets = Self::facets_stages();
+        facets.insert("total", vec![doc! {"$count": "count"}]);
+        facets.insert(
+            "ingredients",
+            vec![
+                doc! {"$unwind": "$composition_elements"},
+                doc! {"$group": {"_id": "$composition_elements.name"}},
+                doc! {"$count": "count"},
+            ],
+        );
+        facets.insert(
+            "ratings",
+            vec![
+                doc! {"$match": {"rating.count": {"$gt": 0}}},
+                doc! {"$group": {
+                    "_id": null,
+                    "cocktails": {"$sum": 1},
+                    "count": {"$sum": "$rating.count"},
+                    "stars": {"$sum": {"$multiply": ["$rating.average", "$rating.count"]}},
+                }},
+            ],
+        );
+        let pipeline = vec![
+            doc! {"$match": Self::not_deleted()},
+            doc! {"$addFields": {"base_spirit": Self::base_spirit_expression()}},
+            doc! {"$facet": facets},
+        ];
+
+        let mut cursor = self
+            .db_client
+            .get_cocktails_collection()
+            .aggregate(pipeline)
+            .await
+            .context("failed to aggregate catalogue stats")?;
+
+        let mut result = CatalogueStats::default();
+        if let Some(stats_doc) = cursor.next().await {
+            let stats_doc = stats_doc.context("failed to read catalogue stats from cursor")?;
+            result.facets = Self::read_facets(&stats_doc);
+            result.cocktails_count = Self::read_count(&stats_doc, "total");
+            result.ingredients_count = Self::read_count(&stats_doc, "ingredients");
+            result.tags_count = result.facets.tags.len() as u64;
+            result.countries_count = result.facets.countries.len() as u64;
+            result.glasses_count = result.facets.glasses.len() as u64;
+            if let Some(ratings) = stats_doc
+                .get_array("ratings")
+                .ok()
+                .and_then(|ratings| ratings.first())
+                .and_then(|ratings| ratings.as_document())
+            {
+                let read_number = |key: &str| {
+                    ratings.get(key).and_then(|value| {
+                        value
+                            .as_f64()
+                            .or(value.as_i64().map(|value| value as f64))
+                            .or(value.as_i32().map(f64::from))
+                    })
+                };
+                result.rated_cocktails_count = read_number("cocktails").unwrap_or_default() as u64;
+                result.ratings_count = read_number("count").unwrap_or_default() as u64;
+                if result.ratings_count > 0 {
+                    result.average_rating =
+                        read_number("stars").map(|stars| stars / result.ratings_count as f64);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn get_random(&self, filter: &RandomCocktailFilter) -> Result<Option<Cocktail>> {
+        let pipeline = vec![
+            doc! {"$match": Self::random_filter_document(filter)},
+            doc! {"$sample": {"size": 1}},
+        ];
+
+        let mut cursor = self
+            .db_client
+            .get_cocktails_collection()
+            .aggregate(pipeline)
+            .await
+            .context("failed to sample random cocktail")?;
+
+        match cursor.next().await {
+            Some(cocktail_doc) => {
+                let cocktail_doc = cocktail_doc.context("failed to read random cocktail")?;
+                let cocktail: CocktailDbModel = mongodb::bson::from_document(cocktail_doc)
+                    .context("failed to read random cocktail")?;
+                Ok(Some(cocktail.into()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn count_by_random_filter(&self, filter: &RandomCocktailFilter) -> Result<u64> {
+        self.db_client
+            .get_cocktails_collection()
+            .count_documents(Self::random_filter_document(filter))
+            .await
+            .context("failed to count cocktail documents")
+    }
+
+    async fn get_nth_by_random_filter(
+        &self,
+        filter: &RandomCocktailFilter,
+        index: u64,
+    ) -> Result<Option<Cocktail>> {
+        let cocktail = self
+            .db_client
+            .get_cocktails_collection()
+            .find_one(Self::random_filter_document(filter))
+            .sort(doc! {"id": 1})
+            .skip(index)
+            .await
+            .context("failed to find cocktail by position")?;
+
+        Ok(cocktail.map(|cocktail| cocktail.into()))
+    }
 }
@@ -0,0 +1,138 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use tokio_stream::StreamExt;
+
+use crate::{
+    domain::aggregates::collection::{
+        Collection, CollectionFilter, CollectionRepo, CollectionsPaged,
+    },
+    infrastructure::{
+        configurations::DbConfiguration,
+        mongo::{CollectionDbModel, MongoDbClient},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct CollectionRepository {
+    db_client: MongoDbClient,
+}
+
+impl CollectionRepository {
+    pub async fn new(config: DbConfiguration) -> Result<Self> {
+        let client = MongoDbClient::new(config)
+            .await
+            .context("failed to create mongodb client from collection repository")?;
+        Ok(Self { db_client: client })
+    }
+}
+
+#[async_trait]
+impl CollectionRepo for CollectionRepository {
+    async fn create(&self, entity: &Collection) -> Result<()> {
+        self.db_client
+            .get_collections_collection()
+            .insert_one(CollectionDbModel::from(entity.clone()))
+            .await
+            .context("Error while insert collection to database")?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, entity: &Collection) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
+        self.db_client
+            .get_collections_collection()
+            .find_one_and_delete(doc! {"id": &uuid_mongo})
+            .await
+            .context("Error while delete collection from database")?;
+
+        Ok(())
+    }
+
+    async fn update(&self, entity: &Collection) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
+        self.db_client
+            .get_collections_collection()
+            .find_one_and_update(
+                doc! {"id": &uuid_mongo},
+                CollectionDbModel::from(entity.clone()),
+            )
+            .await
+            .context("Error while update collection in database")?;
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &uuid::Uuid) -> Result<Option<Collection>> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+
+        self.db_client
+            .get_collections_collection()
+            .find_one(doc! {"id": &uuid_mongo})
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context(format!("Collection with id {} not found", uuid_mongo))
+    }
+
+    async fn get_by_filter(&self, filter: &CollectionFilter) -> Result<CollectionsPaged> {
+        let mut filter_document = doc! {};
+        if let Some(ids) = &filter.ids {
+            let uuids: Vec<mongodb::bson::Uuid> = ids
+                .iter()
+                .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+                .collect();
+            filter_document.insert("id", doc! {"$in": uuids});
+        }
+        if let Some(owner_id) = &filter.owner_id {
+            filter_document.insert(
+                "owner_id",
+                mongodb::bson::Uuid::parse_str(owner_id.to_string()).unwrap(),
+            );
+        }
+
+        let result = self
+            .db_client
+            .get_collections_collection()
+            .find(filter_document.clone())
+            .sort(doc! {"created_at": 1})
+            .limit(filter.pagination.items_per_page as i64)
+            .skip(filter.pagination.page * filter.pagination.items_per_page)
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect collections in result")?;
+
+        let count_by_filter = self
+            .db_client
+            .get_collections_collection()
+            .count_documents(filter_document)
+            .await
+            .context("failed to count collection documents")?;
+
+        Ok(CollectionsPaged {
+            items: result,
+            total_count: count_by_filter,
+        })
+    }
+
+    async fn remove_cocktails(&self, cocktail_ids: &[uuid::Uuid]) -> Result<u64> {
+        let uuids_mongo: Vec<mongodb::bson::Uuid> = cocktail_ids
+            .iter()
+            .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+            .collect();
+        let update_result = self
+            .db_client
+            .get_collections_collection()
+            .update_many(
+                doc! {"cocktail_ids": {"$in": &uuids_mongo}},
+                doc! {"$pull": {"cocktail_ids": {"$in": &uuids_mongo}}},
+            )
+            .await
+            .context("Error while remove cocktails from collections in database")?;
+
+        Ok(update_result.modified_count)
+    }
+}
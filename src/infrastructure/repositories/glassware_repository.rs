@@ -0,0 +1,113 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use tokio_stream::StreamExt;
+
+use crate::{
+    domain::aggregates::glassware::{Glassware, GlasswareFilter, GlasswarePaged, GlasswareRepo},
+    infrastructure::{
+        configurations::DbConfiguration,
+        mongo::{GlasswareDbModel, MongoDbClient},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct GlasswareRepository {
+    db_client: MongoDbClient,
+}
+
+impl GlasswareRepository {
+    pub async fn new(config: DbConfiguration) -> Result<Self> {
+        let client = MongoDbClient::new(config)
+            .await
+            .context("failed to create mongodb client from glassware repository")?;
+        Ok(Self { db_client: client })
+    }
+}
+
+#[async_trait]
+impl GlasswareRepo for GlasswareRepository {
+    async fn create(&self, entity: &Glassware) -> Result<()> {
+        self.db_client
+            .get_glassware_collection()
+            .insert_one(GlasswareDbModel::from(entity.clone()))
+            .await
+            .context("Error while insert glassware to database")?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, entity: &Glassware) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
+        self.db_client
+            .get_glassware_collection()
+            .find_one_and_delete(doc! {"id": &uuid_mongo})
+            .await
+            .context("Error while delete glassware from database")?;
+
+        Ok(())
+    }
+
+    async fn update(&self, entity: &Glassware) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
+        self.db_client
+            .get_glassware_collection()
+            .find_one_and_update(
+                doc! {"id": &uuid_mongo},
+                GlasswareDbModel::from(entity.clone()),
+            )
+            .await
+            .context("Error while update glassware in database")?;
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &uuid::Uuid) -> Result<Option<Glassware>> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+
+        self.db_client
+            .get_glassware_collection()
+            .find_one(doc! {"id": &uuid_mongo})
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context(format!("Glassware with id {} not found", uuid_mongo))
+    }
+
+    async fn get_by_filter(&self, filter: &GlasswareFilter) -> Result<GlasswarePaged> {
+        let filter_document = if let Some(ids) = &filter.ids {
+            let uuids: Vec<mongodb::bson::Uuid> = ids
+                .iter()
+                .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+                .collect();
+            doc! {"id": {"$in": uuids}}
+        } else {
+            doc! {}
+        };
+
+        let result = self
+            .db_client
+            .get_glassware_collection()
+            .find(filter_document.clone())
+            .sort(doc! {"russian_name": 1})
+            .limit(filter.pagination.items_per_page as i64)
+            .skip(filter.pagination.page * filter.pagination.items_per_page)
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect glassware in result")?;
+
+        let count_by_filter = self
+            .db_client
+            .get_glassware_collection()
+            .count_documents(filter_document)
+            .await
+            .context("failed to count glassware documents")?;
+
+        Ok(GlasswarePaged {
+            items: result,
+            total_count: count_by_filter,
+        })
+    }
+}
@@ -1,2 +1,7 @@
 pub mod cocktail_repository;
+pub mod collection_repository;
+pub mod glassware_repository;
+pub mod rating_repository;
+pub mod revision_repository;
+pub mod tool_repository;
 pub mod user_repository;
@@ -0,0 +1,158 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use tokio_stream::StreamExt;
+
+use crate::{
+    domain::aggregates::rating::{Rating, RatingFilter, RatingRepo, RatingSummary, RatingsPaged},
+    infrastructure::{
+        configurations::DbConfiguration,
+        mongo::{MongoDbClient, RatingDbModel},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct RatingRepository {
+    db_client: MongoDbClient,
+}
+
+impl RatingRepository {
+    pub async fn new(config: DbConfiguration) -> Result<Self> {
+        let client = MongoDbClient::new(config)
+            .await
+            .context("failed to create mongodb client from rating repository")?;
+        Ok(Self { db_client: client })
+    }
+}
+
+#[async_trait]
+impl RatingRepo for RatingRepository {
+    async fn upsert(&self, entity: &Rating) -> Result<()> {
+        let rating_db = RatingDbModel::from(entity.clone());
+        self.db_client
+            .get_ratings_collection()
+            .replace_one(
+                doc! {"user_id": &rating_db.user_id, "cocktail_id": &rating_db.cocktail_id},
+                &rating_db,
+            )
+            .upsert(true)
+            .await
+            .context("Error while upsert rating to database")?;
+
+        Ok(())
+    }
+
+    async fn get_by_user_and_cocktail(
+        &self,
+        user_id: &uuid::Uuid,
+        cocktail_id: &uuid::Uuid,
+    ) -> Result<Option<Rating>> {
+        let user_uuid_mongo = mongodb::bson::Uuid::parse_str(user_id.to_string()).unwrap();
+        let cocktail_uuid_mongo = mongodb::bson::Uuid::parse_str(cocktail_id.to_string()).unwrap();
+
+        self.db_client
+            .get_ratings_collection()
+            .find_one(doc! {"user_id": &user_uuid_mongo, "cocktail_id": &cocktail_uuid_mongo})
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context("Error while get rating from database")
+    }
+
+    async fn get_by_filter(&self, filter: &RatingFilter) -> Result<RatingsPaged> {
+        let cocktail_uuid_mongo =
+            mongodb::bson::Uuid::parse_str(filter.cocktail_id.to_string()).unwrap();
+        let filter_document = if filter.with_review_only {
+            doc! {"cocktail_id": &cocktail_uuid_mongo, "review": {"$nin": [null, ""]}}
+        } else {
+            doc! {"cocktail_id": &cocktail_uuid_mongo}
+        };
+
+        let result = self
+            .db_client
+            .get_ratings_collection()
+            .find(filter_document.clone())
+            .sort(doc! {"updated_at": -1})
+            .limit(filter.pagination.items_per_page as i64)
+            .skip(filter.pagination.page * filter.pagination.items_per_page)
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect ratings in result")?;
+
+        let count_by_filter = self
+            .db_client
+            .get_ratings_collection()
+            .count_documents(filter_document)
+            .await
+            .context("failed to count rating documents")?;
+
+        Ok(RatingsPaged {
+            items: result,
+            total_count: count_by_filter,
+        })
+    }
+
+    async fn get_by_user(&self, user_id: &uuid::Uuid) -> Result<Vec<Rating>> {
+        let user_uuid_mongo = mongodb::bson::Uuid::parse_str(user_id.to_string()).unwrap();
+        let result = self
+            .db_client
+            .get_ratings_collection()
+            .find(doc! {"user_id": &user_uuid_mongo})
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect ratings in result")?;
+
+        Ok(result)
+    }
+
+    async fn get_summary(&self, cocktail_id: &uuid::Uuid) -> Result<RatingSummary> {
+        let cocktail_uuid_mongo = mongodb::bson::Uuid::parse_str(cocktail_id.to_string()).unwrap();
+        let pipeline = vec![
+            doc! {"$match": {"cocktail_id": &cocktail_uuid_mongo}},
+            doc! {"$group": {"_id": null, "average": {"$avg": "$stars"}, "count": {"$sum": 1}}},
+        ];
+
+        let mut cursor = self
+            .db_client
+            .get_ratings_collection()
+            .aggregate(pipeline)
+            .await
+            .context("failed to aggregate cocktail ratings")?;
+
+        let summary = match cursor.next().await {
+            Some(summary_doc) => {
+                let summary_doc = summary_doc.context("failed to read rating summary")?;
+                RatingSummary {
+                    average: summary_doc.get_f64("average").unwrap_or_default(),
+                    count: summary_doc
+                        .get("count")
+                        .and_then(|count| count.as_i32().map(i64::from).or(count.as_i64()))
+                        .unwrap_or_default() as u64,
+                }
+            }
+            None => RatingSummary::default(),
+        };
+
+        Ok(summary)
+    }
+
+    async fn delete_by_cocktails(&self, cocktail_ids: &[uuid::Uuid]) -> Result<u64> {
+        let uuids_mongo: Vec<mongodb::bson::Uuid> = cocktail_ids
+            .iter()
+            .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+            .collect();
+        let delete_result = self
+            .db_client
+            .get_ratings_collection()
+            .delete_many(doc! {"cocktail_id": {"$in": &uuids_mongo}})
+            .await
+            .context("failed to delete ratings of cocktails")?;
+
+        Ok(delete_result.deleted_count)
+    }
+}
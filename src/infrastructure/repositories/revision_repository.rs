@@ -0,0 +1,139 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use mongodb::{bson::doc, options::IndexOptions, IndexModel};
+use tokio::sync::OnceCell;
+use tokio_stream::StreamExt;
+
+use crate::{
+    domain::{
+        aggregates::revision::{CocktailRevision, RevisionError, RevisionRepo, RevisionsPaged},
+        Pagination,
+    },
+    infrastructure::{
+        configurations::DbConfiguration,
+        mongo::{is_duplicate_key_error, CocktailRevisionDbModel, MongoDbClient},
+    },
+};
+
+const NUMBER_INDEX_NAME: &str = "cocktail_revisions_number";
+
+/// Number index is created once per process, before the first revision is saved.
+static NUMBER_INDEX: OnceCell<()> = OnceCell::const_new();
+
+#[derive(Debug, Clone)]
+pub struct RevisionRepository {
+    db_client: MongoDbClient,
+}
+
+impl RevisionRepository {
+    pub async fn new(config: DbConfiguration) -> Result<Self> {
+        let client = MongoDbClient::new(config)
+            .await
+            .context("failed to create mongodb client from revision repository")?;
+        Ok(Self { db_client: client })
+    }
+
+    /// Revisions of a cocktail are numbered without gaps and repeats,
+    /// the index rejects a number taken by a concurrent change.
+    async fn ensure_number_index(&self) -> Result<()> {
+        NUMBER_INDEX
+            .get_or_try_init(|| async {
+                let index = IndexModel::builder()
+                    .keys(doc! {"cocktail_id": 1, "number": 1})
+                    .options(
+                        IndexOptions::builder()
+                            .name(NUMBER_INDEX_NAME.to_string())
+                            .unique(true)
+                            .build(),
+                    )
+                    .build();
+                self.db_client
+                    .get_revisions_collection()
+                    .create_index(index)
+                    .await
+                    .context("failed to create cocktail revisions number index")
+                    .map(|_| ())
+            })
+            .await
+            .map(|_| ())
+    }
+}
+
+#[async_trait]
+impl RevisionRepo for RevisionRepository {
+    async fn create(&self, entity: &CocktailRevision) -> Result<()> {
+        self.ensure_number_index().await?;
+        match self
+            .db_client
+            .get_revisions_collection()
+            .insert_one(CocktailRevisionDbModel::from(entity.clone()))
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) if is_duplicate_key_error(&err) => Err(RevisionError::NumberTaken {
+                cocktail_id: entity.cocktail_id,
+                number: entity.number,
+            }
+            .into()),
+            Err(err) => Err(err).context("Error while insert cocktail revision to database"),
+        }
+    }
+
+    async fn get_by_cocktail(
+        &self,
+        cocktail_id: &uuid::Uuid,
+        pagination: &Pagination,
+    ) -> Result<RevisionsPaged> {
+        let cocktail_uuid_mongo = mongodb::bson::Uuid::parse_str(cocktail_id.to_string()).unwrap();
+        let filter_document = doc! {"cocktail_id": &cocktail_uuid_mongo};
+
+        let result = self
+            .db_client
+            .get_revisions_collection()
+            .find(filter_document.clone())
+            .sort(doc! {"number": -1})
+            .limit(pagination.items_per_page as i64)
+            .skip(pagination.page * pagination.items_per_page)
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect revisions in result")?;
+
+        let count_by_filter = self
+            .db_client
+            .get_revisions_collection()
+            .count_documents(filter_document)
+            .await
+            .context("failed to count revision documents")?;
+
+        Ok(RevisionsPaged {
+            items: result,
+            total_count: count_by_filter,
+        })
+    }
+
+    async fn get(&self, cocktail_id: &uuid::Uuid, number: u64) -> Result<Option<CocktailRevision>> {
+        let cocktail_uuid_mongo = mongodb::bson::Uuid::parse_str(cocktail_id.to_string()).unwrap();
+
+        self.db_client
+            .get_revisions_collection()
+            .find_one(doc! {"cocktail_id": &cocktail_uuid_mongo, "number": number as i64})
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context("Error while get cocktail revision from database")
+    }
+
+    async fn get_latest(&self, cocktail_id: &uuid::Uuid) -> Result<Option<CocktailRevision>> {
+        let cocktail_uuid_mongo = mongodb::bson::Uuid::parse_str(cocktail_id.to_string()).unwrap();
+
+        self.db_client
+            .get_revisions_collection()
+            .find_one(doc! {"cocktail_id": &cocktail_uuid_mongo})
+            .sort(doc! {"number": -1})
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context("Error while get latest cocktail revision from database")
+    }
+}
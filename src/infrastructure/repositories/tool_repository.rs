@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use tokio_stream::StreamExt;
+
+use crate::{
+    domain::aggregates::tool::{Tool, ToolFilter, ToolRepo, ToolsPaged},
+    infrastructure::{
+        configurations::DbConfiguration,
+        mongo::{MongoDbClient, ToolDbModel},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct ToolRepository {
+    db_client: MongoDbClient,
+}
+
+impl ToolRepository {
+    pub async fn new(config: DbConfiguration) -> Result<Self> {
+        let client = MongoDbClient::new(config)
+            .await
+            .context("failed to create mongodb client from tool repository")?;
+        Ok(Self { db_client: client })
+    }
+}
+
+#[async_trait]
+impl ToolRepo for ToolRepository {
+    async fn create(&self, entity: &Tool) -> Result<()> {
+        self.db_client
+            .get_tools_collection()
+            .insert_one(ToolDbModel::from(entity.clone()))
+            .await
+            .context("Error while insert tool to database")?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, entity: &Tool) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
+        self.db_client
+            .get_tools_collection()
+            .find_one_and_delete(doc! {"id": &uuid_mongo})
+            .await
+            .context("Error while delete tool from database")?;
+
+        Ok(())
+    }
+
+    async fn update(&self, entity: &Tool) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(entity.id.to_string()).unwrap();
+        self.db_client
+            .get_tools_collection()
+            .find_one_and_update(doc! {"id": &uuid_mongo}, ToolDbModel::from(entity.clone()))
+            .await
+            .context("Error while update tool in database")?;
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &uuid::Uuid) -> Result<Option<Tool>> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(id.to_string()).unwrap();
+
+        self.db_client
+            .get_tools_collection()
+            .find_one(doc! {"id": &uuid_mongo})
+            .await
+            .map(|x| x.map(|x| x.into()))
+            .context(format!("Tool with id {} not found", uuid_mongo))
+    }
+
+    async fn get_by_filter(&self, filter: &ToolFilter) -> Result<ToolsPaged> {
+        let filter_document = if let Some(ids) = &filter.ids {
+            let uuids: Vec<mongodb::bson::Uuid> = ids
+                .iter()
+                .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+                .collect();
+            doc! {"id": {"$in": uuids}}
+        } else {
+            doc! {}
+        };
+
+        let result = self
+            .db_client
+            .get_tools_collection()
+            .find(filter_document.clone())
+            .sort(doc! {"russian_name": 1})
+            .limit(filter.pagination.items_per_page as i64)
+            .skip(filter.pagination.page * filter.pagination.items_per_page)
+            .await
+            .context("failed to find")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect tools in result")?;
+
+        let count_by_filter = self
+            .db_client
+            .get_tools_collection()
+            .count_documents(filter_document)
+            .await
+            .context("failed to count tool documents")?;
+
+        Ok(ToolsPaged {
+            items: result,
+            total_count: count_by_filter,
+        })
+    }
+}
@@ -2,10 +2,15 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 
 use mongodb::bson::doc;
+use tokio_stream::StreamExt;
 
 use crate::{
-    domain::aggregates::user::{User, UserRepo},
-    infrastructure::{configurations::DbConfiguration, mongo::MongoDbClient, mongo::UserDbModel},
+    domain::aggregates::user::{User, UserRepo, MAX_VIEWED_COCKTAILS},
+    infrastructure::{
+        configurations::DbConfiguration,
+        mongo::MongoDbClient,
+        mongo::{UserDbModel, DAILY_LAST_SENT_ON_FORMAT},
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -42,7 +47,8 @@ impl UserRepo for UserRepository {
     async fn delete(&self, user_entity: &User) -> Result<()> {
         let uuid_mongo = mongodb::bson::Uuid::parse_str(user_entity.id.to_string()).unwrap();
         let delete_filter = doc! {"id":  &uuid_mongo};
-        let _delete_result = self.db_client
+        let _delete_result = self
+            .db_client
             .get_users_collection()
             .find_one_and_delete(delete_filter)
             .await
@@ -91,4 +97,132 @@ impl UserRepo for UserRepository {
 
         Ok(user_found)
     }
+
+    async fn get_with_daily_subscription(&self) -> Result<Vec<User>> {
+        let users = self
+            .db_client
+            .get_users_collection()
+            .find(doc! {"daily_subscription": {"$ne": null}})
+            .await
+            .context("failed to find users with daily subscription")?
+            .map(|x| x.map(|x| x.into()))
+            .collect::<Result<_, _>>()
+            .await
+            .context("fail to collect users with daily subscription")?;
+
+        Ok(users)
+    }
+
+    async fn claim_daily_delivery(
+        &self,
+        user_id: &uuid::Uuid,
+        date: &chrono::NaiveDate,
+    ) -> Result<bool> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(user_id.to_string()).unwrap();
+        let date = date.format(DAILY_LAST_SENT_ON_FORMAT).to_string();
+        let update_result = self
+            .db_client
+            .get_users_collection()
+            .update_one(
+                doc! {
+                    "id": &uuid_mongo,
+                    "daily_subscription": {"$ne": null},
+                    "daily_last_sent_on": {"$ne": &date},
+                },
+                doc! {"$set": {"daily_last_sent_on": &date}},
+            )
+            .await
+            .context("Error while claim daily delivery in db")?;
+
+        Ok(update_result.modified_count == 1)
+    }
+
+    async fn release_daily_delivery(
+        &self,
+        user_id: &uuid::Uuid,
+        date: &chrono::NaiveDate,
+        previous_date: &Option<chrono::NaiveDate>,
+    ) -> Result<()> {
+        let uuid_mongo = mongodb::bson::Uuid::parse_str(user_id.to_string()).unwrap();
+        let date = date.format(DAILY_LAST_SENT_ON_FORMAT).to_string();
+        let previous_date = previous_date
+            .map(|previous_date| previous_date.format(DAILY_LAST_SENT_ON_FORMAT).to_string());
+        self.db_client
+            .get_users_collection()
+            .update_one(
+                doc! {"id": &uuid_mongo, "daily_last_sent_on": &date},
+                doc! {"$set": {"daily_last_sent_on": previous_date}},
+            )
+            .await
+            .context("Error while release daily delivery in db")?;
+
+        Ok(())
+    }
+
+    async fn record_cocktail_view(
+        &self,
+        user_id: &uuid::Uuid,
+        cocktail_id: &uuid::Uuid,
+    ) -> Result<()> {
+        let user_uuid_mongo = mongodb::bson::Uuid::parse_str(user_id.to_string()).unwrap();
+        let cocktail_uuid_mongo = mongodb::bson::Uuid::parse_str(cocktail_id.to_string()).unwrap();
+        let viewed = doc! {
+            "cocktail_id": &cocktail_uuid_mongo,
+            "viewed_at": mongodb::bson::DateTime::now(),
+        };
+        // single pipeline update removes the previous view of the cocktail and trims the list atomically
+        let pipeline = vec![doc! {"$set": {"viewed_cocktails": {"$slice": [
+            {"$concatArrays": [
+                {"$filter": {
+                    "input": {"$ifNull": ["$viewed_cocktails", []]},
+                    "cond": {"$ne": ["$$this.cocktail_id", &cocktail_uuid_mongo]},
+                }},
+                [viewed],
+            ]},
+            -(MAX_VIEWED_COCKTAILS as i64),
+        ]}}}];
+        self.db_client
+            .get_users_collection()
+            .update_one(doc! {"id": &user_uuid_mongo}, pipeline)
+            .await
+            .context("Error while record cocktail view in db")?;
+
+        Ok(())
+    }
+
+    async fn remove_cocktails(&self, cocktail_ids: &[uuid::Uuid]) -> Result<u64> {
+        let uuids_mongo: Vec<mongodb::bson::Uuid> = cocktail_ids
+            .iter()
+            .map(|id| mongodb::bson::Uuid::parse_str(id.to_string()).unwrap())
+            .collect();
+        // legacy favourites are stored as bare ids, newer ones as documents with cocktail_id
+        let pipeline = vec![doc! {"$set": {
+            "favorite_cocktails": {"$filter": {
+                "input": {"$ifNull": ["$favorite_cocktails", []]},
+                "cond": {"$not": [{"$in": [
+                    {"$ifNull": ["$$this.cocktail_id", "$$this"]},
+                    &uuids_mongo,
+                ]}]},
+            }},
+            "viewed_cocktails": {"$filter": {
+                "input": {"$ifNull": ["$viewed_cocktails", []]},
+                "cond": {"$not": [{"$in": ["$$this.cocktail_id", &uuids_mongo]}]},
+            }},
+        }}];
+        let update_result = self
+            .db_client
+            .get_users_collection()
+            .update_many(
+                doc! {"$or": [
+                    {"favorite_cocktails": {"$in": &uuids_mongo}},
+                    {"favorite_cocktails.cocktail_id": {"$in": &uuids_mongo}},
+                    {"viewed_cocktails.cocktail_id": {"$in": &uuids_mongo}},
+                ]},
+                pipeline,
+            )
+            .await
+            .context("Error while remove cocktails from users in db")?;
+
+        Ok(update_result.modified_count)
+    }
 }
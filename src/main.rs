@@ -25,12 +25,22 @@ async fn main() -> Result<()> {
     bot::INSTANCE
         .set(bot_provider.clone())
         .expect("Can't set static bot provider");
-    let repo_factory = RepositoryFactory::new(&app.config.db_configuration);
+    let repo_factory = RepositoryFactory::new(
+        &app.config.db_configuration,
+        &app.config.blob_store_configuration,
+    );
     infrastructure::REPOFACTORYINSTANCE
         .set(repo_factory.clone())
         .expect("Can't set static repository factory");
     tokio::spawn(start_bot());
     log::info!("Bot started...");
+    tokio::spawn(bot::scheduler::start_daily_subscription_scheduler());
+    log::info!("Daily subscription scheduler started...");
+    tokio::spawn(app::trash_purge::start_trash_purge_job(
+        app.config.trash_retention_days,
+    ));
+    log::info!("Trash purge job started...");
+    tokio::spawn(app::slug_backfill::start_slug_backfill());
     log::info!("Start Api Server...");
     let api_provider = api::ApiProvider::new(&app.config.api_configuration);
     api_provider.start_server().await;
@@ -0,0 +1,440 @@
+use teloxide::utils::markdown::escape;
+
+use super::i18n::Language;
+use crate::domain::{
+    aggregates::cocktail::{Cocktail, PreparationMethod},
+    locale::LocaleChain,
+};
+
+/// Telegram limit of text message length.
+pub const MAX_MESSAGE_LENGTH: usize = 4096;
+/// Telegram limit of photo caption length.
+pub const MAX_CAPTION_LENGTH: usize = 1024;
+
+const TRUNCATION_MARK: &str = "…";
+
+/// Part of cocktail card. Sections without data in the cocktail are left out of the card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardSection {
+    Title,
+    EnglishName,
+    Rating,
+    UserRating,
+    Note,
+    Ingredients,
+    Glass,
+    Method,
+    Tools,
+    Recipe,
+    History,
+    Tags,
+    ShareLink,
+}
+
+impl CardSection {
+    /// Header lines go one after another, other sections are separated by an empty line.
+    fn is_header(&self) -> bool {
+        matches!(
+            self,
+            CardSection::Title
+                | CardSection::EnglishName
+                | CardSection::Rating
+                | CardSection::UserRating
+        )
+    }
+}
+
+/// Card in the bot chat, with rating and note of the user.
+pub const CHAT_CARD_TEMPLATE: &[CardSection] = &[
+    CardSection::Title,
+    CardSection::EnglishName,
+    CardSection::Rating,
+    CardSection::UserRating,
+    CardSection::Note,
+    CardSection::Ingredients,
+    CardSection::Glass,
+    CardSection::Method,
+    CardSection::Tools,
+    CardSection::Recipe,
+    CardSection::History,
+    CardSection::Tags,
+    CardSection::ShareLink,
+];
+
+/// Card shared to other chats in inline mode or exported by the API, without personal data.
+pub const SHARED_CARD_TEMPLATE: &[CardSection] = &[
+    CardSection::Title,
+    CardSection::EnglishName,
+    CardSection::Rating,
+    CardSection::Ingredients,
+    CardSection::Glass,
+    CardSection::Method,
+    CardSection::Tools,
+    CardSection::Recipe,
+    CardSection::History,
+    CardSection::Tags,
+    CardSection::ShareLink,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardFormat {
+    /// Telegram MarkdownV2.
+    Markdown,
+    PlainText,
+}
+
+impl CardFormat {
+    fn text(&self, text: &str) -> String {
+        match self {
+            CardFormat::Markdown => escape(text),
+            CardFormat::PlainText => text.to_string(),
+        }
+    }
+
+    fn bold(&self, text: &str) -> String {
+        match self {
+            CardFormat::Markdown => format!("*{}*", escape(text)),
+            CardFormat::PlainText => text.to_string(),
+        }
+    }
+}
+
+/// Cocktail card with the texts in the language of the bot and cocktail texts in `locales`.
+pub struct CocktailCard<'a> {
+    pub cocktail: &'a Cocktail,
+    pub language: Language,
+    pub locales: &'a LocaleChain,
+    pub user_rating: Option<u8>,
+    pub note: Option<&'a str>,
+    /// Ingredients in the bar of the user, set to mark which ones are missing.
+    pub bar_ingredients: Option<&'a [String]>,
+    pub share_link: Option<String>,
+    /// History is long, so in the chat it is collapsed until the user asks for it.
+    pub show_history: bool,
+}
+
+impl<'a> CocktailCard<'a> {
+    pub fn new(cocktail: &'a Cocktail, language: Language, locales: &'a LocaleChain) -> Self {
+        CocktailCard {
+            cocktail,
+            language,
+            locales,
+            user_rating: None,
+            note: None,
+            bar_ingredients: None,
+            share_link: None,
+            show_history: true,
+        }
+    }
+
+    pub fn has_history(&self) -> bool {
+        self.history().is_some()
+    }
+
+    pub fn render(&self, template: &[CardSection], format: CardFormat) -> String {
+        let mut text = String::new();
+        for section in template {
+            let lines = self.render_section(*section, format);
+            if lines.is_empty() {
+                continue;
+            }
+            if !text.is_empty() {
+                text.push_str(if section.is_header() { "\n" } else { "\n\n" });
+            }
+            text.push_str(&lines.join("\n"));
+        }
+        text
+    }
+
+    fn render_section(&self, section: CardSection, format: CardFormat) -> Vec<String> {
+        let cocktail = self.cocktail;
+        let title = |id: &str| format.bold(&self.language.text(id));
+        let titled = |id: &str, value: String| vec![format!("{} {}", title(id), value)];
+        let block = |id: &str, lines: Vec<String>| {
+            if lines.is_empty() {
+                return lines;
+            }
+            [vec![title(id)], lines].concat()
+        };
+        match section {
+            CardSection::Title => vec![format!(
+                "🍸{} {}",
+                title("card-cocktail"),
+                format.text(cocktail.localized_name(self.locales))
+            )],
+            CardSection::EnglishName => match non_empty(cocktail.name.as_deref()) {
+                Some(name) => titled("card-english-name", format.text(name)),
+                None => vec![],
+            },
+            CardSection::Rating => match cocktail.rating.as_ref().filter(|r| r.count > 0) {
+                Some(rating) => titled(
+                    "card-rating",
+                    format.text(&format!("⭐ {:.1} ({})", rating.average, rating.count)),
+                ),
+                None => vec![],
+            },
+            CardSection::UserRating => match self.user_rating {
+                Some(stars) => titled("card-user-rating", format!("{}⭐", stars)),
+                None => vec![],
+            },
+            CardSection::Note => match non_empty(self.note) {
+                Some(note) => block("card-note", vec![format!("📝 {}", format.text(note))]),
+                None => vec![],
+            },
+            CardSection::Ingredients => {
+                let items = cocktail.composition_elements.as_deref().unwrap_or_default();
+                let lines = items
+                    .iter()
+                    .map(|item| {
+                        let marker = match self.bar_ingredients {
+                            Some(bar) if bar.contains(&item.name) => "✅",
+                            Some(_) => "❌",
+                            None => "👉",
+                        };
+                        let line = format!(
+                            "{} {} {}{}",
+                            marker,
+                            item.localized_name(self.locales),
+                            item.count,
+                            item.unit
+                        );
+                        format.text(&line)
+                    })
+                    .collect();
+                block("card-ingredients", lines)
+            }
+            CardSection::Glass => match non_empty(cocktail.glass.as_deref()) {
+                Some(glass) => block("card-glass", vec![format!("🥃 {}", format.text(glass))]),
+                None => vec![],
+            },
+            CardSection::Method => match &cocktail.method {
+                Some(method) => block(
+                    "card-method",
+                    vec![format!(
+                        "🌀 {}",
+                        format.text(&preparation_method_name(method, self.language))
+                    )],
+                ),
+                None => vec![],
+            },
+            CardSection::Tools => {
+                let tools = cocktail.tools.as_deref().unwrap_or_default();
+                let lines = tools
+                    .iter()
+                    .map(|tool| format!("👉 {}", format.text(&tool.name)))
+                    .collect();
+                block("card-tools", lines)
+            }
+            CardSection::Recipe => {
+                let steps = match &cocktail.recipe {
+                    Some(recipe) => recipe.localized_steps(self.locales),
+                    None => vec![],
+                };
+                let lines = steps
+                    .iter()
+                    .filter(|step| !step.trim().is_empty())
+                    .enumerate()
+                    .map(|(i, step)| format.text(&format!("{}. {}", i + 1, step)))
+                    .collect();
+                block("card-recipe", lines)
+            }
+            CardSection::History => match self.history().filter(|_| self.show_history) {
+                Some(history) => block("card-history", vec![format.text(history)]),
+                None => vec![],
+            },
+            CardSection::Tags => {
+                let tags = cocktail.tags.as_deref().unwrap_or_default();
+                if tags.is_empty() {
+                    return vec![];
+                }
+                let tags = tags
+                    .iter()
+                    .map(|tag| format.text(&format!("#{}", tag.name.replace(' ', "_"))))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                block("card-tags", vec![tags])
+            }
+            CardSection::ShareLink => match &self.share_link {
+                Some(link) => vec![format.text(
+                    &self
+                        .language
+                        .text_with("card-share", &[("link", link.clone().into())]),
+                )],
+                None => vec![],
+            },
+        }
+    }
+
+    fn history(&self) -> Option<&str> {
+        non_empty(self.cocktail.localized_history(self.locales))
+    }
+}
+
+fn non_empty(text: Option<&str>) -> Option<&str> {
+    text.filter(|text| !text.trim().is_empty())
+}
+
+pub fn preparation_method_name(method: &PreparationMethod, language: Language) -> String {
+    language.text(match method {
+        PreparationMethod::Shake => "method-shake",
+        PreparationMethod::Stir => "method-stir",
+        PreparationMethod::Build => "method-build",
+        PreparationMethod::Blend => "method-blend",
+        PreparationMethod::Throw => "method-throw",
+    })
+}
+
+/// Length as Telegram limits it, in UTF-16 code units. MarkdownV2 is measured with markup,
+/// so the result is never less than the length of the shown text.
+pub fn message_length(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+/// Splits text into messages of at most `max_length`, breaking between lines.
+/// Lines longer than a message are broken between words.
+pub fn split(text: &str, max_length: usize) -> Vec<String> {
+    let mut parts = vec![];
+    let mut current = String::new();
+    for line in text.lines() {
+        let mut line = line;
+        loop {
+            let separator = usize::from(!current.is_empty());
+            if message_length(&current) + separator + message_length(line) <= max_length {
+                if separator > 0 {
+                    current.push('\n');
+                }
+                current.push_str(line);
+                break;
+            }
+            if !current.is_empty() {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            let (head, rest) = cut(line, max_length);
+            parts.push(head.to_string());
+            line = rest;
+        }
+    }
+    parts.push(current);
+
+    parts
+        .into_iter()
+        .map(|part| part.trim_matches('\n').to_string())
+        .filter(|part| !part.trim().is_empty())
+        .collect()
+}
+
+/// Cuts text to `max_length`, breaking between lines when possible, and marks the cut.
+pub fn truncate(text: &str, max_length: usize) -> String {
+    if message_length(text) <= max_length {
+        return text.to_string();
+    }
+    let mark_length = message_length(TRUNCATION_MARK);
+    // no room for the text, only the mark is left when it fits
+    if max_length < mark_length {
+        return String::new();
+    }
+    if max_length == mark_length {
+        return TRUNCATION_MARK.to_string();
+    }
+    let head = split(text, max_length - mark_length)
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    format!("{}{}", head.trim_end(), TRUNCATION_MARK)
+}
+
+/// Beginning of the line not longer than `max_length` and the rest. The line is broken
+/// at the last space, and never between MarkdownV2 escape character and the escaped one.
+fn cut(line: &str, max_length: usize) -> (&str, &str) {
+    let mut end = 0;
+    let mut length = 0;
+    for (index, char) in line.char_indices() {
+        length += char.len_utf16();
+        if length > max_length {
+            break;
+        }
+        end = index + char.len_utf8();
+    }
+    if let Some(space) = line[..end].rfind(' ').filter(|space| *space > 0) {
+        end = space;
+    }
+    let backslashes = line[..end].chars().rev().take_while(|c| *c == '\\').count();
+    if backslashes % 2 == 1 {
+        end -= 1;
+    }
+    if end == 0 {
+        // limit is less than an escape sequence, the line is broken anyway to make progress
+        end = line.chars().next().map_or(0, char::len_utf8);
+    }
+    (&line[..end], line[end..].trim_start())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_keeps_short_text_in_one_message() {
+        assert_eq!(split("first\nsecond", 20), vec!["first\nsecond"]);
+    }
+
+    #[test]
+    fn split_breaks_between_lines() {
+        assert_eq!(split("first\nsecond", 8), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn split_breaks_line_longer_than_limit_between_words() {
+        assert_eq!(
+            split("one two three four", 9),
+            vec!["one two", "three", "four"]
+        );
+    }
+
+    #[test]
+    fn split_breaks_word_longer_than_limit() {
+        assert_eq!(split("abcdefgh", 3), vec!["abc", "def", "gh"]);
+    }
+
+    #[test]
+    fn cut_never_breaks_right_after_escape_character() {
+        assert_eq!(cut("ab\\.cd", 3), ("ab", "\\.cd"));
+        assert_eq!(split("ab\\.cd", 3), vec!["ab", "\\.c", "d"]);
+    }
+
+    #[test]
+    fn cut_keeps_escaped_backslash_whole() {
+        assert_eq!(cut("a\\\\b", 3), ("a\\\\", "b"));
+    }
+
+    #[test]
+    fn cut_makes_progress_when_limit_is_less_than_escape_sequence() {
+        assert_eq!(cut("\\.", 1), ("\\", "."));
+    }
+
+    #[test]
+    fn split_measures_emoji_in_utf16_code_units() {
+        // every emoji is two UTF-16 code units
+        assert_eq!(message_length("🍸🍹"), 4);
+        assert_eq!(split("🍸🍹🍷", 4), vec!["🍸🍹", "🍷"]);
+        assert_eq!(cut("🍸🍹", 3), ("🍸", "🍹"));
+    }
+
+    #[test]
+    fn truncate_keeps_text_within_limit() {
+        assert_eq!(truncate("short", 5), "short");
+    }
+
+    #[test]
+    fn truncate_marks_cut_text() {
+        assert_eq!(truncate("first\nsecond", 8), "first…");
+        assert_eq!(truncate("abcdefgh", 4), "abc…");
+    }
+
+    #[test]
+    fn truncate_with_limit_not_greater_than_mark() {
+        assert_eq!(truncate("abcdefgh", 1), "…");
+        assert_eq!(truncate("abcdefgh", 0), "");
+    }
+}
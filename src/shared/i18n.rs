@@ -0,0 +1,121 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+use crate::domain::{aggregates::user::User, locale::LocaleChain};
+
+type Catalogue = FluentBundle<FluentResource>;
+
+static CATALOGUES: OnceLock<HashMap<Language, Catalogue>> = OnceLock::new();
+
+/// Language of the bot interface. Texts are kept in Fluent catalogues in `locales` folder,
+/// one file per language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Russian,
+    English,
+}
+
+impl Language {
+    pub const ALL: &'static [Language] = &[Language::Russian, Language::English];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Russian => "ru",
+            Language::English => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Language::ALL
+            .iter()
+            .find(|language| language.code() == code)
+            .copied()
+    }
+
+    /// Language chosen by user on profile page, otherwise the first supported one
+    /// for Telegram `language_code`.
+    pub fn choose(user: Option<&User>, language_code: Option<&str>) -> Self {
+        Language::from_locales(&content_locales(user, language_code))
+    }
+
+    /// The first supported language of the chain, e.g. from `Accept-Language` header.
+    pub fn from_locales(locales: &LocaleChain) -> Self {
+        locales
+            .locales()
+            .find_map(Language::from_code)
+            .unwrap_or(Language::Russian)
+    }
+
+    /// Text of the message with given id, plain text without MarkdownV2 escaping.
+    pub fn text(&self, id: &str) -> String {
+        self.format(id, None)
+    }
+
+    pub fn text_with(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        let args: FluentArgs = args
+            .iter()
+            .map(|(name, value)| (*name, value.clone()))
+            .collect();
+        self.format(id, Some(&args))
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        let catalogues = CATALOGUES.get_or_init(load_catalogues);
+        // russian catalogue is the complete one, missing translations fall back to it
+        for language in [*self, Language::Russian] {
+            let Some(pattern) = catalogues[&language]
+                .get_message(id)
+                .and_then(|message| message.value())
+            else {
+                continue;
+            };
+            let mut errors = vec![];
+            let text = catalogues[&language].format_pattern(pattern, args, &mut errors);
+            if !errors.is_empty() {
+                log::warn!(
+                    "Errors in message {} of {} catalogue: {:?}",
+                    id,
+                    language.code(),
+                    errors
+                );
+            }
+            return text.into_owned();
+        }
+
+        log::warn!("Message {} not found in catalogues", id);
+        id.to_string()
+    }
+}
+
+/// Locales of cocktail texts for user, they follow the language chosen for the bot.
+pub fn content_locales(user: Option<&User>, language_code: Option<&str>) -> LocaleChain {
+    let chosen = user.and_then(|user| user.language.as_deref());
+    LocaleChain::from_language_code(chosen.or(language_code))
+}
+
+fn load_catalogues() -> HashMap<Language, Catalogue> {
+    [
+        (Language::Russian, include_str!("locales/ru.ftl")),
+        (Language::English, include_str!("locales/en.ftl")),
+    ]
+    .into_iter()
+    .map(|(language, source)| {
+        let resource = FluentResource::try_new(source.to_string()).unwrap_or_else(|(_, errors)| {
+            panic!("Can't parse {} catalogue: {:?}", language.code(), errors)
+        });
+        let language_id: LanguageIdentifier = language
+            .code()
+            .parse()
+            .expect("Can't parse language identifier");
+        let mut catalogue = FluentBundle::new_concurrent(vec![language_id]);
+        // isolation marks around arguments break MarkdownV2 entities
+        catalogue.set_use_isolating(false);
+        catalogue
+            .add_resource(resource)
+            .unwrap_or_else(|errors| panic!("Duplicate messages in catalogue: {:?}", errors));
+        (language, catalogue)
+    })
+    .collect()
+}
@@ -0,0 +1,171 @@
+## Texts of the bot interface. Messages are plain text, MarkdownV2 escaping is done by the bot.
+
+language-name = English
+
+## Common buttons
+
+button-back = 👈 Back
+button-cancel = 👈 Cancel
+button-done = 👈 Done
+
+## Main menu
+
+main-menu = Main menu:
+main-menu-cocktails-list = 📋 Cocktails list
+main-menu-search-by-name = 🔎 Search by name
+main-menu-search-by-tags = 🏷 Search by tags
+main-menu-random-cocktail = 🎲 Random cocktail
+main-menu-profile-page = 🗄 Personal page
+main-menu-register = 🔑 Sign up
+
+## Dialogues
+
+search-by-name-prompt = Send me the full name of a cocktail or a part of it.
+search-by-name-retry = Send me the name of a cocktail or a part of it.
+favorite-note-retry = Send me the text of the note, no longer than { $max_length } characters.
+collection-name-prompt = Send me the name of the new collection, for example "Summer menu".
+collection-name-retry = Send me the name of the collection, no longer than { $max_length } characters.
+bar-ingredient-prompt = Send me the name of a bottle or an ingredient, a part is enough, for example "rum".
+bar-ingredient-retry = Send me the name of an ingredient or a part of it.
+
+## Cocktail lists
+
+cocktails = Cocktails:
+cocktails-by-tag = Cocktails tagged #{ $tag }:
+tags = Tags:
+available-pages = Available pages:
+search-found-in-description = Nothing matched the name, but these words are in the description:
+shopping-list-button = 🛒 Shopping list
+
+## Cocktail card
+
+cocktail-not-found = Cocktail not found, it may have been removed from the catalogue.
+card-cocktail = Cocktail:
+card-english-name = English name:
+card-rating = Rating:
+card-user-rating = Your rating:
+card-note = Your note:
+card-ingredients = Ingredients:
+card-glass = Glass:
+card-method = Method:
+card-tools = Bar tools:
+card-recipe = Preparation:
+card-history = History of the cocktail:
+card-tags = Tags:
+card-share = 🔗 Share the cocktail: { $link }
+card-show-history-button = 📜 Show history
+card-hide-history-button = 📜 Hide history
+card-similar-button = 🔁 Similar cocktails
+card-another-random-button = 🎲 One more
+method-shake = Shake
+method-stir = Stir
+method-build = Build
+method-blend = Blend
+method-throw = Throw
+rating-thanks = Thank you for the rating!
+
+## Favorite note
+
+note-form = Send me a note for the cocktail. Maximum length is { $max_length } characters.
+note-current = Current note:
+note-remove-button = 🗑 Remove note
+
+## Registration
+
+register-confirmation =
+    By confirming the sign up you agree that we store your Telegram user id. We collect no other information.
+
+    You will be able to save favorite cocktails to your personal selection to find them easier.
+
+    You can remove your profile completely at any time.
+    Enjoy ☺️
+register-confirm-button = Confirm sign up
+register-success = You have signed up successfully
+remove-account-confirmation =
+    Are you sure you want to remove your profile?
+
+    All favorite cocktails will be removed. 😔
+remove-account-confirm-button = Confirm removal
+remove-account-success = Your account has been removed
+
+## Profile page
+
+profile-page = Personal page:
+profile-favorites = ❤ Show favorites
+profile-collections = 📚 My collections
+profile-bar = 🍾 My bar
+profile-recommendations = ✨ Recommendations for you
+profile-daily = ⏰ Cocktail of the day
+profile-language = 🌐 Language: { $language }
+profile-remove-account = 🗑 Remove account
+language-chooser = Choose the language of the bot:
+
+## Collections
+
+collections = Your collections:
+collection = Collection:
+collection-share = 🔗 Share the collection: { $link }
+collection-not-found = Collection not found 😔
+collection-create-button = ➕ New collection
+collection-chooser = Choose a collection for the cocktail:
+collection-chooser-empty = You have no collections yet. You can create them on your personal page.
+collection-cocktail-added = Cocktail added to the collection
+collection-cocktail-removed = Cocktail removed from the collection
+
+## Home bar
+
+bar = Your bar (tap an ingredient to remove it):
+bar-empty = Your bar is empty yet. Add the bottles and ingredients you have at home.
+bar-add-button = ➕ Add ingredient
+bar-what-can-i-make-button = 🍸 What can I make?
+bar-suggestions = Choose an ingredient or refine the name:
+bar-suggestions-empty = Nothing found 😔 Try to spell the name differently.
+bar-ingredient-added = { $ingredient } added to your bar
+what-can-i-make = Cocktails from what you have in your bar (the ones you have everything for go first):
+what-can-i-make-missing = { $name } (missing: { $count })
+
+## Shopping list
+
+shopping-list = 🛒 Shopping list for { $count } { $count ->
+    [one] cocktail
+   *[other] cocktails
+}:
+shopping-list-nothing = There is nothing to buy.
+shopping-list-owned = Already in your bar:
+
+## Daily cocktail
+
+daily = ⏰ Cocktail of the day
+daily-subscribed = Every day at { $time } ({ $timezone }) the bot will send you a random cocktail.
+daily-not-subscribed = Subscribe, and the bot will send you a random cocktail every day at the chosen time.
+daily-time = 🕘 Time: { $time }
+daily-timezone = 🌍 Time zone: { $timezone }
+daily-non-alcoholic-on = 🍹 Non-alcoholic only: yes
+daily-non-alcoholic-off = 🍹 Non-alcoholic only: no
+daily-max-ingredients-any = 🔢 Ingredients: any number
+daily-max-ingredients = 🔢 Ingredients: at most { $max_ingredients }
+daily-subscribe = 🔔 Subscribe
+daily-unsubscribe = 🔕 Unsubscribe
+daily-time-chooser = When should the cocktail of the day be sent?
+daily-timezone-chooser = Choose your time zone:
+timezone-kaliningrad = Kaliningrad (UTC+2)
+timezone-moscow = Moscow (UTC+3)
+timezone-samara = Samara (UTC+4)
+timezone-yekaterinburg = Yekaterinburg (UTC+5)
+timezone-omsk = Omsk (UTC+6)
+timezone-novosibirsk = Novosibirsk (UTC+7)
+timezone-irkutsk = Irkutsk (UTC+8)
+timezone-yakutsk = Yakutsk (UTC+9)
+timezone-vladivostok = Vladivostok (UTC+10)
+timezone-magadan = Magadan (UTC+11)
+timezone-kamchatka = Kamchatka (UTC+12)
+
+## Similar cocktails and recommendations
+
+similar = Similar cocktails:
+similar-empty = No similar cocktails found.
+recommendations = ✨ Recommendations for you:
+recommendations-empty = Nothing to recommend yet. Add cocktails to favorites and rate them, and picks for you will appear here.
+recommendation-favorite = Because you like { $name }
+recommendation-rated = Because you rated { $name } highly
+recommendation-viewed = Because you viewed { $name }
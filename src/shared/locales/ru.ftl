@@ -0,0 +1,172 @@
+## Texts of the bot interface. Messages are plain text, MarkdownV2 escaping is done by the bot.
+
+language-name = Русский
+
+## Common buttons
+
+button-back = 👈 Назад
+button-cancel = 👈 Отмена
+button-done = 👈 Готово
+
+## Main menu
+
+main-menu = Основное меню:
+main-menu-cocktails-list = 📋 Список коктейлей
+main-menu-search-by-name = 🔎 Поиск по названию
+main-menu-search-by-tags = 🏷 Поиск по тегам
+main-menu-random-cocktail = 🎲 Случайный коктейль
+main-menu-profile-page = 🗄 Личная страница
+main-menu-register = 🔑 Регистрация
+
+## Dialogues
+
+search-by-name-prompt = Напишите мне полное название коктейля или его часть.
+search-by-name-retry = Отправь мне название коктейля или его часть.
+favorite-note-retry = Отправь мне текст заметки не длиннее { $max_length } символов.
+collection-name-prompt = Напишите название новой коллекции, например "Летнее меню".
+collection-name-retry = Отправь мне название коллекции не длиннее { $max_length } символов.
+bar-ingredient-prompt = Напишите название бутылки или ингредиента, можно часть, например "ром".
+bar-ingredient-retry = Отправь мне название ингредиента или его часть.
+
+## Cocktail lists
+
+cocktails = Коктейли:
+cocktails-by-tag = Коктейли с тегом #{ $tag }:
+tags = Теги:
+available-pages = Доступные страницы:
+search-found-in-description = По названию ничего не нашлось, но эти слова есть в описании:
+shopping-list-button = 🛒 Список покупок
+
+## Cocktail card
+
+cocktail-not-found = Коктейль не найден, возможно, его удалили из каталога.
+card-cocktail = Коктейль:
+card-english-name = Английское название:
+card-rating = Рейтинг:
+card-user-rating = Ваша оценка:
+card-note = Ваша заметка:
+card-ingredients = Ингредиенты:
+card-glass = Бокал:
+card-method = Метод:
+card-tools = Барный инвентарь:
+card-recipe = Способ приготовления:
+card-history = История для этого коктейля:
+card-tags = Теги:
+card-share = 🔗 Поделиться коктейлем: { $link }
+card-show-history-button = 📜 Показать историю
+card-hide-history-button = 📜 Скрыть историю
+card-similar-button = 🔁 Похожие коктейли
+card-another-random-button = 🎲 Ещё один
+method-shake = Шейк
+method-stir = Стир
+method-build = Билд
+method-blend = Бленд
+method-throw = Троу
+rating-thanks = Спасибо за оценку!
+
+## Favorite note
+
+note-form = Напишите заметку к коктейлю. Максимальная длина - { $max_length } символов.
+note-current = Текущая заметка:
+note-remove-button = 🗑 Удалить заметку
+
+## Registration
+
+register-confirmation =
+    Подтверждая регистрацию, вы соглашаетесь на то, что мы сохраняем ваш идентификатор пользователя Telegram. Другую информацию мы не собираем.
+
+    У вас появляется возможность сохранять любимые коктейли в свою личную подборку, чтобы проще было их искать.
+
+    В любой момент вы можете полностью удалить свой профиль.
+    Приятного использования ☺️
+register-confirm-button = Подтвердить регистрацию
+register-success = Вы успешно зарегистрированы
+remove-account-confirmation =
+    Вы точно хотите удалить свой профиль?
+
+    Все избранные коктейли будут удалены. 😔
+remove-account-confirm-button = Подтвердить удаление
+remove-account-success = Вы успешно удалили свою учетную запись
+
+## Profile page
+
+profile-page = Личный кабинет:
+profile-favorites = ❤ Показать избранное
+profile-collections = 📚 Мои коллекции
+profile-bar = 🍾 Мой бар
+profile-recommendations = ✨ Рекомендации для вас
+profile-daily = ⏰ Коктейль дня
+profile-language = 🌐 Язык: { $language }
+profile-remove-account = 🗑 Удалить учетную запись
+language-chooser = Выберите язык бота:
+
+## Collections
+
+collections = Ваши коллекции:
+collection = Коллекция:
+collection-share = 🔗 Поделиться коллекцией: { $link }
+collection-not-found = Коллекция не найдена 😔
+collection-create-button = ➕ Новая коллекция
+collection-chooser = Выберите коллекцию для коктейля:
+collection-chooser-empty = У вас пока нет коллекций. Создать их можно в личном кабинете.
+collection-cocktail-added = Коктейль добавлен в коллекцию
+collection-cocktail-removed = Коктейль удален из коллекции
+
+## Home bar
+
+bar = Ваш бар (нажмите на ингредиент, чтобы удалить его):
+bar-empty = В вашем баре пока пусто. Добавьте бутылки и ингредиенты, которые есть у вас дома.
+bar-add-button = ➕ Добавить ингредиент
+bar-what-can-i-make-button = 🍸 Что приготовить?
+bar-suggestions = Выберите ингредиент или уточните название:
+bar-suggestions-empty = Ничего не нашлось 😔 Попробуйте написать название иначе.
+bar-ingredient-added = { $ingredient } добавлен в ваш бар
+what-can-i-make = Коктейли из того, что есть в вашем баре (сначала те, для которых всего хватает):
+what-can-i-make-missing = { $name } (не хватает: { $count })
+
+## Shopping list
+
+shopping-list = 🛒 Список покупок на { $count } { $count ->
+    [one] коктейль
+    [few] коктейля
+   *[other] коктейлей
+}:
+shopping-list-nothing = Покупать ничего не нужно.
+shopping-list-owned = Уже есть в вашем баре:
+
+## Daily cocktail
+
+daily = ⏰ Коктейль дня
+daily-subscribed = Каждый день в { $time } ({ $timezone }) бот пришлёт случайный коктейль.
+daily-not-subscribed = Подпишитесь, и бот будет каждый день присылать случайный коктейль в выбранное время.
+daily-time = 🕘 Время: { $time }
+daily-timezone = 🌍 Часовой пояс: { $timezone }
+daily-non-alcoholic-on = 🍹 Только безалкогольные: да
+daily-non-alcoholic-off = 🍹 Только безалкогольные: нет
+daily-max-ingredients-any = 🔢 Ингредиентов: сколько угодно
+daily-max-ingredients = 🔢 Ингредиентов: не больше { $max_ingredients }
+daily-subscribe = 🔔 Подписаться
+daily-unsubscribe = 🔕 Отписаться
+daily-time-chooser = Во сколько присылать коктейль дня?
+daily-timezone-chooser = Выберите ваш часовой пояс:
+timezone-kaliningrad = Калининград (UTC+2)
+timezone-moscow = Москва (UTC+3)
+timezone-samara = Самара (UTC+4)
+timezone-yekaterinburg = Екатеринбург (UTC+5)
+timezone-omsk = Омск (UTC+6)
+timezone-novosibirsk = Новосибирск (UTC+7)
+timezone-irkutsk = Иркутск (UTC+8)
+timezone-yakutsk = Якутск (UTC+9)
+timezone-vladivostok = Владивосток (UTC+10)
+timezone-magadan = Магадан (UTC+11)
+timezone-kamchatka = Камчатка (UTC+12)
+
+## Similar cocktails and recommendations
+
+similar = Похожие коктейли:
+similar-empty = Похожих коктейлей не нашлось.
+recommendations = ✨ Рекомендации для вас:
+recommendations-empty = Пока нечего посоветовать. Добавляйте коктейли в избранное и ставьте оценки, и здесь появятся подборки для вас.
+recommendation-favorite = Потому что вам нравится { $name }
+recommendation-rated = Потому что вы высоко оценили { $name }
+recommendation-viewed = Потому что вы смотрели { $name }
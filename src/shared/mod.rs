@@ -1,3 +1,6 @@
+pub(crate) mod card;
+pub(crate) mod i18n;
+
 use anyhow::Result;
 use async_trait::async_trait;
 